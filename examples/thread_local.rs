@@ -1,27 +1,48 @@
-#![cfg(feature = "alloc")]
+#![cfg(feature = "std")]
 
 use bump_scope::{
     alloc::Global,
     settings::{BumpAllocatorSettings, BumpSettings},
+    thread_local::{self, ThreadLocalBump},
 };
 
-type Bump = bump_scope::Bump<Global, <BumpSettings as BumpAllocatorSettings>::WithGuaranteedAllocated<false>>;
+type Settings = <BumpSettings as BumpAllocatorSettings>::WithGuaranteedAllocated<false>;
 
-thread_local! {
-    static BUMP: Bump = const { Bump::unallocated() };
+// A thread-local bump allocator with custom settings.
+// If the default settings are fine you can use `bump_scope::thread_local::with` instead.
+std::thread_local! {
+    static BUMP: ThreadLocalBump<Global, Settings> = const { ThreadLocalBump::new() };
+}
+
+fn greet(name: &str) {
+    // Helper functions can allocate scratch memory without a `&Bump` parameter.
+    thread_local::with(|bump| {
+        let greeting = bump.alloc_fmt(format_args!("hello {name}"));
+        println!("{greeting}");
+    });
 }
 
 fn main() {
     BUMP.with(|bump| {
-        let hello = bump.alloc_str("hello");
-        assert_eq!(bump.stats().allocated(), 5);
+        bump.with(|bump| {
+            let hello = bump.alloc_str("hello");
+            assert_eq!(bump.stats().allocated(), 5);
 
-        bump.claim().scoped(|bump| {
-            let world = bump.alloc_str("world");
-            assert_eq!(bump.stats().allocated(), 10);
-            println!("{hello} {world}");
+            bump.claim().scoped(|bump| {
+                let world = bump.alloc_str("world");
+                assert_eq!(bump.stats().allocated(), 10);
+                println!("{hello} {world}");
+            });
+
+            assert_eq!(bump.stats().allocated(), 5);
         });
 
-        assert_eq!(bump.stats().allocated(), 5);
+        // the bump allocator is reset once `with` returns
+        bump.with(|bump| assert_eq!(bump.stats().allocated(), 0));
+    });
+
+    thread_local::with(|bump| {
+        let name = bump.alloc_str("world");
+        greet(&name);
     });
 }
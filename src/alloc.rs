@@ -13,7 +13,10 @@
 //! You can also use implementors of their `Allocator` trait as base allocators via the [compat] wrapper types.
 //!
 //! You can convert between this crate's `AllocError` and foreign one's via the `From` and `Into` traits.
+//!
+//! To limit the memory a bump allocator uses, wrap its base allocator in [`Limited`] which draws from a [`Budget`].
 
+mod budget;
 #[cfg(feature = "alloc")]
 mod global;
 #[cfg(feature = "std")]
@@ -23,6 +26,9 @@ use core::{alloc::Layout, error::Error, fmt, ptr::NonNull};
 
 use crate::polyfill::non_null;
 
+#[cfg(target_has_atomic = "ptr")]
+pub use budget::AtomicBudget;
+pub use budget::{Budget, CellBudget, Limited};
 #[cfg(feature = "alloc")]
 pub use global::Global;
 
@@ -0,0 +1,408 @@
+use core::{
+    alloc::Layout,
+    cell::Cell,
+    fmt::{self, Debug},
+    ptr::NonNull,
+};
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{AllocError, Allocator};
+
+/// An amount of bytes that allocations can draw from, used by the [`Limited`] base allocator.
+///
+/// Budgets can be nested: a budget created with a parent only grants a reservation if its parent
+/// grants it as well, so a per-request budget can draw from a per-tenant budget which in turn draws
+/// from a global one.
+///
+/// This crate provides the thread-safe [`AtomicBudget`] and the single-threaded [`CellBudget`].
+pub trait Budget {
+    /// Reserves `size` bytes from this budget and all of its parents.
+    ///
+    /// # Errors
+    ///
+    /// Errors if this or a parent budget would exceed its limit, in which case nothing is reserved.
+    fn try_reserve(&self, size: usize) -> Result<(), AllocError>;
+
+    /// Gives back `size` bytes that were reserved with [`try_reserve`](Self::try_reserve).
+    ///
+    /// Releasing more bytes than are [used](Self::used) is a logic error.
+    /// The budgets of this crate panic in that case if debug assertions are enabled,
+    /// and otherwise leave the used bytes at zero.
+    fn release(&self, size: usize);
+
+    /// Returns the amount of bytes that are currently reserved from this budget.
+    #[must_use]
+    fn used(&self) -> usize;
+
+    /// Returns the maximum amount of bytes that can be reserved from this budget.
+    #[must_use]
+    fn limit(&self) -> usize;
+
+    /// Returns the amount of bytes that can still be reserved, taking the parent budgets into account.
+    #[must_use]
+    fn remaining(&self) -> usize;
+}
+
+impl<B: Budget + ?Sized> Budget for &B {
+    #[inline(always)]
+    fn try_reserve(&self, size: usize) -> Result<(), AllocError> {
+        B::try_reserve(self, size)
+    }
+
+    #[inline(always)]
+    fn release(&self, size: usize) {
+        B::release(self, size);
+    }
+
+    #[inline(always)]
+    fn used(&self) -> usize {
+        B::used(self)
+    }
+
+    #[inline(always)]
+    fn limit(&self) -> usize {
+        B::limit(self)
+    }
+
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        B::remaining(self)
+    }
+}
+
+/// A thread-safe [`Budget`].
+///
+/// # Examples
+/// ```
+/// use bump_scope::alloc::{AtomicBudget, Budget};
+///
+/// let tenant = AtomicBudget::new(1024);
+/// let request = AtomicBudget::with_parent(512, &tenant);
+///
+/// request.try_reserve(300).unwrap();
+/// assert_eq!(tenant.used(), 300);
+///
+/// // the request budget is exhausted
+/// assert!(request.try_reserve(300).is_err());
+///
+/// // other requests can still draw from the tenant budget
+/// tenant.try_reserve(700).unwrap();
+/// assert_eq!(request.remaining(), 24);
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+pub struct AtomicBudget<'p> {
+    used: AtomicUsize,
+    limit: usize,
+    parent: Option<&'p (dyn Budget + Sync)>,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<'p> AtomicBudget<'p> {
+    /// Creates a budget of `limit` bytes.
+    #[must_use]
+    #[inline]
+    pub const fn new(limit: usize) -> Self {
+        Self {
+            used: AtomicUsize::new(0),
+            limit,
+            parent: None,
+        }
+    }
+
+    /// Creates a budget of `limit` bytes that also draws from `parent`.
+    #[must_use]
+    #[inline]
+    pub const fn with_parent(limit: usize, parent: &'p (dyn Budget + Sync)) -> Self {
+        Self {
+            used: AtomicUsize::new(0),
+            limit,
+            parent: Some(parent),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl Budget for AtomicBudget<'_> {
+    fn try_reserve(&self, size: usize) -> Result<(), AllocError> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(size).filter(|&new| new <= self.limit)
+            })
+            .map_err(|_| AllocError)?;
+
+        if let Some(parent) = self.parent {
+            if let Err(error) = parent.try_reserve(size) {
+                self.used.fetch_sub(size, Ordering::Relaxed);
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn release(&self, size: usize) {
+        let update = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| Some(used.saturating_sub(size)));
+        let (Ok(used) | Err(used)) = update;
+        debug_assert!(size <= used, "released more bytes than are used");
+
+        if let Some(parent) = self.parent {
+            parent.release(size);
+        }
+    }
+
+    #[inline]
+    fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn limit(&self) -> usize {
+        self.limit
+    }
+
+    fn remaining(&self) -> usize {
+        let remaining = self.limit.saturating_sub(self.used());
+
+        match self.parent {
+            Some(parent) => remaining.min(parent.remaining()),
+            None => remaining,
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl Debug for AtomicBudget<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_budget("AtomicBudget", self, self.parent.is_some(), f)
+    }
+}
+
+/// A single-threaded [`Budget`].
+///
+/// Its parent can be any budget, so a `CellBudget` for a request that is handled on a single thread
+/// can draw from an [`AtomicBudget`] that is shared between threads.
+///
+/// # Examples
+/// ```
+/// use bump_scope::alloc::{Budget, CellBudget};
+///
+/// let tenant = CellBudget::new(1024);
+/// let request = CellBudget::with_parent(512, &tenant);
+///
+/// request.try_reserve(300).unwrap();
+/// assert_eq!(tenant.used(), 300);
+///
+/// request.release(300);
+/// assert_eq!(tenant.used(), 0);
+/// ```
+pub struct CellBudget<'p> {
+    used: Cell<usize>,
+    limit: usize,
+    parent: Option<&'p dyn Budget>,
+}
+
+impl<'p> CellBudget<'p> {
+    /// Creates a budget of `limit` bytes.
+    #[must_use]
+    #[inline]
+    pub const fn new(limit: usize) -> Self {
+        Self {
+            used: Cell::new(0),
+            limit,
+            parent: None,
+        }
+    }
+
+    /// Creates a budget of `limit` bytes that also draws from `parent`.
+    #[must_use]
+    #[inline]
+    pub const fn with_parent(limit: usize, parent: &'p dyn Budget) -> Self {
+        Self {
+            used: Cell::new(0),
+            limit,
+            parent: Some(parent),
+        }
+    }
+}
+
+impl Budget for CellBudget<'_> {
+    fn try_reserve(&self, size: usize) -> Result<(), AllocError> {
+        let new = match self.used.get().checked_add(size) {
+            Some(new) if new <= self.limit => new,
+            _ => return Err(AllocError),
+        };
+
+        if let Some(parent) = self.parent {
+            parent.try_reserve(size)?;
+        }
+
+        self.used.set(new);
+        Ok(())
+    }
+
+    fn release(&self, size: usize) {
+        let used = self.used.get();
+        debug_assert!(size <= used, "released more bytes than are used");
+        self.used.set(used.saturating_sub(size));
+
+        if let Some(parent) = self.parent {
+            parent.release(size);
+        }
+    }
+
+    #[inline]
+    fn used(&self) -> usize {
+        self.used.get()
+    }
+
+    #[inline]
+    fn limit(&self) -> usize {
+        self.limit
+    }
+
+    fn remaining(&self) -> usize {
+        let remaining = self.limit.saturating_sub(self.used());
+
+        match self.parent {
+            Some(parent) => remaining.min(parent.remaining()),
+            None => remaining,
+        }
+    }
+}
+
+impl Debug for CellBudget<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_budget("CellBudget", self, self.parent.is_some(), f)
+    }
+}
+
+fn debug_budget(name: &str, budget: &dyn Budget, has_parent: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct(name)
+        .field("used", &budget.used())
+        .field("limit", &budget.limit())
+        .field("has_parent", &has_parent)
+        .finish()
+}
+
+/// A base allocator that fails once its [`Budget`] is exhausted.
+///
+/// Every memory block that is allocated from the wrapped allocator is charged to the budget
+/// with the size of its layout, and given back when it is deallocated.
+///
+/// Because `Limited` does not implement [`Default`], a bump allocator using it needs the
+/// `GUARANTEED_ALLOCATED` [setting](crate::settings) to be `true`, which it is by default.
+///
+/// # Examples
+/// ```
+/// use bump_scope::{
+///     Bump,
+///     alloc::{AtomicBudget, Budget, Global, Limited},
+/// };
+///
+/// let budget = AtomicBudget::new(1024);
+/// let bump: Bump<_> = Bump::with_size_in(1024, Limited::new_in(&budget, Global));
+/// assert_eq!(budget.used(), bump.stats().current_chunk().unwrap().size());
+///
+/// // allocate the entire remaining capacity
+/// bump.alloc_uninit_slice::<u8>(bump.stats().remaining());
+///
+/// // a new chunk would exceed the budget
+/// assert!(bump.try_alloc(0u8).is_err());
+///
+/// drop(bump);
+/// assert_eq!(budget.used(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Limited<B, A> {
+    budget: B,
+    allocator: A,
+}
+
+impl<B, A> Limited<B, A> {
+    /// Wraps `allocator` so that its allocations are charged to `budget`.
+    #[must_use]
+    #[inline]
+    pub const fn new_in(budget: B, allocator: A) -> Self {
+        Self { budget, allocator }
+    }
+
+    /// Returns a reference to the budget.
+    #[must_use]
+    #[inline]
+    pub const fn budget(&self) -> &B {
+        &self.budget
+    }
+
+    /// Returns a reference to the wrapped allocator.
+    #[must_use]
+    #[inline]
+    pub const fn allocator(&self) -> &A {
+        &self.allocator
+    }
+}
+
+unsafe impl<B, A> Allocator for Limited<B, A>
+where
+    B: Budget,
+    A: Allocator,
+{
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.budget.try_reserve(layout.size())?;
+
+        self.allocator.allocate(layout).inspect_err(|_| {
+            self.budget.release(layout.size());
+        })
+    }
+
+    #[inline]
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.budget.try_reserve(layout.size())?;
+
+        self.allocator.allocate_zeroed(layout).inspect_err(|_| {
+            self.budget.release(layout.size());
+        })
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.allocator.deallocate(ptr, layout) };
+        self.budget.release(layout.size());
+    }
+
+    #[inline]
+    unsafe fn grow(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let additional = new_layout.size() - old_layout.size();
+        self.budget.try_reserve(additional)?;
+
+        unsafe { self.allocator.grow(ptr, old_layout, new_layout) }.inspect_err(|_| {
+            self.budget.release(additional);
+        })
+    }
+
+    #[inline]
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let additional = new_layout.size() - old_layout.size();
+        self.budget.try_reserve(additional)?;
+
+        unsafe { self.allocator.grow_zeroed(ptr, old_layout, new_layout) }.inspect_err(|_| {
+            self.budget.release(additional);
+        })
+    }
+
+    #[inline]
+    unsafe fn shrink(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = unsafe { self.allocator.shrink(ptr, old_layout, new_layout) }?;
+        self.budget.release(old_layout.size() - new_layout.size());
+        Ok(ptr)
+    }
+}
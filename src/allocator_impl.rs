@@ -1,11 +1,20 @@
 use core::{alloc::Layout, num::NonZeroUsize, ptr::NonNull};
 
 use crate::{
-    BaseAllocator, alloc::AllocError, bump_down, layout::CustomLayout, polyfill::non_null, raw_bump::RawBump,
-    settings::BumpAllocatorSettings, up_align_usize_unchecked,
+    BaseAllocator,
+    alloc::AllocError,
+    asan, bump_down,
+    layout::CustomLayout,
+    poison,
+    polyfill::non_null,
+    raw_bump::RawBump,
+    red_zone::{self, Guard},
+    settings::BumpAllocatorSettings,
+    up_align_usize_unchecked,
 };
 
 #[inline(always)]
+#[cfg_attr(feature = "track-allocations", track_caller)]
 pub fn allocate<A, S>(bump: &RawBump<A, S>, layout: Layout) -> Result<NonNull<[u8]>, AllocError>
 where
     A: BaseAllocator<S::GuaranteedAllocated>,
@@ -23,6 +32,12 @@ where
     A: BaseAllocator<S::GuaranteedAllocated>,
     S: BumpAllocatorSettings,
 {
+    if S::RED_ZONES {
+        return unsafe { deallocate_guarded(bump, ptr, layout) };
+    }
+
+    unsafe { poison::freed::<S>(ptr, layout.size()) };
+
     if !S::DEALLOCATES {
         return;
     }
@@ -57,6 +72,54 @@ where
     }
 }
 
+/// `deallocate` for the `RED_ZONES` setting.
+///
+/// The red zones are checked and the memory block is only freed if its guard is the
+/// most recent one in the current chunk.
+#[cold]
+#[inline(never)]
+#[track_caller]
+unsafe fn deallocate_guarded<A, S>(bump: &RawBump<A, S>, ptr: NonNull<u8>, layout: Layout)
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    unsafe {
+        let guard = red_zone::guard_of::<S>(ptr, layout.align());
+
+        if let Err(error) = Guard::check::<S>(guard) {
+            red_zone::violated(error);
+        }
+
+        poison::freed::<S>(ptr, layout.size());
+
+        if !S::DEALLOCATES {
+            return;
+        }
+
+        let Some(chunk) = bump.chunk.get().as_non_dummy() else {
+            return;
+        };
+
+        let red_zones = chunk.red_zones();
+
+        if red_zones.last() != Some(guard) {
+            return;
+        }
+
+        red_zones.set_last(guard.as_ref().prev);
+
+        let block_end = Guard::block_end::<S>(guard);
+        asan::poison_range(guard.cast()..block_end);
+
+        if S::UP {
+            chunk.set_pos(guard.cast());
+        } else {
+            chunk.set_pos_addr_and_align(block_end.addr().get());
+        }
+    }
+}
+
 /// Checks if the memory block is the last one in the bump allocator.
 ///
 /// The given memory block must have been allocated by some bump allocator,
@@ -96,83 +159,110 @@ where
         "`new_layout.size()` must be greater than or equal to `old_layout.size()`"
     );
 
-    unsafe {
-        if S::UP {
-            if is_last(bump, old_ptr, old_layout) & align_fits(old_ptr, old_layout, new_layout) {
-                // We may be able to grow in place! Just need to check if there is enough space.
-
-                // `is_last` returned true, which guarantees a non-dummy
-                let chunk = bump.chunk.get().as_non_dummy_unchecked();
-                let chunk_end = chunk.content_end();
-                let remaining = chunk_end.addr().get() - old_ptr.addr().get();
-
-                if new_layout.size() <= remaining {
-                    // There is enough space! We will grow in place. Just need to update the bump pointer.
+    if S::RED_ZONES {
+        return unsafe { move_guarded(bump, old_ptr, old_layout, new_layout) };
+    }
 
-                    let old_addr = old_ptr.addr();
+    let result = unsafe {
+        if let Some(new_pos) = grow_in_place_pos(bump, old_ptr, old_layout, new_layout) {
+            // `grow_in_place_pos` returned a position, which guarantees a non-dummy chunk
+            let chunk = bump.chunk.get().as_non_dummy_unchecked();
 
-                    // Up-aligning a pointer inside a chunks content by `MIN_ALIGN` never overflows.
-                    let new_pos = up_align_usize_unchecked(old_addr.get() + new_layout.size(), S::MIN_ALIGN);
+            if S::UP {
+                // There is enough space! We will grow in place. Just need to update the bump pointer.
+                chunk.set_pos_addr(new_pos);
+                Ok(NonNull::slice_from_raw_parts(old_ptr, new_layout.size()))
+            } else {
+                // There is enough space in the current chunk! We will reuse the allocated space.
+                let new_ptr = old_ptr.with_addr(NonZeroUsize::new_unchecked(new_pos));
+                let new_addr_end = new_pos + new_layout.size();
 
-                    chunk.set_pos_addr(new_pos);
+                // The bump position is set before copying, so the new memory block is unpoisoned for the address sanitizer.
+                chunk.set_pos_addr(new_pos);
 
-                    Ok(NonNull::slice_from_raw_parts(old_ptr, new_layout.size()))
-                } else {
-                    // The current chunk doesn't have enough space to allocate this layout. We need to allocate in another chunk.
-                    let new_ptr = bump.alloc_in_another_chunk::<AllocError>(new_layout)?;
+                // Check if the regions don't overlap so we may use the faster `copy_nonoverlapping`.
+                if new_addr_end < old_ptr.addr().get() {
                     old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
-                    Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
+                } else {
+                    old_ptr.copy_to(new_ptr, old_layout.size());
                 }
-            } else {
-                // We can't grow in place. We have to make a new allocation.
-                let new_ptr = bump.alloc::<AllocError>(new_layout)?;
-                old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
+
                 Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
             }
         } else {
-            if is_last(bump, old_ptr, old_layout) {
-                // `is_last` returned true, which guarantees a non-dummy
-                let chunk = bump.chunk.get().as_non_dummy_unchecked();
+            // We can't grow in place. We have to make a new allocation.
+            let old_chunk = bump.chunk.get();
+            let new_ptr = bump.alloc::<AllocError>(new_layout)?;
+            old_chunk.record_abandoned(old_layout.size());
+            old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
+            Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
+        }
+    };
 
-                // We may be able to reuse the currently allocated space. Just need to check if the current chunk has enough space for that.
-                let additional_size = new_layout.size() - old_layout.size();
+    if let Ok(new_ptr) = result {
+        unsafe {
+            let new_ptr = new_ptr.cast::<u8>();
+            poison::freed_except::<S>(old_ptr, old_layout.size(), new_ptr, new_layout.size());
+            poison::uninit::<S>(new_ptr.add(old_layout.size()), new_layout.size() - old_layout.size());
+        }
+    }
 
-                let old_addr = old_ptr.addr();
-                let new_addr = bump_down(old_addr, additional_size, new_layout.align().max(S::MIN_ALIGN));
+    result
+}
 
-                let very_start = chunk.content_start().addr();
+/// Returns the bump position that [`grow`] would move to if it can grow the memory block in place.
+///
+/// Returns `None` if `grow` would move the memory block to a new allocation.
+/// This does not change the bump allocator.
+///
+/// # Safety
+/// Same as for [`grow`].
+#[inline(always)]
+pub(crate) unsafe fn grow_in_place_pos<A, S>(
+    bump: &RawBump<A, S>,
+    old_ptr: NonNull<u8>,
+    old_layout: Layout,
+    new_layout: Layout,
+) -> Option<usize>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    if S::RED_ZONES {
+        return None;
+    }
 
-                if new_addr >= very_start.get() {
-                    // There is enough space in the current chunk! We will reuse the allocated space.
+    unsafe {
+        if S::UP {
+            if !(is_last(bump, old_ptr, old_layout) & align_fits(old_ptr, old_layout, new_layout)) {
+                return None;
+            }
 
-                    let new_addr = NonZeroUsize::new_unchecked(new_addr);
-                    let new_addr_end = new_addr.get() + new_layout.size();
+            // `is_last` returned true, which guarantees a non-dummy
+            let chunk = bump.chunk.get().as_non_dummy_unchecked();
+            let remaining = chunk.content_end().addr().get() - old_ptr.addr().get();
 
-                    let new_ptr = old_ptr.with_addr(new_addr);
+            if new_layout.size() > remaining {
+                return None;
+            }
 
-                    // Check if the regions don't overlap so we may use the faster `copy_nonoverlapping`.
-                    if new_addr_end < old_addr.get() {
-                        old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
-                    } else {
-                        old_ptr.copy_to(new_ptr, old_layout.size());
-                    }
+            // Up-aligning a pointer inside a chunks content by `MIN_ALIGN` never overflows.
+            Some(up_align_usize_unchecked(
+                old_ptr.addr().get() + new_layout.size(),
+                S::MIN_ALIGN,
+            ))
+        } else {
+            if !is_last(bump, old_ptr, old_layout) {
+                return None;
+            }
 
-                    // `is_last_and_allocated` returned true
-                    chunk.set_pos_addr(new_addr.get());
+            // `is_last` returned true, which guarantees a non-dummy
+            let chunk = bump.chunk.get().as_non_dummy_unchecked();
 
-                    Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
-                } else {
-                    // The current chunk doesn't have enough space to allocate this layout. We need to allocate in another chunk.
-                    let new_ptr = bump.alloc_in_another_chunk::<AllocError>(new_layout)?;
-                    old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
-                    Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
-                }
-            } else {
-                // We can't reuse the allocated space. We have to make a new allocation.
-                let new_ptr = bump.alloc::<AllocError>(new_layout)?;
-                old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
-                Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
-            }
+            let additional_size = new_layout.size() - old_layout.size();
+            let new_addr = bump_down(old_ptr.addr(), additional_size, new_layout.align().max(S::MIN_ALIGN));
+
+            (new_addr >= chunk.content_start().addr().get()).then_some(new_addr)
         }
     }
 }
@@ -271,15 +361,42 @@ where
         "`new_layout.size()` must be smaller than or equal to `old_layout.size()`"
     );
 
-    unsafe {
-        if !align_fits(old_ptr, old_layout, new_layout) {
-            return shrink_unfit(bump, old_ptr, old_layout, new_layout);
+    if S::RED_ZONES {
+        return unsafe { shrink_guarded(bump, old_ptr, old_layout, new_layout) };
+    }
+
+    let result = unsafe {
+        if align_fits(old_ptr, old_layout, new_layout) {
+            Ok(shrink_fit(bump, old_ptr, old_layout, new_layout))
+        } else {
+            shrink_unfit(bump, old_ptr, old_layout, new_layout)
         }
+    };
+
+    if let Ok(new_ptr) = result {
+        unsafe { poison::freed_except::<S>(old_ptr, old_layout.size(), new_ptr.cast(), new_ptr.len()) };
+    }
+
+    result
+}
 
+/// Called when `new_layout` fits the alignment of `old_ptr`.
+#[inline(always)]
+unsafe fn shrink_fit<A, S>(
+    bump: &RawBump<A, S>,
+    old_ptr: NonNull<u8>,
+    old_layout: Layout,
+    new_layout: Layout,
+) -> NonNull<[u8]>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    unsafe {
         // If this is not the last allocation, then there's nothing we can do
         if !S::SHRINKS || !is_last(bump, old_ptr, old_layout) {
             // We can't shrink this allocation, so we return it as-is.
-            return Ok(NonNull::slice_from_raw_parts(old_ptr, old_layout.size()));
+            return NonNull::slice_from_raw_parts(old_ptr, old_layout.size());
         }
 
         if S::UP {
@@ -291,7 +408,7 @@ where
             // `is_last` returned true, which guarantees a non-dummy
             bump.chunk.get().as_non_dummy_unchecked().set_pos_addr(new_pos);
 
-            Ok(NonNull::slice_from_raw_parts(old_ptr, new_layout.size()))
+            NonNull::slice_from_raw_parts(old_ptr, new_layout.size())
         } else {
             let old_addr = old_ptr.addr();
             let old_end_addr = NonZeroUsize::new_unchecked(old_addr.get() + old_layout.size());
@@ -316,7 +433,7 @@ where
             // `is_last` returned true, which guarantees a non-dummy
             bump.chunk.get().as_non_dummy_unchecked().set_pos(new_ptr);
 
-            Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
+            NonNull::slice_from_raw_parts(new_ptr, new_layout.size())
         }
     }
 }
@@ -325,3 +442,60 @@ where
 fn align_fits(old_ptr: NonNull<u8>, _old_layout: Layout, new_layout: Layout) -> bool {
     non_null::is_aligned_to(old_ptr, new_layout.align())
 }
+
+/// `shrink` for the `RED_ZONES` setting.
+///
+/// The memory is never freed. If the alignment stays the same the allocation is shrunk in place
+/// by moving its back red zone, otherwise it is moved to a new memory block.
+#[cold]
+#[inline(never)]
+unsafe fn shrink_guarded<A, S>(
+    bump: &RawBump<A, S>,
+    old_ptr: NonNull<u8>,
+    old_layout: Layout,
+    new_layout: Layout,
+) -> Result<NonNull<[u8]>, AllocError>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    if new_layout.align() != old_layout.align() {
+        return unsafe { move_guarded(bump, old_ptr, old_layout, new_layout) };
+    }
+
+    unsafe {
+        let guard = red_zone::guard_of::<S>(old_ptr, old_layout.align());
+
+        if let Err(error) = Guard::check::<S>(guard) {
+            red_zone::violated(error);
+        }
+
+        let freed = Guard::shrink::<S>(guard, new_layout.size());
+        poison::freed_range::<S>(freed);
+    }
+
+    Ok(NonNull::slice_from_raw_parts(old_ptr, new_layout.size()))
+}
+
+/// Moves an allocation made with red zones to a new memory block of `new_layout`.
+#[cold]
+#[inline(never)]
+unsafe fn move_guarded<A, S>(
+    bump: &RawBump<A, S>,
+    old_ptr: NonNull<u8>,
+    old_layout: Layout,
+    new_layout: Layout,
+) -> Result<NonNull<[u8]>, AllocError>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    unsafe {
+        let old_chunk = bump.chunk.get();
+        let new_ptr = bump.alloc::<AllocError>(new_layout)?;
+        old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size().min(new_layout.size()));
+        old_chunk.record_abandoned(old_layout.size());
+        deallocate_guarded(bump, old_ptr, old_layout);
+        Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
+    }
+}
@@ -0,0 +1,102 @@
+//! Manual [AddressSanitizer](https://doc.rust-lang.org/beta/unstable-book/compiler-flags/sanitizer.html#addresssanitizer)
+//! poisoning for the `nightly-asan` feature.
+//!
+//! The content of a chunk is poisoned when the chunk is allocated. Memory is unpoisoned when
+//! the bump position moves over it or when it is prepared for an allocation, and poisoned again
+//! when it is freed by a reset, a scope exit, a deallocation or a shrink.
+//!
+//! Prepared memory that is not allocated in the end, like the buffer of a dropped `MutBumpVec`,
+//! stays unpoisoned until the next prepared allocation is made or the memory is freed.
+//!
+//! All functions do nothing unless the feature is enabled and the crate is compiled with `-Zsanitizer=address`.
+
+use core::{ops::Range, ptr::NonNull};
+
+/// Whether memory is poisoned for the address sanitizer.
+pub(crate) const ENABLED: bool = sys::ENABLED;
+
+/// Marks `len` bytes starting at `ptr` as unaddressable.
+///
+/// # Safety
+/// The memory must belong to a chunk.
+#[inline(always)]
+pub(crate) unsafe fn poison(ptr: NonNull<u8>, len: usize) {
+    if ENABLED {
+        unsafe { sys::poison(ptr.as_ptr(), len) };
+    }
+}
+
+/// Marks `len` bytes starting at `ptr` as addressable.
+///
+/// # Safety
+/// The memory must belong to a chunk.
+#[inline(always)]
+pub(crate) unsafe fn unpoison(ptr: NonNull<u8>, len: usize) {
+    if ENABLED {
+        unsafe { sys::unpoison(ptr.as_ptr(), len) };
+    }
+}
+
+/// Marks the memory `range` as unaddressable.
+///
+/// # Safety
+/// The memory must belong to a chunk.
+#[inline(always)]
+pub(crate) unsafe fn poison_range(range: Range<NonNull<u8>>) {
+    if ENABLED {
+        unsafe { poison(range.start, range.end.addr().get() - range.start.addr().get()) };
+    }
+}
+
+/// Marks the memory `range` as addressable.
+///
+/// # Safety
+/// The memory must belong to a chunk.
+#[inline(always)]
+pub(crate) unsafe fn unpoison_range(range: Range<NonNull<u8>>) {
+    if ENABLED {
+        unsafe { unpoison(range.start, range.end.addr().get() - range.start.addr().get()) };
+    }
+}
+
+#[cfg(feature = "nightly-asan")]
+mod sys {
+    // `cfg(sanitize)` is unstable, so it must only be evaluated with the nightly feature enabled.
+    #[cfg(sanitize = "address")]
+    mod imp {
+        pub(crate) const ENABLED: bool = true;
+
+        unsafe extern "C" {
+            fn __asan_poison_memory_region(addr: *const u8, size: usize);
+            fn __asan_unpoison_memory_region(addr: *const u8, size: usize);
+        }
+
+        pub(crate) unsafe fn poison(addr: *const u8, size: usize) {
+            unsafe { __asan_poison_memory_region(addr, size) }
+        }
+
+        pub(crate) unsafe fn unpoison(addr: *const u8, size: usize) {
+            unsafe { __asan_unpoison_memory_region(addr, size) }
+        }
+    }
+
+    #[cfg(not(sanitize = "address"))]
+    mod imp {
+        pub(crate) const ENABLED: bool = false;
+
+        pub(crate) unsafe fn poison(_addr: *const u8, _size: usize) {}
+
+        pub(crate) unsafe fn unpoison(_addr: *const u8, _size: usize) {}
+    }
+
+    pub(super) use imp::*;
+}
+
+#[cfg(not(feature = "nightly-asan"))]
+mod sys {
+    pub(super) const ENABLED: bool = false;
+
+    pub(super) unsafe fn poison(_addr: *const u8, _size: usize) {}
+
+    pub(super) unsafe fn unpoison(_addr: *const u8, _size: usize) {}
+}
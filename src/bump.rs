@@ -11,7 +11,8 @@ use core::{
 use core::clone::CloneToUninit;
 
 use crate::{
-    BaseAllocator, BumpBox, BumpClaimGuard, BumpScope, BumpScopeGuard, Checkpoint, ErrorBehavior,
+    BaseAllocator, BumpBox, BumpClaimGuard, BumpScope, BumpScopeGuard, BumpScopeGuardWithLimit, BumpScopeWithLimit,
+    Checkpoint, CheckpointError, ErrorBehavior, FrozenBump, RedZoneError,
     alloc::{AllocError, Allocator},
     allocator_impl,
     chunk::ChunkSize,
@@ -72,7 +73,7 @@ macro_rules! make_type {
         ///
         /// #### Free memory using ...
         /// - scopes: [`scoped`], [`scoped_aligned`], [`scope_guard`]
-        /// - checkpoints: [`checkpoint`], [`reset_to`]
+        /// - checkpoints: [`checkpoint`], [`reset_to`], [`try_reset_to`]
         /// - reset: [`reset`], [`reset_to_start`]
         /// - dealloc: [`dealloc`]
         ///
@@ -165,6 +166,7 @@ macro_rules! make_type {
         ///
         /// [`checkpoint`]: BumpAllocatorCore::checkpoint
         /// [`reset_to`]: BumpAllocatorCore::reset_to
+        /// [`try_reset_to`]: Bump::try_reset_to
         ///
         /// [`reset`]: Bump::reset
         /// [`reset_to_start`]: Bump::reset_to_start
@@ -707,8 +709,11 @@ where
     /// the bump pointer to the start of the retained chunk.
     ///
     /// For a version of this function that doesn't deallocate chunks, see [`reset_to_start`].
+    /// If the `GROWS` [setting] is disabled, the chunks could not be allocated again,
+    /// so this behaves like [`reset_to_start`].
     ///
     /// [`reset_to_start`]: Self::reset_to_start
+    /// [setting]: crate::settings
     ///
     /// # Examples
     ///
@@ -820,6 +825,363 @@ where
         self.raw.reset_to_start();
     }
 
+    /// Resets this bump allocator and makes sure it only has a single chunk which
+    /// is big enough to hold everything that was allocated before the reset.
+    ///
+    /// If the largest chunk is already big enough, this behaves like [`reset`].
+    /// Otherwise all chunks are deallocated and replaced by a new chunk with a capacity
+    /// of at least [`stats().allocated()`](Stats::allocated).
+    /// If the `GROWS` [setting] is disabled, this never allocates a chunk and behaves like [`reset`].
+    ///
+    /// When the bump allocator is used in cycles that allocate about the same amount
+    /// of memory, like once per frame, then calling this at the end of each cycle
+    /// ensures that the following cycles fit into a single chunk.
+    ///
+    /// [`reset`]: Self::reset
+    /// [setting]: crate::settings
+    ///
+    /// # Panics
+    /// Panics if the allocation fails. In that case the bump allocator is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let mut bump: Bump = Bump::with_size(512);
+    ///
+    /// for _ in 0..3 {
+    ///     // the allocations span multiple chunks only in the first iteration
+    ///     bump.alloc_uninit_slice::<u8>(400);
+    ///     bump.alloc_uninit_slice::<u8>(600);
+    ///     bump.alloc_uninit_slice::<u8>(1000);
+    ///
+    ///     bump.reset_to_fit();
+    ///     assert_eq!(bump.stats().count(), 1);
+    /// }
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn reset_to_fit(&mut self)
+    where
+        A: Clone,
+    {
+        panic_on_error(self.raw.reset_to_fit());
+    }
+
+    /// Resets this bump allocator and makes sure it only has a single chunk which
+    /// is big enough to hold everything that was allocated before the reset.
+    ///
+    /// If the largest chunk is already big enough, this behaves like [`reset`].
+    /// Otherwise all chunks are deallocated and replaced by a new chunk with a capacity
+    /// of at least [`stats().allocated()`](Stats::allocated).
+    /// If the `GROWS` [setting] is disabled, this never allocates a chunk and behaves like [`reset`].
+    ///
+    /// When the bump allocator is used in cycles that allocate about the same amount
+    /// of memory, like once per frame, then calling this at the end of each cycle
+    /// ensures that the following cycles fit into a single chunk.
+    ///
+    /// [`reset`]: Self::reset
+    /// [setting]: crate::settings
+    ///
+    /// # Errors
+    /// Errors if the allocation fails. In that case the bump allocator is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let mut bump: Bump = Bump::try_with_size(512)?;
+    ///
+    /// for _ in 0..3 {
+    ///     // the allocations span multiple chunks only in the first iteration
+    ///     bump.try_alloc_uninit_slice::<u8>(400)?;
+    ///     bump.try_alloc_uninit_slice::<u8>(600)?;
+    ///     bump.try_alloc_uninit_slice::<u8>(1000)?;
+    ///
+    ///     bump.try_reset_to_fit()?;
+    ///     assert_eq!(bump.stats().count(), 1);
+    /// }
+    /// # Ok::<(), bump_scope::alloc::AllocError>(())
+    /// ```
+    #[inline(always)]
+    pub fn try_reset_to_fit(&mut self) -> Result<(), AllocError>
+    where
+        A: Clone,
+    {
+        self.raw.reset_to_fit()
+    }
+
+    /// Resets the bump position to a previously created checkpoint after checking
+    /// that the checkpoint belongs to this bump allocator.
+    ///
+    /// This is a safe version of [`reset_to`]. Because it takes `&mut self` there can't be any
+    /// references to allocations made since the checkpoint was created.
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    /// - the checkpoint's chunk is not part of this bump allocator ([`ForeignChunk`])
+    /// - the checkpoint lies ahead of the current bump position, which is the case for most checkpoints
+    ///   that outlived a [`reset`] ([`AheadOfPosition`])
+    /// - the bump allocator is [claimed] ([`Claimed`])
+    ///
+    /// In those cases the bump allocator is left unchanged.
+    ///
+    /// [`reset_to`]: BumpAllocatorCore::reset_to
+    /// [`reset`]: Self::reset
+    /// [claimed]: crate::traits::BumpAllocatorScope::claim
+    /// [`ForeignChunk`]: CheckpointError::ForeignChunk
+    /// [`AheadOfPosition`]: CheckpointError::AheadOfPosition
+    /// [`Claimed`]: CheckpointError::Claimed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::{Bump, CheckpointError};
+    /// let mut bump: Bump = Bump::new();
+    /// bump.alloc_str("hello");
+    ///
+    /// let checkpoint = bump.checkpoint();
+    /// bump.alloc_str("world");
+    /// assert_eq!(bump.stats().allocated(), 10);
+    ///
+    /// bump.try_reset_to(checkpoint)?;
+    /// assert_eq!(bump.stats().allocated(), 5);
+    ///
+    /// // the checkpoint belongs to a different bump allocator
+    /// let other: Bump = Bump::new();
+    /// assert_eq!(bump.try_reset_to(other.checkpoint()), Err(CheckpointError::ForeignChunk));
+    ///
+    /// // the checkpoint outlived a reset
+    /// bump.reset();
+    /// assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::AheadOfPosition));
+    /// # Ok::<(), CheckpointError>(())
+    /// ```
+    #[inline]
+    pub fn try_reset_to(&mut self, checkpoint: Checkpoint) -> Result<(), CheckpointError>
+    where
+        A: BaseAllocator<S::GuaranteedAllocated>,
+    {
+        self.raw.chunk.get().check_checkpoint(checkpoint)?;
+
+        // SAFETY: We checked that the checkpoint points into this bump allocator and is not ahead of
+        // the bump position. We have exclusive access so there are no references to newer allocations.
+        unsafe { self.raw.reset_to(checkpoint) };
+        Ok(())
+    }
+
+    /// Deallocates the unused chunks that follow the current chunk.
+    ///
+    /// Such chunks are left over from a [`reset_to_start`], a [`reset_to`] or from exiting a scope.
+    /// Unlike [`reset`], this does not affect any live allocations.
+    ///
+    /// Returns the combined [size](crate::stats::Chunk::size) of the deallocated chunks.
+    ///
+    /// For a version of this function that retains some of the unused chunks, see [`trim_to`].
+    ///
+    /// [`reset`]: Self::reset
+    /// [`reset_to_start`]: Self::reset_to_start
+    /// [`reset_to`]: Self::reset_to
+    /// [`trim_to`]: Self::trim_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let mut bump: Bump = Bump::with_size(512);
+    ///
+    /// bump.scoped(|bump| {
+    ///     // won't fit in the first chunk, will be allocated in another chunk
+    ///     bump.alloc_uninit_slice::<u8>(600);
+    /// });
+    ///
+    /// let hello = bump.alloc_str("hello");
+    ///
+    /// // the chunk of the scope is still around...
+    /// assert_eq!(bump.stats().count(), 2);
+    /// let spare_chunk_size = bump.stats().big_to_small().next().unwrap().size();
+    ///
+    /// // ... until we trim the bump allocator
+    /// assert_eq!(bump.trim(), spare_chunk_size);
+    /// assert_eq!(bump.stats().count(), 1);
+    /// assert_eq!(hello, "hello");
+    /// ```
+    #[inline]
+    pub fn trim(&self) -> usize {
+        self.as_scope().trim()
+    }
+
+    /// Deallocates the unused chunks that follow the current chunk
+    /// except for as many as fit into `max_spare_size` bytes.
+    ///
+    /// The smallest unused chunks are retained.
+    ///
+    /// Returns the combined [size](crate::stats::Chunk::size) of the deallocated chunks.
+    ///
+    /// For a version of this function that deallocates all unused chunks, see [`trim`].
+    ///
+    /// [`trim`]: Self::trim
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let mut bump: Bump = Bump::with_size(512);
+    ///
+    /// // allocate a few chunks
+    /// for _ in 0..3 {
+    ///     bump.alloc_uninit_slice::<u8>(bump.stats().remaining() + 1);
+    /// }
+    ///
+    /// let chunk_sizes = bump.stats().small_to_big().map(|chunk| chunk.size()).collect::<Vec<_>>();
+    /// assert_eq!(chunk_sizes.len(), 4);
+    ///
+    /// bump.reset_to_start();
+    ///
+    /// // keep enough for the second chunk, release the remaining two
+    /// let released = bump.trim_to(chunk_sizes[1]);
+    /// assert_eq!(released, chunk_sizes[2] + chunk_sizes[3]);
+    /// assert_eq!(bump.stats().count(), 2);
+    /// ```
+    #[inline]
+    pub fn trim_to(&self, max_spare_size: usize) -> usize {
+        self.as_scope().trim_to(max_spare_size)
+    }
+
+    /// Allocates a spare chunk for each of the `capacities` and appends them after the last chunk.
+    ///
+    /// The bump allocator moves on to the spare chunks in order once an allocation does not fit into
+    /// the current chunk anymore. The rest of the current chunk is left unused, and so is every spare
+    /// chunk that is too small for that allocation. The base allocator is only called again once
+    /// none of the remaining chunks fits an allocation.
+    ///
+    /// So the base allocator is not called as long as the allocations can be split, in order, into runs
+    /// that each fit into one chunk: the sizes of the allocations of a run, plus the padding needed to
+    /// align them, must add up to at most the [capacity](crate::stats::Chunk::capacity) of its chunk.
+    /// This does not hold for a total number of bytes alone. Allocations that straddle two chunks
+    /// leave memory unused at the end of the first one.
+    ///
+    /// Call [`prefault`] afterwards to also make the operating system map in the memory up front.
+    ///
+    /// Unlike [`reserve`], which picks the chunk sizes according to the growth settings,
+    /// this gives each chunk at least the requested capacity. A chunk is never smaller than the
+    /// one before it, so the last chunk stays the biggest one, which is the one a [`reset`] keeps.
+    ///
+    /// If the bump allocator is unallocated, the first chunk becomes the current chunk.
+    ///
+    /// [`prefault`]: Self::prefault
+    /// [`reserve`]: Self::reserve
+    /// [`reset`]: Self::reset
+    ///
+    /// # Panics
+    /// Panics if an allocation fails. The chunks that were allocated before that are kept.
+    ///
+    /// Panics if the bump allocator is currently [claimed] or if the `GROWS` [setting] is disabled.
+    ///
+    /// [claimed]: crate::traits::BumpAllocatorScope::claim
+    /// [setting]: crate::settings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let bump: Bump = Bump::with_size(512);
+    /// bump.reserve_chunks(&[1024, 4096]);
+    /// assert_eq!(bump.stats().count(), 3);
+    ///
+    /// bump.alloc_uninit_slice::<u8>(1024);
+    /// bump.alloc_uninit_slice::<u8>(4096);
+    /// assert_eq!(bump.stats().count(), 3);
+    /// ```
+    #[inline]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn reserve_chunks(&self, capacities: &[usize])
+    where
+        A: BaseAllocator<S::GuaranteedAllocated>,
+    {
+        panic_on_error(self.raw.reserve_chunks(capacities));
+    }
+
+    /// Allocates a spare chunk for each of the `capacities` and appends them after the last chunk.
+    ///
+    /// The bump allocator moves on to the spare chunks in order once an allocation does not fit into
+    /// the current chunk anymore. The rest of the current chunk is left unused, and so is every spare
+    /// chunk that is too small for that allocation. The base allocator is only called again once
+    /// none of the remaining chunks fits an allocation.
+    ///
+    /// So the base allocator is not called as long as the allocations can be split, in order, into runs
+    /// that each fit into one chunk: the sizes of the allocations of a run, plus the padding needed to
+    /// align them, must add up to at most the [capacity](crate::stats::Chunk::capacity) of its chunk.
+    /// This does not hold for a total number of bytes alone. Allocations that straddle two chunks
+    /// leave memory unused at the end of the first one.
+    ///
+    /// Call [`prefault`] afterwards to also make the operating system map in the memory up front.
+    ///
+    /// Unlike [`try_reserve`], which picks the chunk sizes according to the growth settings,
+    /// this gives each chunk at least the requested capacity. A chunk is never smaller than the
+    /// one before it, so the last chunk stays the biggest one, which is the one a [`reset`] keeps.
+    ///
+    /// If the bump allocator is unallocated, the first chunk becomes the current chunk.
+    ///
+    /// [`prefault`]: Self::prefault
+    /// [`try_reserve`]: Self::try_reserve
+    /// [`reset`]: Self::reset
+    ///
+    /// # Errors
+    /// Errors if an allocation fails. The chunks that were allocated before that are kept.
+    ///
+    /// Errors if the bump allocator is currently [claimed] or if the `GROWS` [setting] is disabled.
+    ///
+    /// [claimed]: crate::traits::BumpAllocatorScope::claim
+    /// [setting]: crate::settings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let bump: Bump = Bump::try_with_size(512)?;
+    /// bump.try_reserve_chunks(&[1024, 4096])?;
+    /// assert_eq!(bump.stats().count(), 3);
+    ///
+    /// bump.try_alloc_uninit_slice::<u8>(1024)?;
+    /// bump.try_alloc_uninit_slice::<u8>(4096)?;
+    /// assert_eq!(bump.stats().count(), 3);
+    /// # Ok::<(), bump_scope::alloc::AllocError>(())
+    /// ```
+    #[inline]
+    pub fn try_reserve_chunks(&self, capacities: &[usize]) -> Result<(), AllocError>
+    where
+        A: BaseAllocator<S::GuaranteedAllocated>,
+    {
+        self.raw.reserve_chunks(capacities)
+    }
+
+    /// Touches every page of the free memory in the current chunk and the spare chunks that follow it.
+    ///
+    /// Operating systems usually only map in the pages of an allocation on first access,
+    /// so writing to fresh memory causes page faults. Prefaulting moves those to this call,
+    /// which is useful after [`reserve_chunks`] or [`reserve`] when the allocations that follow
+    /// are latency sensitive.
+    ///
+    /// The contents of the memory are left unchanged.
+    ///
+    /// [`reserve_chunks`]: Self::reserve_chunks
+    /// [`reserve`]: Self::reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let bump: Bump = Bump::with_size(64 * 1024);
+    /// bump.prefault();
+    ///
+    /// bump.alloc_uninit_slice::<u8>(32 * 1024);
+    /// ```
+    #[inline]
+    pub fn prefault(&self) {
+        self.raw.prefault();
+    }
+
     /// Returns a type which provides statistics about the memory usage of the bump allocator.
     #[must_use]
     #[inline(always)]
@@ -827,6 +1189,71 @@ where
         self.as_scope().stats()
     }
 
+    /// Sets the peak memory usage to the current memory usage.
+    ///
+    /// After this call, [`Stats::peak_allocated`] and [`Stats::peak_capacity`] only
+    /// report the peak from this point on.
+    ///
+    /// This does nothing unless the `TRACK_PEAK` [setting](crate::settings) is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_scope::{Bump, alloc::Global, settings::{BumpAllocatorSettings, BumpSettings}};
+    ///
+    /// type Settings = <BumpSettings as BumpAllocatorSettings>::WithTrackPeak<true>;
+    ///
+    /// let mut bump: Bump<Global, Settings> = Bump::with_size(512);
+    ///
+    /// // warm up
+    /// bump.alloc_uninit_slice::<u8>(1000);
+    /// bump.reset();
+    /// bump.reset_peak();
+    ///
+    /// for len in [10, 100, 20] {
+    ///     bump.alloc_uninit_slice::<u8>(len);
+    ///     bump.reset();
+    /// }
+    ///
+    /// assert_eq!(bump.stats().peak_allocated(), Some(100));
+    /// ```
+    #[inline]
+    pub fn reset_peak(&self) {
+        self.as_scope().reset_peak();
+    }
+
+    /// Checks the red zones of all live allocations.
+    ///
+    /// This always returns `Ok` unless the `RED_ZONES` [setting](crate::settings) is enabled.
+    ///
+    /// # Errors
+    /// Errors if a red zone was overwritten, which means something wrote
+    /// past the start or the end of an allocation.
+    /// The error describes the most recent of those allocations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_scope::{Bump, alloc::Global, settings::{BumpAllocatorSettings, BumpSettings}};
+    ///
+    /// type Settings = <BumpSettings as BumpAllocatorSettings>::WithRedZones<true>;
+    ///
+    /// let bump: Bump<Global, Settings> = Bump::new();
+    /// let mut slice = bump.alloc_slice_copy(&[1u8, 2, 3]);
+    /// assert!(bump.check_integrity().is_ok());
+    ///
+    /// // oops, one past the end
+    /// unsafe { slice.as_mut_ptr().add(3).write(4) };
+    ///
+    /// let error = bump.check_integrity().unwrap_err();
+    /// assert_eq!(error.address(), slice.as_ptr().addr());
+    /// assert_eq!(error.layout().size(), 3);
+    /// ```
+    #[inline]
+    pub fn check_integrity(&self) -> Result<(), RedZoneError> {
+        self.as_scope().check_integrity()
+    }
+
     /// Returns this `&Bump` as a `&BumpScope`.
     #[must_use]
     #[inline(always)]
@@ -845,6 +1272,9 @@ where
     ///
     /// This function will fail to compile if:
     /// - `NewS::UP != S::UP`
+    /// - `NewS::TRACK_PEAK != S::TRACK_PEAK`
+    /// - `NewS::TRACK_WASTE != S::TRACK_WASTE`
+    /// - `NewS::RED_ZONES != S::RED_ZONES`
     ///
     /// # Panics
     /// Panics if `!NewS::CLAIMABLE` and the bump allocator is currently [claimed].
@@ -867,6 +1297,9 @@ where
     /// This function will fail to compile if:
     /// - `NewS::MIN_ALIGN != S::MIN_ALIGN`
     /// - `NewS::UP != S::UP`
+    /// - `NewS::TRACK_PEAK != S::TRACK_PEAK`
+    /// - `NewS::TRACK_WASTE != S::TRACK_WASTE`
+    /// - `NewS::RED_ZONES != S::RED_ZONES`
     /// - `NewS::CLAIMABLE != S::CLAIMABLE`
     /// - `NewS::GUARANTEED_ALLOCATED > S::GUARANTEED_ALLOCATED`
     #[inline]
@@ -884,6 +1317,9 @@ where
     /// This function will fail to compile if:
     /// - `NewS::MIN_ALIGN < S::MIN_ALIGN`
     /// - `NewS::UP != S::UP`
+    /// - `NewS::TRACK_PEAK != S::TRACK_PEAK`
+    /// - `NewS::TRACK_WASTE != S::TRACK_WASTE`
+    /// - `NewS::RED_ZONES != S::RED_ZONES`
     /// - `NewS::GUARANTEED_ALLOCATED != S::GUARANTEED_ALLOCATED`
     /// - `NewS::CLAIMABLE != S::CLAIMABLE`
     #[inline]
@@ -896,6 +1332,31 @@ where
         unsafe { transmute_mut(self) }
     }
 
+    /// Stops allocating and turns this `Bump` into a [`FrozenBump`] that can be shared between threads.
+    ///
+    /// Allocations can be accessed again with [`FrozenBump::get`] after turning them into raw pointers
+    /// with [`BumpBox::into_raw`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::{Bump, BumpBox};
+    /// let bump: Bump = Bump::new();
+    /// let greeting = BumpBox::into_raw(bump.alloc_str("hello"));
+    ///
+    /// let frozen = bump.freeze();
+    /// let greeting = unsafe { frozen.get(greeting) };
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| assert_eq!(greeting, "hello"));
+    ///     s.spawn(|| assert_eq!(frozen.stats().allocated(), 5));
+    /// });
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn freeze(self) -> FrozenBump<A, S> {
+        FrozenBump::from(self)
+    }
+
     /// Converts this `Bump` into a raw pointer.
     ///
     /// ```
@@ -1006,6 +1467,7 @@ where
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
     #[expect(clippy::missing_errors_doc)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn alloc_try_with<T, E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<BumpBox<'_, T>, E> {
         self.as_scope().alloc_try_with(f)
     }
@@ -1041,6 +1503,7 @@ where
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn try_alloc_try_with<T, E>(
         &self,
         f: impl FnOnce() -> Result<T, E>,
@@ -1079,6 +1542,7 @@ where
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
     #[expect(clippy::missing_errors_doc)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn alloc_try_with_mut<T, E>(&mut self, f: impl FnOnce() -> Result<T, E>) -> Result<BumpBox<'_, T>, E> {
         self.as_mut_scope().alloc_try_with_mut(f)
     }
@@ -1114,6 +1578,7 @@ where
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn try_alloc_try_with_mut<T, E>(
         &mut self,
         f: impl FnOnce() -> Result<T, E>,
@@ -1128,6 +1593,7 @@ where
     S: BumpAllocatorSettings,
 {
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
         allocator_impl::allocate(&self.raw, layout)
     }
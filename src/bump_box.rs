@@ -357,7 +357,9 @@ impl<'a, T: ?Sized> BumpBox<'a, T> {
     #[inline(always)]
     #[expect(clippy::must_use_candidate)]
     pub fn leak(boxed: Self) -> &'a mut T {
-        unsafe { BumpBox::into_raw(boxed).as_mut() }
+        // Not `into_raw`, so that the tracked allocation is still owned by this box
+        // and counted as leaked once its memory is freed.
+        unsafe { ManuallyDrop::new(boxed).ptr.as_mut() }
     }
 
     /// Returns a `NonNull` pointer to the `BumpBox`'s contents.
@@ -420,7 +422,14 @@ impl<'a, T: ?Sized> BumpBox<'a, T> {
     #[inline(always)]
     #[must_use = "use `leak` if you don't make use of the pointer"]
     pub fn into_raw(self) -> NonNull<T> {
-        ManuallyDrop::new(self).ptr
+        let ptr = ManuallyDrop::new(self).ptr;
+
+        #[cfg(feature = "track-allocations")]
+        if mem::needs_drop::<T>() {
+            crate::tracking::box_released(ptr.cast::<u8>().addr().get());
+        }
+
+        ptr
     }
 
     /// Constructs a `BumpBox` from a raw pointer.
@@ -477,6 +486,11 @@ impl<'a, T: ?Sized> BumpBox<'a, T> {
     #[must_use]
     #[inline(always)]
     pub unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+        #[cfg(feature = "track-allocations")]
+        if mem::needs_drop::<T>() {
+            crate::tracking::box_created(ptr.cast::<u8>().addr().get());
+        }
+
         Self {
             ptr,
             marker: PhantomData,
@@ -2915,6 +2929,11 @@ where
 unsafe impl<#[may_dangle] T: ?Sized> Drop for BumpBox<'_, T> {
     #[inline(always)]
     fn drop(&mut self) {
+        #[cfg(feature = "track-allocations")]
+        if mem::needs_drop::<T>() {
+            crate::tracking::box_released(self.ptr.cast::<u8>().addr().get());
+        }
+
         unsafe { self.ptr.drop_in_place() }
     }
 }
@@ -2923,6 +2942,11 @@ unsafe impl<#[may_dangle] T: ?Sized> Drop for BumpBox<'_, T> {
 impl<T: ?Sized> Drop for BumpBox<'_, T> {
     #[inline(always)]
     fn drop(&mut self) {
+        #[cfg(feature = "track-allocations")]
+        if mem::needs_drop::<T>() {
+            crate::tracking::box_released(self.ptr.cast::<u8>().addr().get());
+        }
+
         unsafe { self.ptr.drop_in_place() }
     }
 }
@@ -3218,8 +3242,7 @@ impl<'a, T> IntoIterator for BumpBox<'a, [T]> {
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        let this = ManuallyDrop::new(self);
-        unsafe { owned_slice::IntoIter::new(this.ptr) }
+        unsafe { owned_slice::IntoIter::new(self.into_raw()) }
     }
 }
 
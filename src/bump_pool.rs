@@ -1,21 +1,29 @@
-use std::{
+use core::{
     alloc::Layout,
+    iter,
     mem::{self, ManuallyDrop},
     ops::{Deref, DerefMut},
-    sync::{Mutex, MutexGuard, PoisonError},
-    vec::Vec,
 };
 
+#[cfg(target_has_atomic = "ptr")]
+use alloc_crate::sync::Arc;
+use alloc_crate::vec::Vec;
+
 use crate::{
-    Bump, BumpScope, ErrorBehavior,
+    BaseAllocator, Bump, BumpScope, BumpScopeWithLimit, Checkpoint, ErrorBehavior,
     alloc::{AllocError, Allocator},
     maybe_default_allocator,
-    settings::{BumpAllocatorSettings, BumpSettings},
+    settings::{BumpAllocatorSettings, BumpObserver, BumpSettings},
+    stats::{AnyStats, Chunk},
 };
 
 #[cfg(feature = "panic-on-alloc")]
 use crate::panic_on_error;
 
+use lock::{Lock, LockGuard};
+
+mod lock;
+
 macro_rules! make_pool {
     ($($allocator_parameter:tt)*) => {
         /// A pool of bump allocators.
@@ -77,24 +85,33 @@ macro_rules! make_pool {
             A: Allocator,
             S: BumpAllocatorSettings,
         {
-            bumps: Mutex<Vec<Bump<A, S>>>,
+            state: Lock<PoolState<A, S>>,
             allocator: A,
+            max_retained_bumps: usize,
+            max_retained_bump_size: usize,
         }
     };
 }
 
 maybe_default_allocator!(make_pool);
 
+#[derive(Debug)]
+struct PoolState<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    bumps: Vec<Bump<A, S>>,
+    checked_out: BumpPoolStats,
+}
+
 impl<A, S> Default for BumpPool<A, S>
 where
     A: Allocator + Default,
     S: BumpAllocatorSettings,
 {
     fn default() -> Self {
-        Self {
-            bumps: Mutex::default(),
-            allocator: Default::default(),
-        }
+        Self::new_in(Default::default())
     }
 }
 
@@ -121,35 +138,263 @@ where
     #[must_use]
     pub const fn new_in(allocator: A) -> Self {
         Self {
-            bumps: Mutex::new(Vec::new()),
+            state: Lock::new(PoolState {
+                bumps: Vec::new(),
+                checked_out: BumpPoolStats::ZERO,
+            }),
             allocator,
+            max_retained_bumps: usize::MAX,
+            max_retained_bump_size: usize::MAX,
         }
     }
 
+    /// Sets the maximum number of `Bump`s this pool retains.
+    ///
+    /// This does not limit how many `Bump`s can be checked out at the same time.
+    /// A `BumpPool` creates as many `Bump`s as are requested concurrently.
+    ///
+    /// When a guard returns a `Bump` that has no live allocations while the pool already
+    /// holds this many `Bump`s, the returned `Bump` is deallocated.
+    /// The memory of a `Bump` with allocations can't be released while those may still be alive,
+    /// so it is kept until the pool is [reset](Self::reset), which deallocates any `Bump` exceeding the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// let mut pool: BumpPool = BumpPool::new().with_max_retained_bumps(1);
+    ///
+    /// let a = pool.get();
+    /// let b = pool.get();
+    /// let c = pool.get();
+    /// a.alloc_str("a");
+    /// b.alloc_str("b");
+    /// drop((a, b, c));
+    ///
+    /// // `c` had no allocations, so it was deallocated right away
+    /// assert_eq!(pool.bumps().len(), 2);
+    ///
+    /// pool.reset();
+    /// assert_eq!(pool.bumps().len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_max_retained_bumps(mut self, max_retained_bumps: usize) -> Self {
+        self.max_retained_bumps = max_retained_bumps;
+        self
+    }
+
+    /// Sets the maximum [size](crate::stats::Stats::size) in bytes of the chunks of a single `Bump` this pool retains.
+    ///
+    /// This is not a cap on the memory a guard can use. A [`BumpPoolGuard`] is free to grow its `Bump`
+    /// beyond this limit, the excess is only given back once the `Bump` is returned.
+    /// To cap the memory of a guard, use [`get_scoped_with_limit`](Self::get_scoped_with_limit).
+    ///
+    /// When a guard returns its `Bump`, the unused chunks that exceed the limit are deallocated,
+    /// and the whole `Bump` if it has no live allocations and that's not enough.
+    /// When the pool is [reset](Self::reset), the same happens for every `Bump` of the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// let mut pool: BumpPool = BumpPool::new().with_max_retained_bump_size(1024);
+    ///
+    /// let a = pool.get();
+    /// let b = pool.get();
+    /// a.alloc_uninit_slice::<u8>(4096);
+    /// b.alloc_uninit_slice::<u8>(16);
+    /// drop((a, b));
+    /// assert_eq!(pool.bumps().len(), 2);
+    ///
+    /// pool.reset();
+    /// assert_eq!(pool.bumps().len(), 1);
+    /// assert!(pool.stats().size() <= 1024);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_max_retained_bump_size(mut self, max_retained_bump_size: usize) -> Self {
+        self.max_retained_bump_size = max_retained_bump_size;
+        self
+    }
+
+    /// Returns the maximum number of `Bump`s this pool retains.
+    ///
+    /// See [`with_max_retained_bumps`](Self::with_max_retained_bumps).
+    #[inline]
+    #[must_use]
+    pub const fn max_retained_bumps(&self) -> usize {
+        self.max_retained_bumps
+    }
+
+    /// Returns the maximum size of a single `Bump` this pool retains.
+    ///
+    /// See [`with_max_retained_bump_size`](Self::with_max_retained_bump_size).
+    #[inline]
+    #[must_use]
+    pub const fn max_retained_bump_size(&self) -> usize {
+        self.max_retained_bump_size
+    }
+
     /// [`reset`](Bump::reset) all `Bump`s in this pool.
+    ///
+    /// Afterwards [`max_retained_bumps`](Self::with_max_retained_bumps) and [`max_retained_bump_size`](Self::with_max_retained_bump_size)
+    /// are applied.
     pub fn reset(&mut self) {
         for bump in self.bumps() {
             bump.reset();
         }
+
+        self.apply_limits();
     }
 
     /// [`reset_to_start`](Bump::reset_to_start) all `Bump`s in this pool.
+    ///
+    /// Afterwards [`max_retained_bumps`](Self::with_max_retained_bumps) and [`max_retained_bump_size`](Self::with_max_retained_bump_size)
+    /// are applied.
     pub fn reset_to_start(&mut self) {
         for bump in self.bumps() {
             bump.reset_to_start();
         }
+
+        self.apply_limits();
+    }
+
+    /// Deallocates all but `keep` of the `Bump`s in this pool.
+    ///
+    /// Unlike [`reset`](Self::reset), this does not affect the `Bump`s that are retained.
+    ///
+    /// Returns the combined [size](crate::stats::Stats::size) of the deallocated `Bump`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// let mut pool: BumpPool = BumpPool::new();
+    ///
+    /// let guards = [pool.get(), pool.get(), pool.get()];
+    /// let size = guards[2].stats().size();
+    /// drop(guards);
+    ///
+    /// assert_eq!(pool.trim(2), size);
+    /// assert_eq!(pool.bumps().len(), 2);
+    /// ```
+    pub fn trim(&mut self, keep: usize) -> usize {
+        let bumps = self.bumps();
+
+        if bumps.len() <= keep {
+            return 0;
+        }
+
+        bumps.drain(keep..).map(|bump| bump.stats().size()).sum()
     }
 
     /// Returns the vector of `Bump`s.
     pub fn bumps(&mut self) -> &mut Vec<Bump<A, S>> {
-        self.bumps.get_mut().unwrap_or_else(PoisonError::into_inner)
+        &mut self.state.get_mut().bumps
+    }
+
+    /// Returns statistics about the memory usage of all `Bump`s of this pool.
+    ///
+    /// This includes the `Bump`s that are currently borrowed by a [`BumpPoolGuard`].
+    /// Those can't be inspected while they are in use, so they are accounted for with
+    /// the statistics they had when they were borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// let pool: BumpPool = BumpPool::new();
+    ///
+    /// let guard = pool.get();
+    /// let size = guard.stats().size();
+    /// guard.alloc_str("hello");
+    ///
+    /// let stats = pool.stats();
+    /// assert_eq!(stats.bumps(), 1);
+    /// assert_eq!(stats.checked_out(), 1);
+    /// assert_eq!(stats.size(), size);
+    /// assert_eq!(stats.allocated(), 0);
+    ///
+    /// drop(guard);
+    ///
+    /// let stats = pool.stats();
+    /// assert_eq!(stats.checked_out(), 0);
+    /// assert_eq!(stats.allocated(), 5);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> BumpPoolStats {
+        let pool = self.lock();
+        let mut stats = pool.checked_out;
+
+        for bump in &pool.bumps {
+            stats.add(BumpPoolStats::of(bump));
+        }
+
+        stats
     }
 
-    fn lock(&self) -> MutexGuard<'_, Vec<Bump<A, S>>> {
-        self.bumps.lock().unwrap_or_else(PoisonError::into_inner)
+    fn apply_limits(&mut self) {
+        let max_retained_bumps = self.max_retained_bumps;
+        let max_retained_bump_size = self.max_retained_bump_size;
+        let bumps = self.bumps();
+
+        bumps.truncate(max_retained_bumps);
+        bumps.retain(|bump| trim_to_retained_size(bump, max_retained_bump_size));
+    }
+
+    fn lock(&self) -> LockGuard<'_, PoolState<A, S>> {
+        self.state.lock()
+    }
+
+    fn return_bump(&self, bump: Bump<A, S>, stats: BumpPoolStats) {
+        // Deallocating chunks notifies the observer, so we do that without holding the lock.
+        let retain = trim_to_retained_size(&bump, self.max_retained_bump_size);
+
+        let mut pool = self.lock();
+        pool.checked_out.sub(stats);
+
+        let is_empty = bump.stats().allocated() == 0;
+        let retained = pool.bumps.len() + pool.checked_out.checked_out;
+
+        if retain && !(is_empty && retained >= self.max_retained_bumps) {
+            pool.bumps.push(bump);
+            return;
+        }
+
+        drop(pool);
+        drop(bump);
     }
 }
 
+/// Deallocates the unused chunks of `bump` that don't fit into `max_size`.
+///
+/// Returns `false` if `bump` has no live allocations but is still bigger than `max_size`,
+/// in which case it should be deallocated as a whole.
+fn trim_to_retained_size<A, S>(bump: &Bump<A, S>, max_size: usize) -> bool
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    let stats = bump.stats();
+
+    // The current chunk and the ones before it may hold live allocations.
+    let used_size: usize = iter::successors(stats.current_chunk(), |chunk| chunk.prev())
+        .map(Chunk::size)
+        .sum();
+
+    if used_size > max_size {
+        bump.trim();
+
+        // The memory of a `Bump` with allocations can't be released
+        // while those may still be alive.
+        return stats.allocated() != 0;
+    }
+
+    bump.trim_to(max_size - used_size);
+    true
+}
+
 impl<A, S> BumpPool<A, S>
 where
     A: Allocator + Clone,
@@ -168,15 +413,7 @@ where
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
     pub fn get(&self) -> BumpPoolGuard<'_, A, S> {
-        let bump = match self.lock().pop() {
-            Some(bump) => bump,
-            None => Bump::new_in(self.allocator.clone()),
-        };
-
-        BumpPoolGuard {
-            pool: self,
-            bump: ManuallyDrop::new(bump),
-        }
+        panic_on_error(self.generic_get_with_size(S::MINIMUM_CHUNK_SIZE))
     }
 
     /// Borrows a bump allocator from the pool.
@@ -190,15 +427,7 @@ where
     /// Errors if the allocation fails.
     #[inline(always)]
     pub fn try_get(&self) -> Result<BumpPoolGuard<'_, A, S>, AllocError> {
-        let bump = match self.lock().pop() {
-            Some(bump) => bump,
-            None => Bump::try_new_in(self.allocator.clone())?,
-        };
-
-        Ok(BumpPoolGuard {
-            pool: self,
-            bump: ManuallyDrop::new(bump),
-        })
+        self.generic_get_with_size(S::MINIMUM_CHUNK_SIZE)
     }
 
     /// Borrows a bump allocator from the pool.
@@ -232,15 +461,7 @@ where
     }
 
     pub(crate) fn generic_get_with_size<E: ErrorBehavior>(&self, size: usize) -> Result<BumpPoolGuard<'_, A, S>, E> {
-        let bump = match self.lock().pop() {
-            Some(bump) => bump,
-            None => Bump::generic_with_size_in(size, self.allocator.clone())?,
-        };
-
-        Ok(BumpPoolGuard {
-            pool: self,
-            bump: ManuallyDrop::new(bump),
-        })
+        self.checkout(|allocator| Bump::generic_with_size_in(size, allocator))
     }
 
     /// Borrows a bump allocator from the pool.
@@ -274,18 +495,363 @@ where
     }
 
     pub(crate) fn generic_get_with_capacity<E: ErrorBehavior>(&self, layout: Layout) -> Result<BumpPoolGuard<'_, A, S>, E> {
-        let bump = match self.lock().pop() {
-            Some(bump) => bump,
-            None => Bump::generic_with_capacity_in(layout, self.allocator.clone())?,
-        };
+        self.checkout(|allocator| Bump::generic_with_capacity_in(layout, allocator))
+    }
+
+    fn checkout<E: ErrorBehavior>(
+        &self,
+        new: impl FnOnce(A) -> Result<Bump<A, S>, E>,
+    ) -> Result<BumpPoolGuard<'_, A, S>, E> {
+        let (bump, stats) = self.take_bump(new)?;
 
         Ok(BumpPoolGuard {
             pool: self,
             bump: ManuallyDrop::new(bump),
+            stats,
+        })
+    }
+
+    fn take_bump<E: ErrorBehavior>(
+        &self,
+        new: impl FnOnce(A) -> Result<Bump<A, S>, E>,
+    ) -> Result<(Bump<A, S>, BumpPoolStats), E> {
+        let mut pool = self.lock();
+
+        let bump = match pool.bumps.pop() {
+            Some(bump) => bump,
+            None => new(self.allocator.clone())?,
+        };
+
+        let stats = BumpPoolStats {
+            checked_out: 1,
+            ..BumpPoolStats::of(&bump)
+        };
+
+        pool.checked_out.add(stats);
+        Ok((bump, stats))
+    }
+
+    /// Borrows a bump allocator from a shared pool.
+    ///
+    /// Unlike a [`BumpPoolGuard`], the returned guard does not borrow the pool but keeps it alive,
+    /// so it can be moved into a spawned thread or stored in a struct. Allocations made with it
+    /// live for as long as the guard is borrowed.
+    ///
+    /// Unlike a [`BumpPoolGuard`], the returned guard derefs to a [`Bump`] rather than a [`BumpScope`],
+    /// and only immutably. A `BumpScope` would need a lifetime for its allocations, but the guard has
+    /// no borrow of the pool to tie them to, and a `&mut Bump` could be used to reset allocations made by
+    /// other guards of the pool. Use [`OwnedBumpPoolGuard::scope`] to get a `&mut BumpScope`.
+    ///
+    /// If this needs to create a new `Bump`, it will be constructed by calling <code>Bump::[new]\()</code>.
+    ///
+    /// [new]: Bump::new
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// # use std::sync::Arc;
+    /// let pool: Arc<BumpPool> = Arc::new(BumpPool::new());
+    ///
+    /// let handles = (0..4).map(|i| {
+    ///     let bump = pool.get_owned();
+    ///
+    ///     std::thread::spawn(move || {
+    ///         let string = bump.alloc_fmt(format_args!("thread {i}"));
+    ///         string.len()
+    ///     })
+    /// }).collect::<Vec<_>>();
+    ///
+    /// for handle in handles {
+    ///     assert_eq!(handle.join().unwrap(), 8);
+    /// }
+    ///
+    /// assert_eq!(pool.stats().checked_out(), 0);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    #[cfg(all(feature = "panic-on-alloc", target_has_atomic = "ptr"))]
+    pub fn get_owned(self: &Arc<Self>) -> OwnedBumpPoolGuard<A, S> {
+        panic_on_error(self.generic_get_owned())
+    }
+
+    /// Borrows a bump allocator from a shared pool.
+    ///
+    /// Unlike a [`BumpPoolGuard`], the returned guard does not borrow the pool but keeps it alive,
+    /// so it can be moved into a spawned thread or stored in a struct. Allocations made with it
+    /// live for as long as the guard is borrowed.
+    ///
+    /// Unlike a [`BumpPoolGuard`], the returned guard derefs to a [`Bump`] rather than a [`BumpScope`],
+    /// and only immutably. A `BumpScope` would need a lifetime for its allocations, but the guard has
+    /// no borrow of the pool to tie them to, and a `&mut Bump` could be used to reset allocations made by
+    /// other guards of the pool. Use [`OwnedBumpPoolGuard::scope`] to get a `&mut BumpScope`.
+    ///
+    /// If this needs to create a new `Bump`, it will be constructed by calling <code>Bump::[try_new]\()</code>.
+    ///
+    /// [try_new]: Bump::try_new
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn try_get_owned(self: &Arc<Self>) -> Result<OwnedBumpPoolGuard<A, S>, AllocError> {
+        self.generic_get_owned()
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    pub(crate) fn generic_get_owned<E: ErrorBehavior>(self: &Arc<Self>) -> Result<OwnedBumpPoolGuard<A, S>, E> {
+        let (bump, stats) = self.take_bump(|allocator| Bump::generic_with_size_in(S::MINIMUM_CHUNK_SIZE, allocator))?;
+
+        Ok(OwnedBumpPoolGuard {
+            pool: Arc::clone(self),
+            bump: ManuallyDrop::new(bump),
+            stats,
         })
     }
 }
 
+impl<A, S> BumpPool<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    /// Borrows a bump allocator from the pool for the duration of a scope.
+    ///
+    /// Unlike with [`get`](Self::get), the allocations made with the returned guard only live
+    /// as long as the guard. When the guard is dropped, the memory of its allocations is freed
+    /// and the `Bump` is returned to the pool. This allows a long-lived pool to serve many
+    /// short tasks without ever being [reset](Self::reset) as a whole.
+    ///
+    /// If this needs to create a new `Bump`, it will be constructed by calling <code>Bump::[new]\()</code>.
+    ///
+    /// [new]: Bump::new
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// let pool: BumpPool = BumpPool::new();
+    ///
+    /// for i in 0..3 {
+    ///     let mut guard = pool.get_scoped();
+    ///     let bump = guard.scope();
+    ///     let string = bump.alloc_fmt(format_args!("request {i}"));
+    ///     assert_eq!(string, *format!("request {i}"));
+    /// }
+    ///
+    /// // the memory of the strings was freed when the guards were dropped
+    /// assert_eq!(pool.stats().allocated(), 0);
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn get_scoped(&self) -> BumpPoolScopeGuard<'_, A, S> {
+        BumpPoolScopeGuard::new(self.get())
+    }
+
+    /// Borrows a bump allocator from the pool for the duration of a scope.
+    ///
+    /// Unlike with [`try_get`](Self::try_get), the allocations made with the returned guard only live
+    /// as long as the guard. When the guard is dropped, the memory of its allocations is freed
+    /// and the `Bump` is returned to the pool. This allows a long-lived pool to serve many
+    /// short tasks without ever being [reset](Self::reset) as a whole.
+    ///
+    /// If this needs to create a new `Bump`, it will be constructed by calling <code>Bump::[try_new]\()</code>.
+    ///
+    /// [try_new]: Bump::try_new
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::BumpPool;
+    /// let pool: BumpPool = BumpPool::new();
+    ///
+    /// for i in 0..3 {
+    ///     let mut guard = pool.try_get_scoped()?;
+    ///     let bump = guard.scope();
+    ///     let string = bump.try_alloc_fmt(format_args!("request {i}"))?;
+    ///     assert_eq!(string, *format!("request {i}"));
+    /// }
+    ///
+    /// // the memory of the strings was freed when the guards were dropped
+    /// assert_eq!(pool.stats().allocated(), 0);
+    /// # Ok::<(), bump_scope::alloc::AllocError>(())
+    /// ```
+    #[inline(always)]
+    pub fn try_get_scoped(&self) -> Result<BumpPoolScopeGuard<'_, A, S>, AllocError> {
+        Ok(BumpPoolScopeGuard::new(self.try_get()?))
+    }
+
+    /// Borrows a bump allocator from the pool for the duration of a scope,
+    /// allowing it to use at most `limit` bytes.
+    ///
+    /// This works like [`get_scoped`](Self::get_scoped), but allocations through the
+    /// [`BumpScopeWithLimit`] of the returned guard fail once they would make the guard
+    /// use more than `limit` bytes. The guard can't grow its `Bump` any further than that.
+    ///
+    /// If this needs to create a new `Bump`, it will be constructed by calling <code>Bump::[new]\()</code>.
+    ///
+    /// [new]: Bump::new
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::{BumpPool, traits::BumpAllocatorTypedScope};
+    /// let pool: BumpPool = BumpPool::new();
+    ///
+    /// let mut guard = pool.get_scoped_with_limit(1024);
+    /// let bump = guard.scope();
+    /// bump.alloc_str("hello");
+    /// assert!(bump.try_alloc_uninit_slice::<u8>(1024).is_err());
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn get_scoped_with_limit(&self, limit: usize) -> BumpPoolScopeGuardWithLimit<'_, A, S> {
+        BumpPoolScopeGuardWithLimit::new(self.get_scoped(), limit)
+    }
+
+    /// Borrows a bump allocator from the pool for the duration of a scope,
+    /// allowing it to use at most `limit` bytes.
+    ///
+    /// This works like [`try_get_scoped`](Self::try_get_scoped), but allocations through the
+    /// [`BumpScopeWithLimit`] of the returned guard fail once they would make the guard
+    /// use more than `limit` bytes. The guard can't grow its `Bump` any further than that.
+    ///
+    /// If this needs to create a new `Bump`, it will be constructed by calling <code>Bump::[try_new]\()</code>.
+    ///
+    /// [try_new]: Bump::try_new
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::{BumpPool, traits::BumpAllocatorTypedScope};
+    /// let pool: BumpPool = BumpPool::new();
+    ///
+    /// let mut guard = pool.try_get_scoped_with_limit(1024)?;
+    /// let bump = guard.scope();
+    /// bump.try_alloc_str("hello")?;
+    /// assert!(bump.try_alloc_uninit_slice::<u8>(1024).is_err());
+    /// # Ok::<(), bump_scope::alloc::AllocError>(())
+    /// ```
+    #[inline(always)]
+    pub fn try_get_scoped_with_limit(&self, limit: usize) -> Result<BumpPoolScopeGuardWithLimit<'_, A, S>, AllocError> {
+        Ok(BumpPoolScopeGuardWithLimit::new(self.try_get_scoped()?, limit))
+    }
+}
+
+/// Statistics about the memory usage of the `Bump`s of a [`BumpPool`].
+///
+/// Returned by [`BumpPool::stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BumpPoolStats {
+    bumps: usize,
+    checked_out: usize,
+    chunks: usize,
+    size: usize,
+    capacity: usize,
+    allocated: usize,
+}
+
+impl BumpPoolStats {
+    const ZERO: Self = Self {
+        bumps: 0,
+        checked_out: 0,
+        chunks: 0,
+        size: 0,
+        capacity: 0,
+        allocated: 0,
+    };
+
+    fn of<A, S>(bump: &Bump<A, S>) -> Self
+    where
+        A: Allocator,
+        S: BumpAllocatorSettings,
+    {
+        let stats = bump.stats();
+
+        Self {
+            bumps: 1,
+            checked_out: 0,
+            chunks: stats.count(),
+            size: stats.size(),
+            capacity: stats.capacity(),
+            allocated: stats.allocated(),
+        }
+    }
+
+    fn add(&mut self, other: Self) {
+        self.bumps += other.bumps;
+        self.checked_out += other.checked_out;
+        self.chunks += other.chunks;
+        self.size += other.size;
+        self.capacity += other.capacity;
+        self.allocated += other.allocated;
+    }
+
+    fn sub(&mut self, other: Self) {
+        self.bumps -= other.bumps;
+        self.checked_out -= other.checked_out;
+        self.chunks -= other.chunks;
+        self.size -= other.size;
+        self.capacity -= other.capacity;
+        self.allocated -= other.allocated;
+    }
+
+    /// Returns the number of `Bump`s, including the checked out ones.
+    #[must_use]
+    pub fn bumps(&self) -> usize {
+        self.bumps
+    }
+
+    /// Returns the number of `Bump`s that are currently borrowed by a [`BumpPoolGuard`].
+    #[must_use]
+    pub fn checked_out(&self) -> usize {
+        self.checked_out
+    }
+
+    /// Returns the total number of chunks.
+    #[must_use]
+    pub fn chunks(&self) -> usize {
+        self.chunks
+    }
+
+    /// Returns the total size of all chunks.
+    ///
+    /// This is the amount of memory the pool holds.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the total capacity of all chunks.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the amount of allocated bytes.
+    ///
+    /// See [`Stats::allocated`](crate::stats::Stats::allocated).
+    #[must_use]
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+}
+
 macro_rules! make_pool_guard {
     ($($allocator_parameter:tt)*) => {
 
@@ -298,6 +864,7 @@ macro_rules! make_pool_guard {
         {
             bump: ManuallyDrop<Bump<A, S>>,
             pool: &'a BumpPool<A, S>,
+            stats: BumpPoolStats,
         }
     };
 }
@@ -358,7 +925,79 @@ where
 {
     fn drop(&mut self) {
         let bump = unsafe { ManuallyDrop::take(&mut self.bump) };
-        self.pool.lock().push(bump);
+        self.pool.return_bump(bump, self.stats);
+    }
+}
+
+macro_rules! make_owned_pool_guard {
+    ($($allocator_parameter:tt)*) => {
+        /// This is a wrapper around [`Bump`] that keeps its [`BumpPool`] alive and returns its [`Bump`] back to the pool on drop.
+        ///
+        /// Returned from [`BumpPool::get_owned`].
+        ///
+        /// Since this guard is not tied to a borrow of the pool, the allocations made with it can only live
+        /// as long as the guard is borrowed. That's why it derefs to a [`Bump`] rather than a `BumpScope`.
+        /// Use [`scope`](Self::scope) to get a `BumpScope`.
+        #[derive(Debug)]
+        pub struct OwnedBumpPoolGuard<$($allocator_parameter)*, S = BumpSettings>
+        where
+            A: Allocator,
+            S: BumpAllocatorSettings,
+        {
+            bump: ManuallyDrop<Bump<A, S>>,
+            pool: Arc<BumpPool<A, S>>,
+            stats: BumpPoolStats,
+        }
+    };
+}
+
+#[cfg(target_has_atomic = "ptr")]
+maybe_default_allocator!(make_owned_pool_guard);
+
+#[cfg(target_has_atomic = "ptr")]
+impl<A, S> OwnedBumpPoolGuard<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    /// The [`BumpPool`], this [`OwnedBumpPoolGuard`] was created from.
+    pub fn pool(&self) -> &Arc<BumpPool<A, S>> {
+        &self.pool
+    }
+
+    /// Returns a `BumpScope` whose allocations live as long as this guard is borrowed.
+    #[inline(always)]
+    pub fn scope(&mut self) -> &mut BumpScope<'_, A, S> {
+        self.bump.as_mut_scope()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<A, S> Deref for OwnedBumpPoolGuard<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    type Target = Bump<A, S>;
+
+    // There is intentionally no `DerefMut` implementation.
+    // A `&mut Bump` could be reset, freeing allocations made by
+    // `BumpPoolGuard`s which live for as long as the pool.
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.bump
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<A, S> Drop for OwnedBumpPoolGuard<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    fn drop(&mut self) {
+        let bump = unsafe { ManuallyDrop::take(&mut self.bump) };
+        self.pool.return_bump(bump, self.stats);
     }
 }
 
@@ -379,3 +1018,126 @@ where
 {
     unsafe { mem::transmute(scope) }
 }
+
+macro_rules! make_pool_scope_guard {
+    ($($allocator_parameter:tt)*) => {
+        /// This is a wrapper around [`Bump`] that frees the memory of its allocations and
+        /// returns its [`Bump`] back to the [`BumpPool`] on drop.
+        ///
+        /// Returned from [`BumpPool::get_scoped`].
+        #[must_use]
+        #[derive(Debug)]
+        pub struct BumpPoolScopeGuard<'a, $($allocator_parameter)*, S = BumpSettings>
+        where
+            A: BaseAllocator<S::GuaranteedAllocated>,
+            S: BumpAllocatorSettings,
+        {
+            guard: BumpPoolGuard<'a, A, S>,
+            checkpoint: Checkpoint,
+        }
+    };
+}
+
+maybe_default_allocator!(make_pool_scope_guard);
+
+impl<'a, A, S> BumpPoolScopeGuard<'a, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn new(guard: BumpPoolGuard<'a, A, S>) -> Self {
+        let checkpoint = guard.bump.raw.checkpoint();
+        Self { guard, checkpoint }
+    }
+
+    /// Returns a new `BumpScope`.
+    #[inline(always)]
+    pub fn scope(&mut self) -> &mut BumpScope<'_, A, S> {
+        self.guard.bump.as_mut_scope()
+    }
+
+    /// Frees the memory taken up by allocations made since creation of this guard.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        unsafe { self.guard.bump.raw.reset_to(self.checkpoint) }
+    }
+
+    /// The [`BumpPool`], this [`BumpPoolScopeGuard`] was created from.
+    pub fn pool(&self) -> &'a BumpPool<A, S> {
+        self.guard.pool
+    }
+}
+
+impl<A, S> Drop for BumpPoolScopeGuard<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.reset();
+        S::Observer::on_scope_exit(AnyStats::from(self.guard.bump.raw.stats()));
+    }
+}
+
+macro_rules! make_pool_scope_guard_with_limit {
+    ($($allocator_parameter:tt)*) => {
+        /// This is a wrapper around [`Bump`] that frees the memory of its allocations and
+        /// returns its [`Bump`] back to the [`BumpPool`] on drop.
+        /// Allocations through its [`scope`](Self::scope) can use at most [`limit`](Self::limit) bytes.
+        ///
+        /// Returned from [`BumpPool::get_scoped_with_limit`].
+        #[must_use]
+        #[derive(Debug)]
+        pub struct BumpPoolScopeGuardWithLimit<'a, $($allocator_parameter)*, S = BumpSettings>
+        where
+            A: BaseAllocator<S::GuaranteedAllocated>,
+            S: BumpAllocatorSettings,
+        {
+            guard: BumpPoolScopeGuard<'a, A, S>,
+            limit: usize,
+        }
+    };
+}
+
+maybe_default_allocator!(make_pool_scope_guard_with_limit);
+
+impl<'a, A, S> BumpPoolScopeGuardWithLimit<'a, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn new(guard: BumpPoolScopeGuard<'a, A, S>, limit: usize) -> Self {
+        Self { guard, limit }
+    }
+
+    /// Returns a new `BumpScopeWithLimit`.
+    ///
+    /// The limit applies to everything allocated since the guard was created,
+    /// across all the scopes it hands out.
+    #[inline(always)]
+    pub fn scope(&mut self) -> BumpScopeWithLimit<'_, A, S> {
+        let checkpoint = self.guard.checkpoint;
+        BumpScopeWithLimit::new(self.guard.scope(), checkpoint, self.limit)
+    }
+
+    /// Returns the maximum amount of bytes this guard can use.
+    #[must_use]
+    #[inline(always)]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Frees the memory taken up by allocations made since creation of this guard.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.guard.reset();
+    }
+
+    /// The [`BumpPool`], this [`BumpPoolScopeGuardWithLimit`] was created from.
+    pub fn pool(&self) -> &'a BumpPool<A, S> {
+        self.guard.pool()
+    }
+}
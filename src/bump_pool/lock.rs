@@ -0,0 +1,138 @@
+//! The lock protecting the `Bump`s of a `BumpPool`.
+//!
+//! With the `std` feature this is a `std::sync::Mutex` that ignores poisoning.
+//! Otherwise it is a spin lock so `BumpPool` can be used with just `alloc`.
+
+use core::fmt;
+
+#[cfg(feature = "std")]
+mod imp {
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    pub(crate) struct Lock<T>(Mutex<T>);
+
+    pub(crate) type LockGuard<'a, T> = MutexGuard<'a, T>;
+
+    impl<T> Lock<T> {
+        #[inline(always)]
+        pub(crate) const fn new(value: T) -> Self {
+            Self(Mutex::new(value))
+        }
+
+        #[inline(always)]
+        pub(crate) fn lock(&self) -> LockGuard<'_, T> {
+            self.0.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        #[inline(always)]
+        pub(crate) fn try_lock(&self) -> Option<LockGuard<'_, T>> {
+            match self.0.try_lock() {
+                Ok(guard) => Some(guard),
+                Err(std::sync::TryLockError::Poisoned(error)) => Some(error.into_inner()),
+                Err(std::sync::TryLockError::WouldBlock) => None,
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_mut(&mut self) -> &mut T {
+            self.0.get_mut().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    use core::{
+        cell::UnsafeCell,
+        hint,
+        ops::{Deref, DerefMut},
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    pub(crate) struct Lock<T> {
+        locked: AtomicBool,
+        value: UnsafeCell<T>,
+    }
+
+    // SAFETY: Access to `value` is synchronized by `locked`.
+    unsafe impl<T: Send> Send for Lock<T> {}
+    unsafe impl<T: Send> Sync for Lock<T> {}
+
+    impl<T> Lock<T> {
+        #[inline(always)]
+        pub(crate) const fn new(value: T) -> Self {
+            Self {
+                locked: AtomicBool::new(false),
+                value: UnsafeCell::new(value),
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn lock(&self) -> LockGuard<'_, T> {
+            while self
+                .locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                while self.locked.load(Ordering::Relaxed) {
+                    hint::spin_loop();
+                }
+            }
+
+            LockGuard(self)
+        }
+
+        #[inline(always)]
+        pub(crate) fn try_lock(&self) -> Option<LockGuard<'_, T>> {
+            self.locked
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .ok()
+                .map(|_| LockGuard(self))
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_mut(&mut self) -> &mut T {
+            self.value.get_mut()
+        }
+    }
+
+    pub(crate) struct LockGuard<'a, T>(&'a Lock<T>);
+
+    impl<T> Deref for LockGuard<'_, T> {
+        type Target = T;
+
+        #[inline(always)]
+        fn deref(&self) -> &T {
+            unsafe { &*self.0.value.get() }
+        }
+    }
+
+    impl<T> DerefMut for LockGuard<'_, T> {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut T {
+            unsafe { &mut *self.0.value.get() }
+        }
+    }
+
+    impl<T> Drop for LockGuard<'_, T> {
+        #[inline(always)]
+        fn drop(&mut self) {
+            self.0.locked.store(false, Ordering::Release);
+        }
+    }
+}
+
+pub(crate) use imp::{Lock, LockGuard};
+
+impl<T: fmt::Debug> fmt::Debug for Lock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Lock");
+
+        match self.try_lock() {
+            Some(guard) => debug.field("data", &&*guard),
+            None => debug.field("data", &format_args!("<locked>")),
+        };
+
+        debug.finish()
+    }
+}
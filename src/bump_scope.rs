@@ -12,10 +12,12 @@ use core::{
 use core::clone::CloneToUninit;
 
 use crate::{
-    BaseAllocator, BumpBox, BumpClaimGuard, BumpScopeGuard, Checkpoint, ErrorBehavior, NoDrop, SizedTypeProperties,
+    BaseAllocator, BumpBox, BumpClaimGuard, BumpScopeGuard, BumpScopeGuardWithLimit, BumpScopeWithLimit, Checkpoint,
+    ErrorBehavior, NoDrop, RedZoneError, SizedTypeProperties,
     alloc::{AllocError, Allocator},
     allocator_impl, down_align_usize, maybe_default_allocator,
     owned_slice::OwnedSlice,
+    poison,
     polyfill::{non_null, transmute_mut, transmute_ref, transmute_value},
     raw_bump::RawBump,
     settings::{BumpAllocatorSettings, BumpSettings, MinimumAlignment, SupportedMinimumAlignment},
@@ -158,6 +160,25 @@ where
         self.raw.stats()
     }
 
+    /// Sets the peak memory usage to the current memory usage.
+    ///
+    /// See [`Bump::reset_peak`](crate::Bump::reset_peak).
+    #[inline]
+    pub fn reset_peak(&self) {
+        self.raw.reset_peak();
+    }
+
+    /// Checks the red zones of all live allocations.
+    ///
+    /// See [`Bump::check_integrity`](crate::Bump::check_integrity).
+    ///
+    /// # Errors
+    /// Errors if a red zone was overwritten.
+    #[inline]
+    pub fn check_integrity(&self) -> Result<(), RedZoneError> {
+        self.raw.check_integrity()
+    }
+
     #[inline(always)]
     pub(crate) fn align<const ALIGN: usize>(&self)
     where
@@ -171,6 +192,9 @@ where
     /// This function will fail to compile if:
     /// - `NewS::MIN_ALIGN < S::MIN_ALIGN`
     /// - `NewS::UP != S::UP`
+    /// - `NewS::TRACK_PEAK != S::TRACK_PEAK`
+    /// - `NewS::TRACK_WASTE != S::TRACK_WASTE`
+    /// - `NewS::RED_ZONES != S::RED_ZONES`
     ///
     /// # Panics
     /// Panics if `!NewS::CLAIMABLE` and the bump allocator is currently [claimed].
@@ -190,6 +214,9 @@ where
     /// This function will fail to compile if:
     /// - `NewS::MIN_ALIGN != S::MIN_ALIGN`
     /// - `NewS::UP != S::UP`
+    /// - `NewS::TRACK_PEAK != S::TRACK_PEAK`
+    /// - `NewS::TRACK_WASTE != S::TRACK_WASTE`
+    /// - `NewS::RED_ZONES != S::RED_ZONES`
     /// - `NewS::CLAIMABLE != S::CLAIMABLE`
     /// - `NewS::GUARANTEED_ALLOCATED > S::GUARANTEED_ALLOCATED`
     #[inline]
@@ -206,6 +233,9 @@ where
     /// This function will fail to compile if:
     /// - `NewS::MIN_ALIGN < S::MIN_ALIGN`
     /// - `NewS::UP != S::UP`
+    /// - `NewS::TRACK_PEAK != S::TRACK_PEAK`
+    /// - `NewS::TRACK_WASTE != S::TRACK_WASTE`
+    /// - `NewS::RED_ZONES != S::RED_ZONES`
     /// - `NewS::GUARANTEED_ALLOCATED != S::GUARANTEED_ALLOCATED`
     /// - `NewS::CLAIMABLE != S::CLAIMABLE`
     #[inline]
@@ -251,6 +281,34 @@ where
 
 impl<A, S> NoDrop for BumpScope<'_, A, S> where S: BumpAllocatorSettings {}
 
+/// Methods that are always available.
+impl<A, S> BumpScope<'_, A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    /// Deallocates the unused chunks that follow the current chunk.
+    ///
+    /// Returns the combined [size](crate::stats::Chunk::size) of the deallocated chunks.
+    ///
+    /// See [`Bump::trim`](crate::Bump::trim).
+    #[inline]
+    pub fn trim(&self) -> usize {
+        self.raw.trim_to(0)
+    }
+
+    /// Deallocates the unused chunks that follow the current chunk
+    /// except for as many as fit into `max_spare_size` bytes.
+    ///
+    /// Returns the combined [size](crate::stats::Chunk::size) of the deallocated chunks.
+    ///
+    /// See [`Bump::trim_to`](crate::Bump::trim_to).
+    #[inline]
+    pub fn trim_to(&self, max_spare_size: usize) -> usize {
+        self.raw.trim_to(max_spare_size)
+    }
+}
+
 /// Methods that forward to traits.
 // Documentation is in the forwarded to methods.
 #[allow(clippy::missing_errors_doc, clippy::missing_safety_doc)]
@@ -304,6 +362,7 @@ where
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
     #[expect(clippy::missing_errors_doc)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn alloc_try_with<T, E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<BumpBox<'a, T>, E> {
         panic_on_error(self.generic_alloc_try_with(f))
     }
@@ -339,6 +398,7 @@ where
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn try_alloc_try_with<T, E>(
         &self,
         f: impl FnOnce() -> Result<T, E>,
@@ -370,7 +430,8 @@ where
             non_null::write_with(ptr, f);
 
             // If `f` made allocations on this bump allocator we can't shrink the allocation.
-            let can_shrink = pos == self.raw.chunk.get().pos();
+            // With red zones the allocation is followed by its back red zone, so we don't shrink either.
+            let can_shrink = !S::RED_ZONES && pos == self.raw.chunk.get().pos();
 
             match non_null::result(ptr) {
                 Ok(value) => Ok({
@@ -434,6 +495,7 @@ where
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
     #[expect(clippy::missing_errors_doc)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn alloc_try_with_mut<T, E>(&mut self, f: impl FnOnce() -> Result<T, E>) -> Result<BumpBox<'a, T>, E> {
         panic_on_error(self.generic_alloc_try_with_mut(f))
     }
@@ -469,6 +531,7 @@ where
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub fn try_alloc_try_with_mut<T, E>(
         &mut self,
         f: impl FnOnce() -> Result<T, E>,
@@ -488,6 +551,10 @@ where
             };
         }
 
+        if S::RED_ZONES {
+            return self.generic_alloc_try_with(f);
+        }
+
         let checkpoint = self.checkpoint();
         let ptr = self.raw.prepare_sized_allocation::<B, Result<T, E>>()?;
 
@@ -515,6 +582,10 @@ where
                 Err(error) => Err({
                     let error = error.read();
                     self.reset_to(checkpoint);
+
+                    // The bump position never moved, so the prepared memory that held the error isn't freed by `reset_to`.
+                    poison::freed::<S>(ptr.cast(), size_of::<Result<T, E>>());
+
                     error
                 }),
             }
@@ -538,6 +609,7 @@ where
     S: BumpAllocatorSettings,
 {
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
         allocator_impl::allocate(&self.raw, layout)
     }
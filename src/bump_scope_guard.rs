@@ -1,11 +1,16 @@
-use core::{fmt::Debug, num::NonZeroUsize, ptr::NonNull};
+use core::{
+    error::Error,
+    fmt::{self, Debug},
+    num::NonZeroUsize,
+    ptr::NonNull,
+};
 
 use crate::{
     BaseAllocator, BumpScope,
     chunk::ChunkHeader,
     polyfill::transmute_mut,
     raw_bump::{RawBump, RawChunk},
-    settings::{BumpAllocatorSettings, BumpSettings},
+    settings::{BumpAllocatorSettings, BumpObserver, BumpSettings},
     stats::AnyStats,
 };
 
@@ -34,6 +39,42 @@ impl Checkpoint {
     }
 }
 
+/// The error returned from [`Bump::try_reset_to`] and [`SyncBump::try_reset_to`]
+/// when a [`Checkpoint`] can't be used with the bump allocator.
+///
+/// [`Bump::try_reset_to`]: crate::Bump::try_reset_to
+/// [`SyncBump::try_reset_to`]: crate::SyncBump::try_reset_to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CheckpointError {
+    /// The checkpoint does not point into any chunk of this bump allocator.
+    ///
+    /// It was created by a different bump allocator or its chunk has since been deallocated
+    /// by a [`reset`](crate::Bump::reset) or [`trim`](crate::Bump::trim).
+    ForeignChunk,
+    /// The checkpoint lies ahead of the current bump position.
+    ///
+    /// This happens when the bump position has been reset to before the checkpoint,
+    /// for example by a [`reset`](crate::Bump::reset) or another `reset_to`.
+    AheadOfPosition,
+    /// The bump allocator is [claimed] or the checkpoint was created while it was claimed.
+    ///
+    /// [claimed]: crate::traits::BumpAllocatorScope::claim
+    Claimed,
+}
+
+impl Error for CheckpointError {}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ForeignChunk => "checkpoint does not belong to this bump allocator",
+            Self::AheadOfPosition => "checkpoint lies ahead of the current bump position",
+            Self::Claimed => "bump allocator is claimed",
+        })
+    }
+}
+
 /// Returned from [`BumpAllocator::scope_guard`].
 ///
 /// [`BumpAllocator::scope_guard`]: crate::traits::BumpAllocator::scope_guard
@@ -65,6 +106,7 @@ where
     #[inline(always)]
     fn drop(&mut self) {
         self.reset();
+        S::Observer::on_scope_exit(AnyStats::from(self.bump.stats()));
     }
 }
 
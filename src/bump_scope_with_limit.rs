@@ -0,0 +1,441 @@
+use core::{
+    alloc::Layout,
+    fmt::{self, Debug},
+    num::NonZeroUsize,
+    ops::Range,
+    ptr::NonNull,
+};
+
+use crate::{
+    BaseAllocator, BumpScope, BumpScopeGuard, Checkpoint,
+    alloc::{AllocError, Allocator},
+    allocator_impl, down_align_usize,
+    settings::{BumpAllocatorSettings, BumpSettings},
+    stats::{AnyStats, Chunk, Stats},
+    traits::BumpAllocatorCore,
+    up_align_usize_unchecked,
+};
+
+// For docs.
+#[allow(unused_imports)]
+use crate::traits::*;
+
+/// A child scope that fails to allocate once it would use more than a certain amount of bytes.
+///
+/// This is created by [`BumpAllocator::scoped_with_limit`] and [`BumpScopeGuardWithLimit::scope`].
+///
+/// The bytes used by the scope are the ones reported by
+/// <code>[stats](Self::stats)().[allocated_since](Stats::allocated_since)</code> for the checkpoint
+/// of the scope, which includes alignment padding and the free space of chunks that the scope moved past.
+/// An allocation that would take the scope over its limit fails with an [`AllocError`], or panics
+/// for the non-`try_` methods.
+/// The chunks that the bump allocator already owns are still reused.
+///
+/// The methods to allocate are provided by the [`BumpAllocatorTypedScope`] and
+/// [`MutBumpAllocatorTypedScope`] traits.
+///
+/// # Examples
+/// ```
+/// use bump_scope::{Bump, traits::BumpAllocatorTypedScope};
+/// let mut bump: Bump = Bump::new();
+///
+/// bump.scoped_with_limit(16, |bump| {
+///     bump.alloc_str("hello");
+///     assert_eq!(bump.used(), 5);
+///     assert_eq!(bump.remaining(), 11);
+///
+///     assert!(bump.try_alloc_str("this is too long").is_err());
+///     assert_eq!(bump.used(), 5);
+/// });
+/// ```
+pub struct BumpScopeWithLimit<'a, A, S = BumpSettings>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    scope: &'a mut BumpScope<'a, A, S>,
+    checkpoint: Checkpoint,
+    limit: usize,
+}
+
+impl<A, S> Debug for BumpScopeWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        AnyStats::from(self.stats()).debug_format("BumpScopeWithLimit", f)
+    }
+}
+
+impl<'a, A, S> BumpScopeWithLimit<'a, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    pub(crate) fn new(scope: &'a mut BumpScope<'a, A, S>, checkpoint: Checkpoint, limit: usize) -> Self {
+        Self {
+            scope,
+            checkpoint,
+            limit,
+        }
+    }
+
+    /// Returns the maximum amount of bytes this scope can use.
+    #[must_use]
+    #[inline(always)]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the amount of bytes this scope currently uses.
+    #[must_use]
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.stats().allocated_since(self.checkpoint).unwrap_or(0)
+    }
+
+    /// Returns the amount of bytes this scope can still use.
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.limit.saturating_sub(self.used())
+    }
+
+    /// Returns a type which provides statistics about the memory usage of the bump allocator.
+    #[must_use]
+    #[inline(always)]
+    pub fn stats(&self) -> Stats<'a, A, S> {
+        self.scope.stats()
+    }
+
+    /// Checks that allocating `layout` can't take this scope over its limit in case the
+    /// bump allocator has to allocate a new chunk for it.
+    ///
+    /// If the allocation doesn't fit into the current chunk or any spare chunk, the rest of the
+    /// current chunk and all the spare chunks are counted as used, along with the size and
+    /// the worst-case alignment padding of the allocation in the new chunk.
+    /// An allocation that fits into a chunk we already have is left to [`enforce_limit`](Self::enforce_limit).
+    fn check_limit(&self, layout: Layout) -> Result<(), AllocError> {
+        let align = layout.align().max(S::MIN_ALIGN);
+        let mut used = layout.size().saturating_add(align - 1);
+
+        if let Some(chunk) = self.stats().current_chunk() {
+            if fits_at(chunk, chunk.bump_position().addr().get(), layout) {
+                return Ok(());
+            }
+
+            used = used.saturating_add(chunk.remaining());
+
+            for spare in chunk.iter_next() {
+                let start = if S::UP { spare.content_start() } else { spare.content_end() };
+
+                if fits_at(spare, start.addr().get(), layout) {
+                    return Ok(());
+                }
+
+                used = used.saturating_add(spare.capacity());
+            }
+        }
+
+        if used > self.remaining() {
+            return Err(AllocError);
+        }
+
+        Ok(())
+    }
+
+    /// Undoes everything allocated since `checkpoint` if this scope is over its limit.
+    ///
+    /// This is the exact check that follows [`check_limit`](Self::check_limit).
+    #[inline]
+    fn enforce_limit(&self, checkpoint: Checkpoint) -> Result<(), AllocError> {
+        if self.used() <= self.limit {
+            return Ok(());
+        }
+
+        // SAFETY: the memory allocated since `checkpoint` has not been handed out
+        unsafe { self.scope.reset_to(checkpoint) };
+        Err(AllocError)
+    }
+
+    /// Grows a memory block like [`Allocator::grow`] without going over the limit.
+    ///
+    /// Growing in place is checked up front. A memory block that has to move is
+    /// allocated anew within the limit, so the old one stays untouched if that fails.
+    ///
+    /// # Safety
+    /// Same as for [`Allocator::grow`].
+    unsafe fn grow_within_limit(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe {
+            if let Some(new_pos) = allocator_impl::grow_in_place_pos(&self.scope.raw, ptr, old_layout, new_layout) {
+                // growing in place was possible, so there is a current chunk
+                let pos = self
+                    .stats()
+                    .current_chunk()
+                    .map_or(0, |chunk| chunk.bump_position().addr().get());
+
+                if new_pos.abs_diff(pos) > self.remaining() {
+                    return Err(AllocError);
+                }
+
+                return self.scope.grow(ptr, old_layout, new_layout);
+            }
+
+            let old_chunk = self.scope.raw.chunk.get();
+            let new_ptr = self.allocate(new_layout)?;
+            old_chunk.record_abandoned(old_layout.size());
+            ptr.copy_to_nonoverlapping(new_ptr.cast(), old_layout.size());
+            self.scope.deallocate(ptr, old_layout);
+            Ok(new_ptr)
+        }
+    }
+
+    /// Shrinks a prepared range so that allocating all of it stays within the limit.
+    fn limit_prepared_range(
+        &self,
+        checkpoint: Checkpoint,
+        layout: Layout,
+        range: Range<NonNull<u8>>,
+    ) -> Result<Range<NonNull<u8>>, AllocError> {
+        let remaining = self.remaining();
+        let align = layout.align().max(S::MIN_ALIGN);
+
+        // a successful `prepare_allocation` guarantees an allocated chunk
+        let pos = self
+            .stats()
+            .current_chunk()
+            .map_or(0, |chunk| chunk.bump_position().addr().get());
+
+        let (start, end) = (range.start.addr().get(), range.end.addr().get());
+
+        let (start, end) = if S::UP {
+            (start, end.min(down_align_usize(pos.saturating_add(remaining), align)))
+        } else {
+            // up-aligning an address below `end` never overflows
+            (start.max(up_align_usize_unchecked(pos.saturating_sub(remaining), align)), end)
+        };
+
+        if end.saturating_sub(start) < layout.size() {
+            // moving on to another chunk is all that could have happened
+            unsafe { self.scope.reset_to(checkpoint) };
+            return Err(AllocError);
+        }
+
+        // SAFETY: `start` and `end` lie within the nonzero addresses of `range`
+        unsafe {
+            let start = NonZeroUsize::new_unchecked(start);
+            let end = NonZeroUsize::new_unchecked(end);
+            Ok(range.start.with_addr(start)..range.end.with_addr(end))
+        }
+    }
+}
+
+/// Returns whether `layout` fits into `chunk` when bumping from `pos`.
+fn fits_at<A, S>(chunk: Chunk<'_, A, S>, pos: usize, layout: Layout) -> bool
+where
+    S: BumpAllocatorSettings,
+{
+    let align = layout.align().max(S::MIN_ALIGN);
+
+    if S::UP {
+        let end = chunk.content_end().addr().get();
+
+        pos.checked_add(align - 1)
+            .map(|start| down_align_usize(start, align))
+            .and_then(|start| start.checked_add(layout.size()))
+            .is_some_and(|new_pos| new_pos <= end)
+    } else {
+        let start = chunk.content_start().addr().get();
+
+        pos.checked_sub(layout.size())
+            .is_some_and(|new_pos| down_align_usize(new_pos, align) >= start)
+    }
+}
+
+unsafe impl<A, S> Allocator for BumpScopeWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() > self.remaining() {
+            return Err(AllocError);
+        }
+
+        self.check_limit(layout)?;
+
+        let checkpoint = self.scope.checkpoint();
+        let ptr = self.scope.allocate(layout)?;
+        self.enforce_limit(checkpoint)?;
+        Ok(ptr)
+    }
+
+    #[inline]
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() > self.remaining() {
+            return Err(AllocError);
+        }
+
+        self.check_limit(layout)?;
+
+        let checkpoint = self.scope.checkpoint();
+        let ptr = self.scope.allocate_zeroed(layout)?;
+        self.enforce_limit(checkpoint)?;
+        Ok(ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.scope.deallocate(ptr, layout) };
+    }
+
+    #[inline]
+    unsafe fn grow(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { self.grow_within_limit(ptr, old_layout, new_layout) }
+    }
+
+    #[inline]
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe {
+            let new_ptr = self.grow_within_limit(ptr, old_layout, new_layout)?;
+            let delta = new_layout.size() - old_layout.size();
+            new_ptr.cast::<u8>().add(old_layout.size()).write_bytes(0, delta);
+            Ok(new_ptr)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn shrink(&self, ptr: NonNull<u8>, old_layout: Layout, new_layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { self.scope.shrink(ptr, old_layout, new_layout) }
+    }
+}
+
+unsafe impl<A, S> BumpAllocatorCore for BumpScopeWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn any_stats(&self) -> AnyStats<'_> {
+        self.stats().into()
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Checkpoint {
+        self.scope.checkpoint()
+    }
+
+    #[inline(always)]
+    unsafe fn reset_to(&self, checkpoint: Checkpoint) {
+        unsafe { self.scope.reset_to(checkpoint) };
+    }
+
+    #[inline(always)]
+    fn is_claimed(&self) -> bool {
+        self.scope.is_claimed()
+    }
+
+    #[inline]
+    fn prepare_allocation(&self, layout: Layout) -> Result<Range<NonNull<u8>>, AllocError> {
+        if layout.size() > self.remaining() {
+            return Err(AllocError);
+        }
+
+        self.check_limit(layout)?;
+
+        let checkpoint = self.scope.checkpoint();
+        let range = self.scope.prepare_allocation(layout)?;
+        self.limit_prepared_range(checkpoint, layout, range)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared(&self, layout: Layout, range: Range<NonNull<u8>>) -> NonNull<u8> {
+        // The range is only ever shrunk at the side the allocation grows towards,
+        // which the bump allocator is fine with.
+        unsafe { self.scope.allocate_prepared(layout, range) }
+    }
+
+    #[inline]
+    fn prepare_allocation_rev(&self, layout: Layout) -> Result<Range<NonNull<u8>>, AllocError> {
+        if layout.size() > self.remaining() {
+            return Err(AllocError);
+        }
+
+        self.check_limit(layout)?;
+
+        let checkpoint = self.scope.checkpoint();
+        let range = self.scope.prepare_allocation_rev(layout)?;
+        self.limit_prepared_range(checkpoint, layout, range)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared_rev(&self, layout: Layout, range: Range<NonNull<u8>>) -> NonNull<u8> {
+        unsafe { self.scope.allocate_prepared_rev(layout, range) }
+    }
+}
+
+/// Returned from [`BumpAllocator::scope_guard_with_limit`].
+///
+/// The limit applies to everything allocated since the guard was created,
+/// across all the [`scope`](Self::scope)s it hands out.
+#[must_use]
+pub struct BumpScopeGuardWithLimit<'a, A, S = BumpSettings>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    guard: BumpScopeGuard<'a, A, S>,
+    checkpoint: Checkpoint,
+    limit: usize,
+}
+
+impl<A, S> Debug for BumpScopeGuardWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.guard.fmt(f)
+    }
+}
+
+impl<'a, A, S> BumpScopeGuardWithLimit<'a, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    pub(crate) fn new(mut guard: BumpScopeGuard<'a, A, S>, limit: usize) -> Self {
+        let checkpoint = guard.scope().checkpoint();
+        Self {
+            guard,
+            checkpoint,
+            limit,
+        }
+    }
+
+    /// Returns a new `BumpScopeWithLimit`.
+    #[inline(always)]
+    pub fn scope(&mut self) -> BumpScopeWithLimit<'_, A, S> {
+        BumpScopeWithLimit::new(self.guard.scope(), self.checkpoint, self.limit)
+    }
+
+    /// Frees the memory taken up by allocations made since creation of this bump scope guard.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.guard.reset();
+    }
+}
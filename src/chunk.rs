@@ -1,7 +1,23 @@
 mod header;
+mod peak;
+mod red_zones;
 mod size;
 mod size_config;
+mod waste;
 
 pub(crate) use header::ChunkHeader;
+pub(crate) use peak::Peak;
+pub(crate) use red_zones::RedZones;
 pub(crate) use size::{ChunkSize, ChunkSizeHint};
-pub(crate) use size_config::{ChunkSizeConfig, MIN_CHUNK_ALIGN};
+pub(crate) use size_config::{ASSUMED_PAGE_SIZE, ChunkSizeConfig, MIN_CHUNK_ALIGN};
+pub(crate) use waste::Waste;
+
+use crate::settings::BumpAllocatorSettings;
+
+/// The size of the records that sit between a chunk's header and its content.
+///
+/// Going upwards the chunk looks like `[header][peak][waste][red zones][content]`,
+/// going downwards it looks like `[content][red zones][waste][peak][header]`.
+pub(crate) const fn header_extension_size<S: BumpAllocatorSettings>() -> usize {
+    Peak::size_for::<S>() + Waste::size_for::<S>() + RedZones::size_for::<S>()
+}
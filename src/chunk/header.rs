@@ -1,11 +1,21 @@
 use core::{cell::Cell, ptr::NonNull};
 
-use crate::{polyfill::non_null, settings::BumpAllocatorSettings};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{
+    polyfill::non_null,
+    settings::{BumpAllocatorSettings, BumpSettings},
+};
 
 /// The chunk header that lives at
 /// - the start of the allocation when upwards bumping
 /// - the end of the allocation when downwards bumping
 ///
+/// With the `TRACK_PEAK` setting, a [`Peak`](super::Peak) record sits between the header and the chunk's content.
+/// With the `TRACK_WASTE` setting, a [`Waste`](super::Waste) record follows.
+/// With the `RED_ZONES` setting, a [`RedZones`](super::RedZones) record follows.
+///
 /// All non-`Cell` fields are immutable.
 #[repr(C, align(16))]
 pub(crate) struct ChunkHeader<A = ()> {
@@ -64,7 +74,86 @@ macro_rules! dummy_chunk {
     };
 }
 
+impl<A> ChunkHeader<A> {
+    /// Returns the bump position.
+    ///
+    /// The position is loaded atomically if possible, so the stats of a [`SyncBump`](crate::SyncBump) can be
+    /// read while other threads allocate.
+    #[inline(always)]
+    pub(crate) fn load_pos(&self) -> NonNull<u8> {
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            // SAFETY: The position is never null.
+            unsafe { NonNull::new_unchecked(self.atomic_pos().load(Ordering::Relaxed)) }
+        }
+
+        #[cfg(not(target_has_atomic = "ptr"))]
+        {
+            self.pos.get()
+        }
+    }
+
+    /// Returns the next chunk.
+    ///
+    /// The pointer is loaded atomically if possible, see [`load_pos`](Self::load_pos).
+    #[inline(always)]
+    pub(crate) fn load_next(&self) -> Option<NonNull<Self>> {
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            NonNull::new(self.atomic_next().load(Ordering::Acquire))
+        }
+
+        #[cfg(not(target_has_atomic = "ptr"))]
+        {
+            self.next.get()
+        }
+    }
+
+    /// Sets the next chunk.
+    ///
+    /// The pointer is stored atomically if possible, see [`load_pos`](Self::load_pos).
+    #[inline(always)]
+    pub(crate) fn store_next(&self, next: Option<NonNull<Self>>) {
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            let next = next.map_or(core::ptr::null_mut(), NonNull::as_ptr);
+            self.atomic_next().store(next, Ordering::Release);
+        }
+
+        #[cfg(not(target_has_atomic = "ptr"))]
+        {
+            self.next.set(next);
+        }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline(always)]
+    pub(crate) fn atomic_pos(&self) -> &AtomicPtr<u8> {
+        // SAFETY:
+        // - `Cell<NonNull<u8>>` has the same size and bit validity as `AtomicPtr<u8>`
+        // - `pos` is the first field of this `align(16)` struct, so it is sufficiently aligned
+        unsafe { AtomicPtr::from_ptr(self.pos.as_ptr().cast()) }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline(always)]
+    fn atomic_next(&self) -> &AtomicPtr<Self> {
+        // SAFETY:
+        // - `Cell<Option<NonNull<Self>>>` has the same size and bit validity as `AtomicPtr<Self>`
+        // - `next` is preceded by three pointer sized fields in this `repr(C, align(16))` struct,
+        //   so it is aligned to the size of a pointer
+        unsafe { AtomicPtr::from_ptr(self.next.as_ptr().cast()) }
+    }
+}
+
 impl ChunkHeader {
     dummy_chunk!(unallocated);
     dummy_chunk!(claimed);
+
+    /// Returns whether `header` is the [`unallocated`](Self::unallocated) dummy chunk of either bump direction.
+    ///
+    /// The dummy chunks only depend on `S::UP`, so this works without knowing the settings.
+    pub(crate) fn is_unallocated(header: NonNull<ChunkHeader>) -> bool {
+        header == Self::unallocated::<BumpSettings<1, true>>() || header == Self::unallocated::<BumpSettings<1, false>>()
+    }
 }
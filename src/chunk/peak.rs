@@ -0,0 +1,143 @@
+#[cfg(not(target_has_atomic = "ptr"))]
+use core::cell::Cell;
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::settings::BumpAllocatorSettings;
+
+/// The peak memory usage of a bump allocator with the `TRACK_PEAK` setting.
+///
+/// Every chunk has one of these right next to its chunk header,
+/// between the header and the chunk's content.
+/// The peak of the bump allocator is the highest value among the records of all its chunks.
+/// Moving the bump position back only raises the record of the current chunk,
+/// so that takes constant time. Before chunks are deallocated, their records are
+/// merged into the record of a chunk that is kept.
+///
+/// The values are atomic if possible so the stats of a [`SyncBump`](crate::SyncBump)
+/// can be read while other threads deallocate.
+#[repr(C, align(16))]
+pub(crate) struct Peak {
+    allocated: Value,
+    capacity: Value,
+    /// The capacity of all previous chunks, which is what they add to the allocated bytes
+    /// while this chunk is the current chunk.
+    capacity_before: Value,
+}
+
+impl Peak {
+    /// The space taken up by the peak record in each chunk.
+    pub(crate) const fn size_for<S: BumpAllocatorSettings>() -> usize {
+        if S::TRACK_PEAK { size_of::<Self>() } else { 0 }
+    }
+
+    pub(crate) const fn new(capacity_before: usize) -> Self {
+        Self {
+            allocated: Value::new(0),
+            capacity: Value::new(0),
+            capacity_before: Value::new(capacity_before),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn allocated(&self) -> usize {
+        self.allocated.load()
+    }
+
+    #[inline(always)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity.load()
+    }
+
+    #[inline(always)]
+    pub(crate) fn capacity_before(&self) -> usize {
+        self.capacity_before.load()
+    }
+
+    #[inline(always)]
+    pub(crate) fn set_capacity_before(&self, capacity_before: usize) {
+        self.capacity_before.store(capacity_before);
+    }
+
+    /// Raises the peak to the given values.
+    #[inline(always)]
+    pub(crate) fn update(&self, allocated: usize, capacity: usize) {
+        self.allocated.fetch_max(allocated);
+        self.capacity.fetch_max(capacity);
+    }
+
+    /// Raises the allocated bytes of the peak to the given amount of bytes allocated in the chunk of this record.
+    #[inline(always)]
+    pub(crate) fn update_allocated_in_chunk(&self, allocated: usize) {
+        self.allocated.fetch_max(self.capacity_before() + allocated);
+    }
+
+    #[inline(always)]
+    pub(crate) fn set(&self, allocated: usize, capacity: usize) {
+        self.allocated.store(allocated);
+        self.capacity.store(capacity);
+    }
+}
+
+/// A counter that is atomic if possible.
+#[cfg(target_has_atomic = "ptr")]
+pub(super) struct Value(AtomicUsize);
+
+#[cfg(target_has_atomic = "ptr")]
+impl Value {
+    pub(super) const fn new(value: usize) -> Self {
+        Self(AtomicUsize::new(value))
+    }
+
+    #[inline(always)]
+    pub(super) fn load(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub(super) fn store(&self, value: usize) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+
+    #[inline(always)]
+    pub(super) fn fetch_max(&self, value: usize) {
+        self.0.fetch_max(value, Ordering::Relaxed);
+    }
+
+    #[inline(always)]
+    pub(super) fn fetch_add(&self, value: usize) {
+        self.0.fetch_add(value, Ordering::Relaxed);
+    }
+}
+
+/// A counter that is atomic if possible.
+#[cfg(not(target_has_atomic = "ptr"))]
+pub(super) struct Value(Cell<usize>);
+
+#[cfg(not(target_has_atomic = "ptr"))]
+impl Value {
+    pub(super) const fn new(value: usize) -> Self {
+        Self(Cell::new(value))
+    }
+
+    #[inline(always)]
+    pub(super) fn load(&self) -> usize {
+        self.0.get()
+    }
+
+    #[inline(always)]
+    pub(super) fn store(&self, value: usize) {
+        self.0.set(value);
+    }
+
+    #[inline(always)]
+    pub(super) fn fetch_max(&self, value: usize) {
+        self.0.set(self.0.get().max(value));
+    }
+
+    #[inline(always)]
+    pub(super) fn fetch_add(&self, value: usize) {
+        self.0.set(self.0.get().wrapping_add(value));
+    }
+}
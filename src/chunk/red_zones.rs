@@ -0,0 +1,33 @@
+use core::{cell::Cell, ptr::NonNull};
+
+use crate::{red_zone::Guard, settings::BumpAllocatorSettings};
+
+/// The guard of the most recent allocation in a chunk, kept for the `RED_ZONES` setting.
+///
+/// Every chunk has one of these between the [`Waste`](super::Waste) record (if there is one) and the chunk's content.
+/// The guards of the chunk's allocations form a linked list that starts here, see [`red_zone`](crate::red_zone).
+#[repr(C, align(16))]
+pub(crate) struct RedZones {
+    last: Cell<Option<NonNull<Guard>>>,
+}
+
+impl RedZones {
+    /// The space taken up by the red zones record in each chunk.
+    pub(crate) const fn size_for<S: BumpAllocatorSettings>() -> usize {
+        if S::RED_ZONES { size_of::<Self>() } else { 0 }
+    }
+
+    pub(crate) const fn new() -> Self {
+        Self { last: Cell::new(None) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn last(&self) -> Option<NonNull<Guard>> {
+        self.last.get()
+    }
+
+    #[inline(always)]
+    pub(crate) fn set_last(&self, guard: Option<NonNull<Guard>>) {
+        self.last.set(guard);
+    }
+}
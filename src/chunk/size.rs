@@ -1,7 +1,7 @@
 use core::{alloc::Layout, marker::PhantomData, num::NonZeroUsize};
 
 use crate::{
-    chunk::{ChunkHeader, ChunkSizeConfig, MIN_CHUNK_ALIGN},
+    chunk::{ChunkHeader, ChunkSizeConfig, MIN_CHUNK_ALIGN, header_extension_size},
     settings::BumpAllocatorSettings,
 };
 
@@ -17,7 +17,20 @@ where
     ChunkSizeConfig {
         up: S::UP,
         assumed_malloc_overhead_layout: Layout::new::<AssumedMallocOverhead>(),
-        chunk_header_layout: Layout::new::<ChunkHeader<A>>(),
+        chunk_header_layout: chunk_header_layout::<A, S>(),
+    }
+}
+
+/// The layout of the chunk header including the records that follow it, see [`header_extension_size`].
+const fn chunk_header_layout<A, S>() -> Layout
+where
+    S: BumpAllocatorSettings,
+{
+    let header = Layout::new::<ChunkHeader<A>>();
+
+    match Layout::from_size_align(header.size() + header_extension_size::<S>(), header.align()) {
+        Ok(ok) => ok,
+        Err(_) => panic!("chunk header is too big"),
     }
 }
 
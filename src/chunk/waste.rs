@@ -0,0 +1,55 @@
+use crate::settings::BumpAllocatorSettings;
+
+use super::peak::Value;
+
+/// The memory a chunk lost to padding and to abandoned reallocations, kept for the `TRACK_WASTE` setting.
+///
+/// Every chunk has one of these between the chunk header (and the [`Peak`](super::Peak) record if there is one)
+/// and the chunk's content. The counters are reset when the chunk is reset.
+///
+/// The values are atomic if possible so a [`SyncBump`](crate::SyncBump) can update them from multiple threads.
+#[repr(C, align(16))]
+pub(crate) struct Waste {
+    padding: Value,
+    abandoned: Value,
+}
+
+impl Waste {
+    /// The space taken up by the waste record in each chunk.
+    pub(crate) const fn size_for<S: BumpAllocatorSettings>() -> usize {
+        if S::TRACK_WASTE { size_of::<Self>() } else { 0 }
+    }
+
+    pub(crate) const fn new() -> Self {
+        Self {
+            padding: Value::new(0),
+            abandoned: Value::new(0),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn padding(&self) -> usize {
+        self.padding.load()
+    }
+
+    #[inline(always)]
+    pub(crate) fn abandoned(&self) -> usize {
+        self.abandoned.load()
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_padding(&self, bytes: usize) {
+        self.padding.fetch_add(bytes);
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_abandoned(&self, bytes: usize) {
+        self.abandoned.fetch_add(bytes);
+    }
+
+    #[inline(always)]
+    pub(crate) fn clear(&self) {
+        self.padding.store(0);
+        self.abandoned.store(0);
+    }
+}
@@ -70,6 +70,13 @@ impl_allocator_via_allocator! {
         S: BumpAllocatorSettings,
     ]
 
+    #[cfg(target_has_atomic = "ptr")]
+    use {self} for allocator_api2_03 as crate impl[A, S] crate::SyncBump<A, S>
+    where [
+        A: BaseAllocator<S::GuaranteedAllocated>,
+        S: BumpAllocatorSettings,
+    ]
+
     use {self} for allocator_api2_03 as crate impl[A, S] &mut Bump<A, S>
     where [
         A: BaseAllocator<S::GuaranteedAllocated>,
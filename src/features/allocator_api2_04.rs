@@ -70,6 +70,13 @@ impl_allocator_via_allocator! {
         S: BumpAllocatorSettings,
     ]
 
+    #[cfg(target_has_atomic = "ptr")]
+    use {self} for allocator_api2_04 as crate impl[A, S] crate::SyncBump<A, S>
+    where [
+        A: BaseAllocator<S::GuaranteedAllocated>,
+        S: BumpAllocatorSettings,
+    ]
+
     use {self} for allocator_api2_04 as crate impl[A: BumpAllocatorCore] WithoutShrink<A>
     use {self} for allocator_api2_04 as crate impl[A: BumpAllocatorCore] WithoutDealloc<A>
 }
@@ -98,6 +98,7 @@ macro_rules! bytemuck_or_zerocopy {
                 /// ```
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn alloc_zeroed<T>(&self) -> BumpBox<'a, T>
                 where
                     T: $trait,
@@ -120,6 +121,7 @@ macro_rules! bytemuck_or_zerocopy {
                 /// # Ok::<(), bump_scope::alloc::AllocError>(())
                 /// ```
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_alloc_zeroed<T>(&self) -> Result<BumpBox<'a, T>, AllocError>
                 where
                     T: $trait,
@@ -141,6 +143,7 @@ macro_rules! bytemuck_or_zerocopy {
                 /// assert_eq!(*zeroes, [0; 3]);
                 /// ```
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn alloc_zeroed_slice<T>(&self, len: usize) -> BumpBox<'a, [T]>
                 where
                     T: $trait,
@@ -162,6 +165,7 @@ macro_rules! bytemuck_or_zerocopy {
                 /// assert_eq!(*zeroes, [0; 3]);
                 /// # Ok::<(), bump_scope::alloc::AllocError>(())
                 /// ```
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_alloc_zeroed_slice<T>(&self, len: usize) -> Result<BumpBox<'a, [T]>, AllocError>
                 where
                     T: $trait,
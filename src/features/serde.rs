@@ -11,6 +11,12 @@ use crate::{
     traits::{BumpAllocatorTyped, MutBumpAllocatorTyped},
 };
 
+#[cfg(feature = "alloc")]
+use serde::ser::SerializeStruct;
+
+#[cfg(feature = "alloc")]
+use crate::stats::{ChunkSnapshot, StatsDiff, StatsSnapshot};
+
 impl<T: Serialize + ?Sized> Serialize for BumpBox<'_, T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -83,6 +89,55 @@ impl<A> Serialize for MutBumpString<A> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Serialize for StatsSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("StatsSnapshot", 7)?;
+        state.serialize_field("count", &self.count())?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("capacity", &self.capacity())?;
+        state.serialize_field("allocated", &self.allocated())?;
+        state.serialize_field("remaining", &self.remaining())?;
+        state.serialize_field("current_chunk", &self.current_chunk_index())?;
+        state.serialize_field("chunks", self.chunks())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for ChunkSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ChunkSnapshot", 4)?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("capacity", &self.capacity())?;
+        state.serialize_field("allocated", &self.allocated())?;
+        state.serialize_field("remaining", &self.remaining())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for StatsDiff {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("StatsDiff", 5)?;
+        state.serialize_field("count", &self.count())?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("capacity", &self.capacity())?;
+        state.serialize_field("allocated", &self.allocated())?;
+        state.serialize_field("remaining", &self.remaining())?;
+        state.end()
+    }
+}
+
 const AN_ARRAY: &str = "an array";
 const A_STRING: &str = "a string";
 
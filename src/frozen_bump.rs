@@ -0,0 +1,200 @@
+use core::{
+    fmt::{self, Debug},
+    iter,
+    ptr::NonNull,
+};
+
+use crate::{
+    Bump,
+    alloc::Allocator,
+    maybe_default_allocator,
+    settings::{BumpAllocatorSettings, BumpSettings},
+    stats::{AnyStats, Stats},
+};
+
+macro_rules! make_type {
+    ($($allocator_parameter:tt)*) => {
+        /// A bump allocator that no longer allocates and can be shared between threads.
+        ///
+        /// It is created with [`Bump::freeze`] once the data has been built and is only read from then on.
+        /// Unlike a `Bump`, a `FrozenBump` is `Sync`, so a `&FrozenBump` and the references
+        /// into its chunks that are obtained with [`get`](FrozenBump::get) can be handed to many threads.
+        ///
+        /// To get allocations out of the `Bump` before freezing it, turn them into raw pointers with
+        /// [`BumpBox::into_raw`](crate::BumpBox::into_raw). Their destructors won't run.
+        /// Use [`thaw`](FrozenBump::thaw) to turn it back into a `Bump`.
+        ///
+        /// # Write protection
+        ///
+        /// The chunks of a `FrozenBump` can be made read-only to catch stray writes. When the base allocator hands
+        /// out page-aligned chunks, like one based on `mmap`, the ranges from
+        /// <code>[stats](FrozenBump::stats)().[small_to_big](Stats::small_to_big)()</code> with their
+        /// [`chunk_start`](crate::stats::Chunk::chunk_start) and [`chunk_end`](crate::stats::Chunk::chunk_end)
+        /// can be passed to `mprotect`. They must be made writable again before the `FrozenBump` is thawed or dropped.
+        ///
+        /// # Examples
+        /// ```
+        /// use bump_scope::{Bump, BumpBox};
+        ///
+        /// let bump: Bump = Bump::new();
+        /// let numbers = BumpBox::into_raw(bump.alloc_slice_copy(&[1, 2, 3, 4]));
+        /// let frozen = bump.freeze();
+        ///
+        /// // SAFETY: `numbers` was allocated before freezing and is initialized.
+        /// let numbers = unsafe { frozen.get(numbers) };
+        ///
+        /// let sums = std::thread::scope(|s| {
+        ///     let frozen = &frozen;
+        ///
+        ///     let handles = (0..2)
+        ///         .map(|i| s.spawn(move || {
+        ///             assert!(frozen.contains(numbers));
+        ///             numbers[i * 2..][..2].iter().sum::<i32>()
+        ///         }))
+        ///         .collect::<Vec<_>>();
+        ///
+        ///     handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+        /// });
+        ///
+        /// assert_eq!(sums, [3, 7]);
+        /// ```
+        #[repr(transparent)]
+        pub struct FrozenBump<$($allocator_parameter)*, S = BumpSettings>
+        where
+            A: Allocator,
+            S: BumpAllocatorSettings,
+        {
+            bump: Bump<A, S>,
+        }
+    };
+}
+
+maybe_default_allocator!(make_type);
+
+unsafe impl<A, S> Send for FrozenBump<A, S>
+where
+    A: Send + Allocator,
+    S: BumpAllocatorSettings,
+{
+}
+
+// Nothing is written to the chunks anymore, so reading them from multiple threads is fine.
+unsafe impl<A, S> Sync for FrozenBump<A, S>
+where
+    A: Sync + Allocator,
+    S: BumpAllocatorSettings,
+{
+}
+
+impl<A, S> Debug for FrozenBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        AnyStats::from(self.stats()).debug_format("FrozenBump", f)
+    }
+}
+
+impl<A, S> From<Bump<A, S>> for FrozenBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn from(bump: Bump<A, S>) -> Self {
+        Self { bump }
+    }
+}
+
+impl<A, S> FrozenBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    /// Turns this back into a `Bump` that can allocate again.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::Bump;
+    /// let bump: Bump = Bump::new();
+    /// bump.alloc_str("hello");
+    ///
+    /// let bump = bump.freeze().thaw();
+    /// bump.alloc_str("world");
+    ///
+    /// assert_eq!(bump.stats().allocated(), 10);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn thaw(self) -> Bump<A, S> {
+        self.bump
+    }
+
+    /// Returns a type which provides statistics about the memory usage of the bump allocator.
+    #[must_use]
+    #[inline(always)]
+    pub fn stats(&self) -> Stats<'_, A, S> {
+        self.bump.stats()
+    }
+
+    /// Returns a reference to the base allocator.
+    #[must_use]
+    #[inline(always)]
+    pub fn allocator(&self) -> Option<&A> {
+        self.stats().allocator()
+    }
+
+    /// Returns whether `ptr` points into the allocated memory of this bump allocator.
+    ///
+    /// Only the address is checked, not the size or the validity of the value.
+    #[must_use]
+    pub fn contains<T: ?Sized>(&self, ptr: *const T) -> bool {
+        let addr = ptr.cast::<u8>().addr();
+
+        iter::successors(self.stats().current_chunk(), |chunk| chunk.prev()).any(|chunk| {
+            let (start, end) = if S::UP {
+                (chunk.content_start(), chunk.bump_position())
+            } else {
+                (chunk.bump_position(), chunk.content_end())
+            };
+
+            (start.addr().get()..end.addr().get()).contains(&addr)
+        })
+    }
+
+    /// Returns a shared reference to a value that was allocated before freezing.
+    ///
+    /// A `FrozenBump` can be shared between threads, so `T` must be [`Sync`]:
+    /// ```compile_fail,E0277
+    /// # use core::cell::Cell;
+    /// # use bump_scope::{Bump, BumpBox};
+    /// let bump: Bump = Bump::new();
+    /// let cell = BumpBox::into_raw(bump.alloc(Cell::new(1)));
+    ///
+    /// let frozen = bump.freeze();
+    /// let cell = unsafe { frozen.get(cell) };
+    /// # _ = cell;
+    /// ```
+    ///
+    /// # Safety
+    /// - `ptr` must point to an initialized `T` that was allocated in this bump allocator before it was frozen,
+    ///   like a pointer returned from [`BumpBox::into_raw`](crate::BumpBox::into_raw).
+    /// - There must be no mutable references to the value.
+    ///
+    /// # Panics
+    /// Panics with debug assertions enabled if `T` is not zero-sized and [`contains`](Self::contains)
+    /// returns `false` for `ptr`.
+    #[must_use]
+    #[inline]
+    pub unsafe fn get<T: ?Sized + Sync>(&self, ptr: NonNull<T>) -> &T {
+        let value = unsafe { ptr.as_ref() };
+
+        debug_assert!(
+            size_of_val(value) == 0 || self.contains(ptr.as_ptr()),
+            "the pointer is not allocated in this bump allocator"
+        );
+
+        value
+    }
+}
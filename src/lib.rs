@@ -16,6 +16,7 @@
 #![cfg_attr(all(feature = "nightly-tests", test, doc), feature(offset_of_enum))]
 #![cfg_attr(feature = "nightly-dropck-eyepatch", feature(dropck_eyepatch))]
 #![cfg_attr(feature = "nightly-clone-to-uninit", feature(clone_to_uninit, ptr_metadata))]
+#![cfg_attr(feature = "nightly-asan", feature(cfg_sanitize))]
 #![cfg_attr(docsrs,
     feature(doc_cfg),
     doc(auto_cfg(hide(feature, values("panic-on-alloc")))) // too noisy
@@ -60,6 +61,7 @@
 //! A bump allocator owns a big chunk of memory. It has a pointer that starts at one end of that chunk.
 //! When an allocation is made that pointer gets aligned and bumped towards the other end of the chunk.
 //! When its chunk is full, this allocator allocates another chunk with twice the size.
+//! (The growth of chunk sizes can be configured using [settings].)
 //!
 //! This makes allocations very fast. The drawback is that you can't reclaim memory like you do with a more general allocator.
 //! Memory for the most recent allocation *can* be reclaimed. You can also use [scopes, checkpoints](#scopes-and-checkpoints) and [`reset`](Bump::reset) to reclaim memory.
@@ -94,7 +96,7 @@
 //! - You can allocate a slice from any `Iterator` with [`alloc_iter`](Bump::alloc_iter).
 //! - `Bump`'s base allocator is generic.
 //! - Won't try to allocate a smaller chunk if allocation failed.
-//! - No built-in allocation limit. You can provide an allocator that enforces an allocation limit (see `examples/limit_memory_usage.rs`).
+//! - Allocation limits are enforced by the base allocator. The [`Limited`](alloc::Limited) base allocator draws from a nestable [`Budget`](alloc::Budget) (see `examples/limit_memory_usage.rs`).
 //! - Allocations are a tiny bit more optimized. See [./crates/callgrind-benches][benches].
 //! - [You can choose the bump direction.](crate::settings#bumping-upwards-or-downwards) Bumps upwards by default.
 //!
@@ -172,6 +174,8 @@
 //! unsafe { bump.reset_to(checkpoint); }
 //! assert_eq!(bump.stats().allocated(), 0);
 //! ```
+//! With a `&mut Bump`, [`try_reset_to`](Bump::try_reset_to) does the same without `unsafe`.
+//! It returns an error if the checkpoint does not belong to the bump allocator or lies ahead of the bump position.
 //! When using a `Bump(Scope)` as an allocator for collections you will find that you can no longer
 //! call `scoped` or `scope_guard` because those functions require `&mut self` which does not allow
 //! any outstanding references to the allocator.
@@ -222,7 +226,12 @@
 //! # Parallel Allocation
 //! [`Bump`] is `!Sync` which means it can't be shared between threads.
 //!
-//! To bump allocate in parallel you can use a [`BumpPool`].
+//! To bump allocate in parallel you can use a [`BumpPool`] or a [`SyncBump`].
+//! A `BumpPool` hands out a `Bump` per thread while a `SyncBump` is a single bump allocator
+//! that multiple threads can allocate from at the same time.
+//!
+//! Once you are done allocating, you can [`freeze`](Bump::freeze) a `Bump` into a [`FrozenBump`]
+//! which is `Sync`, so the data can be read from many threads.
 //!
 //! # Allocator API
 //! `Bump` and `BumpScope` implement `bump-scope`'s own [`Allocator`] trait and with the
@@ -243,12 +252,14 @@
 //!
 //! # Feature Flags
 //! <!-- feature documentation start -->
-//! - **`std`** *(enabled by default)* — Adds `BumpPool` and implementations of `std::io` traits.
-//! - **`alloc`** *(enabled by default)* — Adds `Global` as the default base allocator and some interactions with `alloc` collections.
+//! - **`std`** *(enabled by default)* — Adds the `thread_local` module, implementations of `std::io` traits and makes `BumpPool` use `std::sync::Mutex` instead of a spin lock.
+//! - **`alloc`** *(enabled by default)* — Adds `Global` as the default base allocator, `BumpPool` and some interactions with `alloc` collections.
 //! - **`panic-on-alloc`** *(enabled by default)* — Adds functions and traits that will panic when allocations fail.
 //!   Without this feature, allocation failures cannot cause panics, and only
 //!   `try_`-prefixed allocation methods will be available.
-//! - **`serde`** — Adds `Serialize` implementations for `BumpBox`, strings and vectors, and `DeserializeSeed` for strings and vectors.
+//! - **`track-allocations`** — Adds the `tracking` module and records the size, type name and caller location of allocations
+//!   so `Stats` can break down the live allocations by call site and by type. This makes allocations much slower.
+//! - **`serde`** — Adds `Serialize` implementations for `BumpBox`, strings, vectors and stats snapshots, and `DeserializeSeed` for strings and vectors.
 //! - **`bytemuck`** — Adds `bytemuck::*` extension traits for
 //!   <code>[alloc_zeroed](bytemuck::BumpAllocatorTypedScopeExt::alloc_zeroed)([_slice](bytemuck::BumpAllocatorTypedScopeExt::alloc_zeroed_slice))</code>,
 //!   [`init_zeroed`](bytemuck::InitZeroed::init_zeroed),
@@ -290,6 +301,8 @@
 //!   This makes it so references don't have to strictly outlive the container.
 //!   (Just like with std's `Box` and `Vec`.)
 //! - **`nightly-clone-to-uninit`** — Adds [`alloc_clone`](crate::traits::BumpAllocatorTypedScope::alloc_clone) method.
+//! - **`nightly-asan`** — Poisons the free memory of chunks for the address sanitizer when compiling with `-Zsanitizer=address`,
+//!   so accessing memory after a reset or outside of allocations is reported.
 //! <!-- feature documentation end -->
 //!
 //! [benches]: https://github.com/bluurryy/bump-scope/tree/main/crates/callgrind-benches
@@ -312,15 +325,17 @@ extern crate alloc as alloc_crate;
 
 pub mod alloc;
 mod allocator_impl;
+mod asan;
 mod bump;
 mod bump_align_guard;
 /// [`BumpBox`] and associated types.
 mod bump_box;
 mod bump_claim_guard;
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", any(feature = "std", target_has_atomic = "8")))]
 mod bump_pool;
 mod bump_scope;
 mod bump_scope_guard;
+mod bump_scope_with_limit;
 /// [`BumpString`] and associated types.
 mod bump_string;
 /// [`BumpVec`] and associated types.
@@ -334,6 +349,7 @@ mod fixed_bump_string;
 mod fixed_bump_vec;
 mod from_utf16_error;
 mod from_utf8_error;
+mod frozen_bump;
 mod layout;
 mod mut_bump_string;
 /// [`MutBumpVec`] and associated types.
@@ -346,23 +362,35 @@ pub mod owned_slice;
 /// Types associated with owned strings.
 pub mod owned_str;
 mod partial_eq;
+mod poison;
 mod polyfill;
 mod raw_bump;
+mod red_zone;
 mod set_len_on_drop;
 mod set_len_on_drop_by_ptr;
 pub mod settings;
 pub mod stats;
+#[cfg(target_has_atomic = "ptr")]
+mod sync_bump;
+#[cfg(feature = "std")]
+pub mod thread_local;
+#[cfg(feature = "track-allocations")]
+pub mod tracking;
 /// Traits that provide ways to be generic over `Bump(Scope)`s.
 pub mod traits;
 mod without_dealloc;
+mod zeroize;
 
 pub use bump::Bump;
 pub use bump_box::BumpBox;
 pub use bump_claim_guard::BumpClaimGuard;
-#[cfg(feature = "std")]
-pub use bump_pool::{BumpPool, BumpPoolGuard};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use bump_pool::OwnedBumpPoolGuard;
+#[cfg(all(feature = "alloc", any(feature = "std", target_has_atomic = "8")))]
+pub use bump_pool::{BumpPool, BumpPoolGuard, BumpPoolScopeGuard, BumpPoolScopeGuardWithLimit, BumpPoolStats};
 pub use bump_scope::BumpScope;
-pub use bump_scope_guard::{BumpScopeGuard, Checkpoint};
+pub use bump_scope_guard::{BumpScopeGuard, Checkpoint, CheckpointError};
+pub use bump_scope_with_limit::{BumpScopeGuardWithLimit, BumpScopeWithLimit};
 pub use bump_string::BumpString;
 #[doc(inline)]
 pub use bump_vec::BumpVec;
@@ -374,6 +402,7 @@ pub use fixed_bump_string::FixedBumpString;
 pub use fixed_bump_vec::FixedBumpVec;
 pub use from_utf8_error::FromUtf8Error;
 pub use from_utf16_error::FromUtf16Error;
+pub use frozen_bump::FrozenBump;
 use layout::ArrayLayout;
 pub use mut_bump_string::MutBumpString;
 #[doc(inline)]
@@ -382,7 +411,10 @@ pub use mut_bump_vec_rev::MutBumpVecRev;
 pub use no_drop::NoDrop;
 #[cfg(feature = "panic-on-alloc")]
 use private::{PanicsOnAlloc, capacity_overflow, format_trait_error};
+pub use red_zone::RedZoneError;
 use set_len_on_drop::SetLenOnDrop;
+#[cfg(target_has_atomic = "ptr")]
+pub use sync_bump::SyncBump;
 pub use without_dealloc::{WithoutDealloc, WithoutShrink};
 
 /// The changelog.
@@ -0,0 +1,90 @@
+//! Fills memory with the byte patterns of the `POISON` setting.
+//!
+//! Freed memory is also wiped with the `ZEROIZE` setting, see [`zeroize`], and poisoned for the
+//! address sanitizer with the `nightly-asan` feature, see [`asan`].
+//! All functions do nothing if none of these is enabled.
+
+use core::{ops::Range, ptr::NonNull};
+
+use crate::{
+    asan,
+    settings::{BumpAllocatorSettings, POISON_FREED, POISON_UNINIT},
+    zeroize,
+};
+
+/// Fills a newly allocated memory block with [`POISON_UNINIT`].
+///
+/// # Safety
+/// `ptr` must be valid for writes of `len` bytes.
+#[inline(always)]
+pub(crate) unsafe fn uninit<S: BumpAllocatorSettings>(ptr: NonNull<u8>, len: usize) {
+    if S::POISON {
+        unsafe { ptr.write_bytes(POISON_UNINIT, len) };
+    }
+}
+
+/// Wipes a freed memory block, fills it with [`POISON_FREED`] and poisons it for the address sanitizer.
+///
+/// # Safety
+/// `ptr` must be valid for writes of `len` bytes.
+#[inline(always)]
+pub(crate) unsafe fn freed<S: BumpAllocatorSettings>(ptr: NonNull<u8>, len: usize) {
+    if S::ZEROIZE {
+        unsafe { zeroize::zero(ptr, len) };
+    }
+
+    if S::POISON {
+        unsafe { ptr.write_bytes(POISON_FREED, len) };
+    }
+
+    unsafe { asan::poison(ptr, len) };
+}
+
+/// Wipes a freed memory range, fills it with [`POISON_FREED`] and poisons it for the address sanitizer.
+///
+/// # Safety
+/// The range must be valid for writes.
+#[inline(always)]
+pub(crate) unsafe fn freed_range<S: BumpAllocatorSettings>(range: Range<NonNull<u8>>) {
+    if S::POISON || S::ZEROIZE || asan::ENABLED {
+        unsafe { freed::<S>(range.start, range.end.addr().get() - range.start.addr().get()) };
+    }
+}
+
+/// Wipes the part of the old memory block that is not covered by the new memory block,
+/// fills it with [`POISON_FREED`] and poisons it for the address sanitizer.
+/// This is for memory blocks that were moved by a `grow` or `shrink`.
+///
+/// # Safety
+/// `old_ptr` must be valid for writes of `old_len` bytes.
+#[inline(always)]
+pub(crate) unsafe fn freed_except<S: BumpAllocatorSettings>(
+    old_ptr: NonNull<u8>,
+    old_len: usize,
+    new_ptr: NonNull<u8>,
+    new_len: usize,
+) {
+    if !S::POISON && !S::ZEROIZE && !asan::ENABLED {
+        return;
+    }
+
+    let old_start = old_ptr.addr().get();
+    let old_end = old_start + old_len;
+    let new_start = new_ptr.addr().get();
+    let new_end = new_start + new_len;
+
+    unsafe {
+        if new_end <= old_start || new_start >= old_end {
+            freed::<S>(old_ptr, old_len);
+            return;
+        }
+
+        if new_start > old_start {
+            freed::<S>(old_ptr, new_start - old_start);
+        }
+
+        if new_end < old_end {
+            freed::<S>(old_ptr.add(new_end - old_start), old_end - new_end);
+        }
+    }
+}
@@ -2,21 +2,27 @@ use core::{
     alloc::Layout,
     cell::Cell,
     marker::PhantomData,
+    mem::MaybeUninit,
     num::NonZeroUsize,
     ops::{Deref, Range},
     ptr::{self, NonNull},
 };
 
 use crate::{
-    BaseAllocator, Checkpoint, SizedTypeProperties, align_pos,
+    BaseAllocator, Checkpoint, CheckpointError, SizedTypeProperties, align_pos,
     alloc::{AllocError, Allocator},
+    asan,
     bumping::{BumpProps, BumpUp, MIN_CHUNK_ALIGN, bump_down, bump_prepare_down, bump_prepare_up, bump_up},
-    chunk::{ChunkHeader, ChunkSize, ChunkSizeHint},
+    chunk::{ASSUMED_PAGE_SIZE, ChunkHeader, ChunkSize, ChunkSizeHint, Peak, RedZones, Waste, header_extension_size},
+    down_align_usize,
     error_behavior::{self, ErrorBehavior},
     layout::{ArrayLayout, CustomLayout, LayoutProps, SizedLayout},
+    poison,
     polyfill::non_null,
-    settings::{BumpAllocatorSettings, False, MinimumAlignment, SupportedMinimumAlignment},
-    stats::Stats,
+    red_zone::{self, Guard, RedZoneError},
+    settings::{BumpAllocatorSettings, BumpObserver, False, MinimumAlignment, SupportedMinimumAlignment},
+    stats::{AnyStats, Stats},
+    up_align_usize_unchecked, zeroize,
 };
 
 /// The internal type used by `Bump` and `Bump(Scope)`.
@@ -68,6 +74,74 @@ where
             ChunkClass::NonDummy(chunk) => Some(chunk.allocator()),
         }
     }
+
+    /// Prepares the memory that was allocated since `checkpoint` for being freed.
+    /// If `checkpoint` is `None` all allocated memory is freed.
+    ///
+    /// The red zones of the freed allocations are checked and the memory is filled with
+    /// [`POISON_FREED`](crate::settings::POISON_FREED) or wiped, depending on the settings.
+    /// With the `nightly-asan` feature it is also poisoned for the address sanitizer.
+    /// With the `track-allocations` feature the records of the freed allocations are removed.
+    ///
+    /// # Panics
+    /// Panics if a red zone was overwritten.
+    ///
+    /// # Safety
+    /// The checkpoint must point into the current chunk or one of its previous chunks.
+    #[track_caller]
+    unsafe fn before_free(&self, checkpoint: Option<Checkpoint>) {
+        if !S::POISON && !S::RED_ZONES && !S::ZEROIZE && !asan::ENABLED && !cfg!(feature = "track-allocations") {
+            return;
+        }
+
+        let Some(mut chunk) = self.chunk.get().as_non_dummy() else {
+            return;
+        };
+
+        loop {
+            let mut range = chunk.allocated_range();
+
+            if let Some(checkpoint) = checkpoint.filter(|c| c.chunk == chunk.header.cast()) {
+                let address = unsafe { chunk.content_ptr_from_addr(checkpoint.address.get()) };
+
+                if S::UP {
+                    range.start = address;
+                } else {
+                    range.end = address;
+                }
+
+                unsafe { chunk.free_range(range) };
+                return;
+            }
+
+            unsafe { chunk.free_range(range) };
+
+            match chunk.prev() {
+                Some(prev) => chunk = prev,
+                None => return,
+            }
+        }
+    }
+
+    /// Checks the red zones of all allocations, see [`Bump::check_integrity`](crate::Bump::check_integrity).
+    pub(crate) fn check_integrity(&self) -> Result<(), RedZoneError> {
+        if !S::RED_ZONES {
+            return Ok(());
+        }
+
+        let Some(mut chunk) = self.chunk.get().as_non_dummy() else {
+            return Ok(());
+        };
+
+        loop {
+            chunk.check_guards()?;
+
+            match chunk.prev() {
+                Some(prev) => chunk = prev,
+                None => return Ok(()),
+            }
+        }
+    }
 }
 
 impl<A, S> RawBump<A, S>
@@ -84,30 +158,61 @@ where
 
     #[inline(always)]
     pub(crate) fn reset(&self) {
+        if !S::GROWS {
+            // The chunks could not be allocated again.
+            self.reset_to_start();
+            return;
+        }
+
         let Some(mut chunk) = self.chunk.get().as_non_dummy() else {
+            S::Observer::on_reset(self.stats().into());
             return;
         };
 
-        unsafe {
-            chunk.for_each_prev(|chunk| chunk.deallocate());
+        self.update_peak();
+        unsafe { self.before_free(None) };
 
-            while let Some(next) = chunk.next() {
-                chunk.deallocate();
-                chunk = next;
-            }
+        while let Some(next) = chunk.next() {
+            chunk = next;
+        }
+
+        // We keep the last chunk, which is the biggest one.
+        // It is unlinked from the others before they are deallocated
+        // so the observer only gets to see live chunks.
+        let prev = chunk.prev();
 
+        unsafe {
             chunk.header.as_ref().prev.set(None);
         }
 
+        if S::TRACK_PEAK {
+            chunk.peak().set_capacity_before(0);
+        }
+
         chunk.reset();
 
         self.chunk.set(chunk.raw);
+
+        let stats = self.stats().into();
+
+        if let Some(prev) = prev {
+            unsafe {
+                prev.for_each_prev(|chunk| chunk.deallocate(stats));
+                prev.deallocate(stats);
+            }
+        }
+
+        S::Observer::on_reset(stats);
     }
 
     /// Reset's the bump pointer to the very start.
     #[inline]
     pub(crate) fn reset_to_start(&self) {
         if let Some(mut chunk) = self.chunk.get().as_non_dummy() {
+            self.record_peak();
+
+            unsafe { self.before_free(None) };
+
             while let Some(prev) = chunk.prev() {
                 chunk = prev;
             }
@@ -116,6 +221,127 @@ where
 
             self.chunk.set(chunk.raw);
         }
+
+        S::Observer::on_reset(self.stats().into());
+    }
+
+    /// Resets the bump allocator so that it has a single chunk with at least
+    /// enough capacity for everything that is currently allocated.
+    ///
+    /// If the allocation fails, the bump allocator is left unchanged.
+    pub(crate) fn reset_to_fit<E: ErrorBehavior>(&self) -> Result<(), E>
+    where
+        A: Clone,
+    {
+        if !S::GROWS {
+            self.reset();
+            return Ok(());
+        }
+
+        let Some(chunk) = self.chunk.get().as_non_dummy() else {
+            S::Observer::on_reset(self.stats().into());
+            return Ok(());
+        };
+
+        let allocated = self.stats().allocated();
+
+        let mut biggest = chunk;
+
+        while let Some(next) = biggest.next() {
+            biggest = next;
+        }
+
+        if biggest.capacity() >= allocated {
+            self.reset();
+            return Ok(());
+        }
+
+        if S::RED_ZONES {
+            if let Err(error) = self.check_integrity() {
+                red_zone::violated(error);
+            }
+        }
+
+        let layout = Layout::array::<u8>(allocated).map_err(|_| E::capacity_overflow())?;
+        let size = ChunkSize::<A, S>::from_capacity(layout).ok_or_else(E::capacity_overflow)?;
+        let new_chunk = NonDummyChunk::new::<E>(size, None, biggest.allocator().clone())?;
+
+        if S::TRACK_PEAK {
+            self.update_peak();
+            new_chunk.copy_peak_from(biggest);
+        }
+
+        self.chunk.set(new_chunk.raw);
+
+        let stats = self.stats().into();
+
+        unsafe {
+            chunk.for_each_prev(|chunk| chunk.deallocate(stats));
+            chunk.for_each_next(|chunk| chunk.deallocate(stats));
+            chunk.deallocate(stats);
+        }
+
+        S::Observer::on_reset(stats);
+        Ok(())
+    }
+
+    /// Deallocates the chunks following the current chunk, keeping the smallest
+    /// ones as long as their combined size does not exceed `max_spare_size`.
+    ///
+    /// Returns the combined size of the deallocated chunks.
+    pub(crate) fn trim_to(&self, max_spare_size: usize) -> usize {
+        let Some(mut chunk) = self.chunk.get().as_non_dummy() else {
+            return 0;
+        };
+
+        let mut spare_size: usize = 0;
+
+        while let Some(next) = chunk.next() {
+            match spare_size.checked_add(next.size().get()) {
+                Some(new_spare_size) if new_spare_size <= max_spare_size => {
+                    spare_size = new_spare_size;
+                    chunk = next;
+                }
+                _ => break,
+            }
+        }
+
+        let Some(next) = chunk.next() else {
+            return 0;
+        };
+
+        if S::TRACK_PEAK {
+            self.update_peak();
+            chunk.copy_peak_from(next.last());
+        }
+
+        unsafe {
+            chunk.header.as_ref().next.set(None);
+        }
+
+        let stats = self.stats().into();
+        let mut released = next.size().get();
+
+        unsafe {
+            next.for_each_next(|chunk| {
+                released += chunk.size().get();
+                chunk.deallocate(stats);
+            });
+
+            next.deallocate(stats);
+        }
+
+        released
+    }
+
+    /// Touches every page of the free memory in the current and the following chunks.
+    pub(crate) fn prefault(&self) {
+        let Some(chunk) = self.chunk.get().as_non_dummy() else {
+            return;
+        };
+
+        chunk.prefault(chunk.remaining_range());
+        chunk.for_each_next(|chunk| chunk.prefault(chunk.content_start()..chunk.content_end()));
     }
 
     /// # Safety
@@ -128,9 +354,11 @@ where
             }
             ChunkClass::Unallocated => (),
             ChunkClass::NonDummy(chunk) => unsafe {
-                chunk.for_each_prev(|chunk| chunk.deallocate());
-                chunk.for_each_next(|chunk| chunk.deallocate());
-                chunk.deallocate();
+                // The bump allocator is going away, so there are no stats to report.
+                let stats = AnyStats::default();
+                chunk.for_each_prev(|chunk| chunk.deallocate(stats));
+                chunk.for_each_next(|chunk| chunk.deallocate(stats));
+                chunk.deallocate(stats);
             },
         }
     }
@@ -207,20 +435,18 @@ where
                 ChunkHeader::unallocated::<S>(),
                 "the checkpoint must not have been created by a `!GUARANTEED_ALLOCATED` when self is `GUARANTEED_ALLOCATED`"
             );
+        }
 
-            let chunk = self
-                .stats()
-                .small_to_big()
-                .find(|chunk| chunk.header() == checkpoint.chunk.cast())
-                .expect("this checkpoint does not refer to any chunk in this bump allocator");
-
-            assert!(
-                chunk.chunk.contains_addr_or_end(checkpoint.address.get()),
-                "checkpoint address does not point within its chunk"
-            );
+        // The checkpoint's chunk may have been deallocated by a `trim` after the bump position
+        // was reset to before the checkpoint. Then there is nothing left to reset.
+        if !self.chunk.get().contains_checkpoint(checkpoint) {
+            return;
         }
 
+        self.record_peak();
+
         unsafe {
+            self.before_free(Some(checkpoint));
             checkpoint.reset_within_chunk();
 
             self.chunk.set(RawChunk {
@@ -244,6 +470,10 @@ where
                     return Err(E::capacity_overflow());
                 };
 
+                if !S::GROWS {
+                    return Err(E::allocation(layout));
+                }
+
                 let new_chunk = NonDummyChunk::<A, S>::new(
                     ChunkSize::<A, S>::from_capacity(layout).ok_or_else(E::capacity_overflow)?,
                     None,
@@ -287,50 +517,193 @@ where
         }
     }
 
+    /// Appends a chunk with at least the given capacity for each capacity in `capacities`.
+    ///
+    /// If the bump allocator is unallocated, the first of those chunks becomes the current chunk.
+    pub(crate) fn reserve_chunks<E: ErrorBehavior>(&self, capacities: &[usize]) -> Result<(), E>
+    where
+        A: BaseAllocator<S::GuaranteedAllocated>,
+    {
+        let mut capacities = capacities.iter().copied();
+
+        let mut chunk = match self.chunk.get().classify() {
+            ChunkClass::Claimed => return Err(E::claimed()),
+            ChunkClass::Unallocated => {
+                let Some(capacity) = capacities.next() else {
+                    return Ok(());
+                };
+
+                let Ok(layout) = Layout::from_size_align(capacity, 1) else {
+                    return Err(E::capacity_overflow());
+                };
+
+                if !S::GROWS {
+                    return Err(E::allocation(layout));
+                }
+
+                let new_chunk = NonDummyChunk::<A, S>::new(
+                    ChunkSize::<A, S>::from_capacity(layout).ok_or_else(E::capacity_overflow)?,
+                    None,
+                    // When this bump allocator is unallocated, `A` is guaranteed to implement `Default`,
+                    // `default_or_panic` will not panic.
+                    A::default_or_panic(),
+                )?;
+
+                self.chunk.set(new_chunk.raw);
+                new_chunk
+            }
+            ChunkClass::NonDummy(chunk) => chunk.last(),
+        };
+
+        for capacity in capacities {
+            let Ok(layout) = Layout::from_size_align(capacity, 1) else {
+                return Err(E::capacity_overflow());
+            };
+
+            chunk = chunk.append_with_capacity(layout)?;
+        }
+
+        Ok(())
+    }
+
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub(crate) fn alloc<B: ErrorBehavior>(&self, layout: Layout) -> Result<NonNull<u8>, B> {
-        match self.chunk.get().alloc(CustomLayout(layout)) {
-            Some(ptr) => Ok(ptr),
-            None => self.alloc_in_another_chunk(layout),
+        let result = if S::RED_ZONES {
+            self.alloc_guarded(layout)
+        } else {
+            match self.chunk.get().alloc(CustomLayout(layout)) {
+                Some(ptr) => Ok(ptr),
+                None => self.alloc_in_another_chunk(layout),
+            }
+        };
+
+        #[cfg(feature = "track-allocations")]
+        if let Ok(ptr) = &result {
+            self.track(*ptr, layout.size(), None);
         }
+
+        if let Ok(ptr) = &result {
+            unsafe { poison::uninit::<S>(*ptr, layout.size()) };
+        }
+
+        result
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub(crate) fn alloc_sized<E: ErrorBehavior, T>(&self) -> Result<NonNull<T>, E> {
-        match self.chunk.get().alloc(SizedLayout::new::<T>()) {
-            Some(ptr) => Ok(ptr.cast()),
-            None => match self.alloc_sized_in_another_chunk::<E, T>() {
-                Ok(ptr) => Ok(ptr.cast()),
-                Err(err) => Err(err),
-            },
+        let result = if S::RED_ZONES {
+            self.alloc_guarded(Layout::new::<T>()).map(NonNull::cast)
+        } else {
+            match self.chunk.get().alloc(SizedLayout::new::<T>()) {
+                Some(ptr) => Ok(ptr.cast()),
+                None => match self.alloc_sized_in_another_chunk::<E, T>() {
+                    Ok(ptr) => Ok(ptr.cast()),
+                    Err(err) => Err(err),
+                },
+            }
+        };
+
+        #[cfg(feature = "track-allocations")]
+        if let Ok(ptr) = &result {
+            self.track(ptr.cast(), T::SIZE, Some(core::any::type_name::<T>()));
+        }
+
+        if let Ok(ptr) = &result {
+            unsafe { poison::uninit::<S>(ptr.cast(), T::SIZE) };
         }
+
+        result
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub(crate) fn alloc_slice<E: ErrorBehavior, T>(&self, len: usize) -> Result<NonNull<T>, E> {
         let Ok(layout) = ArrayLayout::array::<T>(len) else {
             return Err(E::capacity_overflow());
         };
 
-        match self.chunk.get().alloc(layout) {
-            Some(ptr) => Ok(ptr.cast()),
-            None => match self.alloc_slice_in_another_chunk::<E, T>(len) {
-                Ok(ptr) => Ok(ptr.cast()),
-                Err(err) => Err(err),
-            },
+        let result = if S::RED_ZONES {
+            self.alloc_guarded(*layout).map(NonNull::cast)
+        } else {
+            match self.chunk.get().alloc(layout) {
+                Some(ptr) => Ok(ptr.cast()),
+                None => match self.alloc_slice_in_another_chunk::<E, T>(len) {
+                    Ok(ptr) => Ok(ptr.cast()),
+                    Err(err) => Err(err),
+                },
+            }
+        };
+
+        #[cfg(feature = "track-allocations")]
+        if let Ok(ptr) = &result {
+            self.track(ptr.cast(), layout.size(), Some(core::any::type_name::<[T]>()));
         }
+
+        if let Ok(ptr) = &result {
+            unsafe { poison::uninit::<S>(ptr.cast(), layout.size()) };
+        }
+
+        result
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     pub(crate) fn alloc_slice_for<E: ErrorBehavior, T>(&self, value: &[T]) -> Result<NonNull<T>, E> {
         let layout = ArrayLayout::for_value(value);
 
-        match self.chunk.get().alloc(layout) {
-            Some(ptr) => Ok(ptr.cast()),
-            None => match self.alloc_slice_in_another_chunk::<E, T>(value.len()) {
-                Ok(ptr) => Ok(ptr.cast()),
-                Err(err) => Err(err),
-            },
+        let result = if S::RED_ZONES {
+            self.alloc_guarded(*layout).map(NonNull::cast)
+        } else {
+            match self.chunk.get().alloc(layout) {
+                Some(ptr) => Ok(ptr.cast()),
+                None => match self.alloc_slice_in_another_chunk::<E, T>(value.len()) {
+                    Ok(ptr) => Ok(ptr.cast()),
+                    Err(err) => Err(err),
+                },
+            }
+        };
+
+        #[cfg(feature = "track-allocations")]
+        if let Ok(ptr) = &result {
+            self.track(ptr.cast(), layout.size(), Some(core::any::type_name::<[T]>()));
+        }
+
+        if let Ok(ptr) = &result {
+            unsafe { poison::uninit::<S>(ptr.cast(), layout.size()) };
+        }
+
+        result
+    }
+
+    /// Allocates a memory block for `layout` surrounded by red zones and returns the pointer to the allocation.
+    #[inline(never)]
+    fn alloc_guarded<E: ErrorBehavior>(&self, layout: Layout) -> Result<NonNull<u8>, E> {
+        let block_layout = red_zone::block_layout::<S>(layout).ok_or_else(E::capacity_overflow)?;
+
+        let block = match self.chunk.get().alloc(CustomLayout(block_layout)) {
+            Some(ptr) => ptr,
+            None => self.alloc_in_another_chunk(block_layout)?,
+        };
+
+        // SAFETY: We just allocated in the current chunk, so it is not a dummy chunk.
+        unsafe { Ok(self.chunk.get().as_non_dummy_unchecked().guard(block, layout)) }
+    }
+
+    /// Records an allocation that was just made in the current chunk.
+    #[cfg(feature = "track-allocations")]
+    #[track_caller]
+    fn track(&self, ptr: NonNull<u8>, size: usize, type_name: Option<&'static str>) {
+        if let Some(chunk) = self.chunk.get().as_non_dummy() {
+            crate::tracking::record(
+                chunk.chunk_start().addr().get()..chunk.chunk_end().addr().get(),
+                S::UP,
+                ptr.addr().get(),
+                size,
+                type_name,
+                core::panic::Location::caller(),
+            );
         }
     }
 
@@ -378,6 +751,11 @@ where
     /// So `end.offset_from_unsigned(start)` may not be used!
     #[inline(always)]
     fn prepare_allocation_range<B: ErrorBehavior, T>(&self, cap: usize) -> Result<Range<NonNull<T>>, B> {
+        if S::RED_ZONES {
+            let range = self.prepare_guarded_allocation_range::<B>(cap.checked_mul(T::SIZE), T::ALIGN)?;
+            return Ok(range.start.cast::<T>()..range.end.cast::<T>());
+        }
+
         let Ok(layout) = ArrayLayout::array::<T>(cap) else {
             return Err(B::capacity_overflow());
         };
@@ -390,6 +768,41 @@ where
         Ok(range.start.cast::<T>()..range.end.cast::<T>())
     }
 
+    /// Prepares an allocation of `size` bytes and leaves enough room to place red zones
+    /// around it, see [`NonDummyChunk::allocate_prepared_guarded`].
+    ///
+    /// `size` is `None` if computing it overflowed.
+    #[inline(never)]
+    pub(crate) fn prepare_guarded_allocation_range<B: ErrorBehavior>(
+        &self,
+        size: Option<usize>,
+        align: usize,
+    ) -> Result<Range<NonNull<u8>>, B> {
+        let overhead = red_zone::prepared_overhead::<S>(align);
+
+        let Some(layout) = size
+            .and_then(|size| size.checked_add(overhead))
+            .and_then(|size| Layout::from_size_align(size, align).ok())
+        else {
+            return Err(B::capacity_overflow());
+        };
+
+        let mut range = match self.chunk.get().prepare_allocation_range(CustomLayout(layout)) {
+            Some(range) => range,
+            None => unsafe { self.in_another_chunk(CustomLayout(layout), RawChunk::prepare_allocation_range)? },
+        };
+
+        unsafe {
+            if S::UP {
+                range.end = range.end.sub(overhead);
+            } else {
+                range.start = range.start.add(overhead);
+            }
+        }
+
+        Ok(range)
+    }
+
     /// Allocation slow path.
     /// The active chunk must *not* have space for `layout`.
     #[cold]
@@ -442,6 +855,7 @@ where
     ) -> Result<R, E> {
         let new_chunk: NonDummyChunk<A, S> = match self.chunk.get().classify() {
             ChunkClass::Claimed => Err(E::claimed()),
+            ChunkClass::Unallocated if !S::GROWS => Err(E::allocation(*layout)),
             ChunkClass::Unallocated => NonDummyChunk::new(
                 ChunkSize::from_capacity(*layout).ok_or_else(E::capacity_overflow)?,
                 None,
@@ -485,6 +899,10 @@ where
         match self.chunk.get().classify() {
             ChunkClass::Claimed => Err(E::claimed()),
             ChunkClass::Unallocated => {
+                if !S::GROWS {
+                    return Err(E::allocation(Layout::new::<()>()));
+                }
+
                 // When this bump allocator is unallocated, `A` is guaranteed to implement `Default`,
                 // `default_or_panic` will not panic.
                 let new_chunk = NonDummyChunk::new(ChunkSize::MINIMUM, None, A::default_or_panic())?;
@@ -507,6 +925,51 @@ where
         Stats::from_raw_chunk(self.chunk.get())
     }
 
+    /// Raises the peak record of the current chunk to the current usage if `TRACK_PEAK` is enabled.
+    ///
+    /// This must be called before the allocated bytes decrease
+    /// other than by [`NonDummyChunk::set_pos_addr`], which does this itself.
+    #[inline(always)]
+    pub(crate) fn record_peak(&self) {
+        if !S::TRACK_PEAK {
+            return;
+        }
+
+        if let Some(chunk) = self.chunk.get().as_non_dummy() {
+            chunk.record_peak();
+        }
+    }
+
+    /// Raises the peak to the current usage and merges the peak records of all chunks
+    /// into the one of the last chunk if `TRACK_PEAK` is enabled.
+    ///
+    /// This must be called before chunks are deallocated.
+    #[inline(always)]
+    pub(crate) fn update_peak(&self) {
+        if !S::TRACK_PEAK {
+            return;
+        }
+
+        if let Some(chunk) = self.chunk.get().as_non_dummy() {
+            chunk.merge_peaks();
+        }
+    }
+
+    /// Sets the peak to the current usage if `TRACK_PEAK` is enabled.
+    #[inline]
+    pub(crate) fn reset_peak(&self) {
+        if !S::TRACK_PEAK {
+            return;
+        }
+
+        if let Some(chunk) = self.chunk.get().as_non_dummy() {
+            let stats = self.stats();
+            let last = chunk.last();
+            last.peak().set(stats.allocated(), stats.capacity());
+            last.for_each_prev(|chunk| chunk.peak().set(0, 0));
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn align<const ALIGN: usize>(&self)
     where
@@ -536,6 +999,18 @@ where
     {
         const {
             assert!(NewS::UP == S::UP, "can't change `UP` setting of `Bump(Scope)`");
+            assert!(
+                NewS::TRACK_PEAK == S::TRACK_PEAK,
+                "can't change `TRACK_PEAK` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::TRACK_WASTE == S::TRACK_WASTE,
+                "can't change `TRACK_WASTE` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::RED_ZONES == S::RED_ZONES,
+                "can't change `RED_ZONES` setting of `Bump(Scope)`"
+            );
         }
 
         if !NewS::CLAIMABLE && self.chunk.get().is_claimed() {
@@ -555,6 +1030,18 @@ where
     {
         const {
             assert!(NewS::UP == S::UP, "can't change `UP` setting of `Bump(Scope)`");
+            assert!(
+                NewS::TRACK_PEAK == S::TRACK_PEAK,
+                "can't change `TRACK_PEAK` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::TRACK_WASTE == S::TRACK_WASTE,
+                "can't change `TRACK_WASTE` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::RED_ZONES == S::RED_ZONES,
+                "can't change `RED_ZONES` setting of `Bump(Scope)`"
+            );
 
             assert!(
                 NewS::MIN_ALIGN >= S::MIN_ALIGN,
@@ -578,6 +1065,18 @@ where
     {
         const {
             assert!(NewS::UP == S::UP, "can't change `UP` setting of `Bump(Scope)`");
+            assert!(
+                NewS::TRACK_PEAK == S::TRACK_PEAK,
+                "can't change `TRACK_PEAK` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::TRACK_WASTE == S::TRACK_WASTE,
+                "can't change `TRACK_WASTE` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::RED_ZONES == S::RED_ZONES,
+                "can't change `RED_ZONES` setting of `Bump(Scope)`"
+            );
 
             assert!(
                 NewS::MIN_ALIGN == S::MIN_ALIGN,
@@ -603,6 +1102,18 @@ where
     {
         const {
             assert!(NewS::UP == S::UP, "can't change `UP` setting of `Bump(Scope)`");
+            assert!(
+                NewS::TRACK_PEAK == S::TRACK_PEAK,
+                "can't change `TRACK_PEAK` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::TRACK_WASTE == S::TRACK_WASTE,
+                "can't change `TRACK_WASTE` setting of `Bump(Scope)`"
+            );
+            assert!(
+                NewS::RED_ZONES == S::RED_ZONES,
+                "can't change `RED_ZONES` setting of `Bump(Scope)`"
+            );
 
             assert!(
                 NewS::MIN_ALIGN >= S::MIN_ALIGN,
@@ -743,6 +1254,11 @@ where
             // SAFETY: allocations never succeed for a dummy chunk
             unsafe {
                 let chunk = self.as_non_dummy_unchecked();
+
+                if S::TRACK_WASTE {
+                    chunk.waste().add_padding(new_pos - chunk.pos().addr().get() - layout.size());
+                }
+
                 chunk.set_pos_addr(new_pos);
                 Some(chunk.content_ptr_from_addr(ptr))
             }
@@ -752,12 +1268,30 @@ where
             // SAFETY: allocations never succeed for a dummy chunk
             unsafe {
                 let chunk = self.as_non_dummy_unchecked();
+
+                if S::TRACK_WASTE {
+                    chunk.waste().add_padding(chunk.pos().addr().get() - ptr - layout.size());
+                }
+
                 chunk.set_pos_addr(ptr);
                 Some(chunk.content_ptr_from_addr(ptr))
             }
         }
     }
 
+    /// Adds the size of a memory block that was left behind because it couldn't grow in place
+    /// to the waste record of this chunk.
+    #[inline(always)]
+    pub(crate) fn record_abandoned(self, size: usize) {
+        if !S::TRACK_WASTE {
+            return;
+        }
+
+        if let Some(chunk) = self.as_non_dummy() {
+            chunk.waste().add_abandoned(size);
+        }
+    }
+
     /// Prepares allocation for a block of memory.
     ///
     /// On success, returns a [`NonNull<u8>`] meeting the size and alignment guarantees of `layout`.
@@ -772,7 +1306,9 @@ where
         // SAFETY: allocations never succeed for a dummy chunk
         unsafe {
             let chunk = self.as_non_dummy_unchecked();
-            Some(chunk.content_ptr_from_addr(ptr))
+            let ptr = chunk.content_ptr_from_addr(ptr);
+            asan::unpoison(ptr, layout.size());
+            Some(ptr)
         }
     }
 
@@ -803,7 +1339,9 @@ where
         // SAFETY: allocations never succeed for a dummy chunk
         unsafe {
             let chunk = self.as_non_dummy_unchecked();
-            Some(chunk.content_ptr_from_addr_range(range))
+            let range = chunk.content_ptr_from_addr_range(range);
+            asan::unpoison_range(range.clone());
+            Some(range)
         }
     }
 
@@ -812,7 +1350,15 @@ where
     where
         L: LayoutProps,
     {
-        let pos = self.pos().addr().get();
+        self.bump_props_at(self.pos().addr().get(), layout)
+    }
+
+    /// Like [`bump_props`](Self::bump_props) but with an explicit bump position.
+    #[inline(always)]
+    pub(crate) fn bump_props_at<L>(self, pos: usize, layout: L) -> BumpProps
+    where
+        L: LayoutProps,
+    {
         let end = unsafe { self.header.as_ref() }.end.addr().get();
 
         let start = if S::UP { pos } else { end };
@@ -839,6 +1385,104 @@ where
         unsafe { self.header.as_ref().pos.get() }
     }
 
+    /// Checks that resetting to `checkpoint` is fine when `self` is the current chunk.
+    ///
+    /// The checkpoint's chunk must be the current chunk or one before it and the checkpoint's
+    /// address must not be ahead of the current bump position.
+    /// This only compares pointers, so it never dereferences the checkpoint's chunk
+    /// unless it is part of this chunk list.
+    pub(crate) fn check_checkpoint(self, checkpoint: Checkpoint) -> Result<(), CheckpointError> {
+        if self.is_claimed() || checkpoint.chunk == ChunkHeader::claimed::<S>() {
+            return Err(CheckpointError::Claimed);
+        }
+
+        // `reset_to` only handles this case for `!GUARANTEED_ALLOCATED` bump allocators.
+        if checkpoint.chunk == ChunkHeader::unallocated::<S>() {
+            return if S::GUARANTEED_ALLOCATED {
+                Err(CheckpointError::ForeignChunk)
+            } else {
+                Ok(())
+            };
+        }
+
+        let Some(current) = self.as_non_dummy() else {
+            return Err(CheckpointError::ForeignChunk);
+        };
+
+        let address = checkpoint.address.get();
+
+        if current.header.cast() == checkpoint.chunk {
+            let pos = current.pos().addr().get();
+            let is_ahead = if S::UP { address > pos } else { address < pos };
+
+            return if !current.contains_addr_or_end(address) {
+                Err(CheckpointError::ForeignChunk)
+            } else if is_ahead {
+                Err(CheckpointError::AheadOfPosition)
+            } else {
+                Ok(())
+            };
+        }
+
+        let mut prev = current.prev();
+
+        while let Some(chunk) = prev {
+            if chunk.header.cast() == checkpoint.chunk {
+                return if chunk.contains_addr_or_end(address) {
+                    Ok(())
+                } else {
+                    Err(CheckpointError::ForeignChunk)
+                };
+            }
+
+            prev = chunk.prev();
+        }
+
+        let mut next = current.next();
+
+        while let Some(chunk) = next {
+            if chunk.header.cast() == checkpoint.chunk {
+                return Err(CheckpointError::AheadOfPosition);
+            }
+
+            next = chunk.next();
+        }
+
+        Err(CheckpointError::ForeignChunk)
+    }
+
+    /// Returns whether the checkpoint's chunk is the current chunk or one before or after it,
+    /// and whether the checkpoint's address lies within that chunk.
+    ///
+    /// Like [`check_checkpoint`](Self::check_checkpoint), this only compares pointers.
+    pub(crate) fn contains_checkpoint(self, checkpoint: Checkpoint) -> bool {
+        let Some(current) = self.as_non_dummy() else {
+            return false;
+        };
+
+        let mut chunk = Some(current);
+
+        while let Some(prev) = chunk {
+            if prev.header.cast() == checkpoint.chunk {
+                return prev.contains_addr_or_end(checkpoint.address.get());
+            }
+
+            chunk = prev.prev();
+        }
+
+        let mut chunk = current.next();
+
+        while let Some(next) = chunk {
+            if next.header.cast() == checkpoint.chunk {
+                return next.contains_addr_or_end(checkpoint.address.get());
+            }
+
+            chunk = next.next();
+        }
+
+        false
+    }
+
     #[inline(always)]
     pub(crate) unsafe fn as_non_dummy_unchecked(self) -> NonDummyChunk<A, S> {
         debug_assert!(matches!(self.classify(), ChunkClass::NonDummy(_)));
@@ -886,6 +1530,11 @@ where
         debug_assert!(size >= layout.size());
         debug_assert_eq!(size % MIN_CHUNK_ALIGN, 0);
 
+        let peak = match prev {
+            Some(prev) if S::TRACK_PEAK => Peak::new(prev.peak().capacity_before() + prev.capacity()),
+            _ => Peak::new(0),
+        };
+
         let prev = Cell::new(prev.map(|c| c.header));
         let next = Cell::new(None);
 
@@ -894,7 +1543,7 @@ where
                 let header = ptr.cast::<ChunkHeader<A>>();
 
                 header.write(ChunkHeader {
-                    pos: Cell::new(header.add(1).cast()),
+                    pos: Cell::new(header.add(1).cast::<u8>().add(header_extension_size::<S>())),
                     end: ptr.add(size),
                     prev,
                     next,
@@ -906,7 +1555,7 @@ where
                 let header = ptr.add(size).cast::<ChunkHeader<A>>().sub(1);
 
                 header.write(ChunkHeader {
-                    pos: Cell::new(header.cast()),
+                    pos: Cell::new(header.cast::<u8>().sub(header_extension_size::<S>())),
                     end: ptr,
                     prev,
                     next,
@@ -917,12 +1566,31 @@ where
             }
         };
 
-        Ok(NonDummyChunk {
+        let chunk = NonDummyChunk {
             raw: RawChunk {
                 header,
                 marker: PhantomData,
             },
-        })
+        };
+
+        if S::TRACK_PEAK {
+            unsafe { chunk.peak_ptr().write(peak) };
+        }
+
+        if S::TRACK_WASTE {
+            unsafe { chunk.waste_ptr().write(Waste::new()) };
+        }
+
+        if S::RED_ZONES {
+            unsafe { chunk.red_zones_ptr().write(RedZones::new()) };
+        }
+
+        unsafe { asan::poison_range(chunk.content_start()..chunk.content_end()) };
+
+        // The new chunk is already linked to `prev`, so the stats include all chunks.
+        S::Observer::on_chunk_allocated(size, Stats::from_raw_chunk(chunk.raw).into());
+
+        Ok(chunk)
     }
 
     /// # Panic
@@ -934,26 +1602,68 @@ where
     {
         debug_assert!(self.next().is_none());
 
+        if !S::GROWS {
+            return Err(B::allocation(layout));
+        }
+
         let required_size = ChunkSizeHint::for_capacity(layout).ok_or_else(B::capacity_overflow)?;
-        let grown_size = self.grow_size()?;
+        let grown_size = self.grow_size();
         let size = required_size.max(grown_size).calc_size().ok_or_else(B::capacity_overflow)?;
+        self.append(size)
+    }
+
+    /// Like [`append_for`](Self::append_for) but ignores the growth settings.
+    ///
+    /// The new chunk is still at least as big as this one so that the last chunk stays the biggest.
+    ///
+    /// # Panic
+    ///
+    /// [`self.next`](RawChunk::next) must return `None`
+    pub(crate) fn append_with_capacity<B: ErrorBehavior>(self, layout: Layout) -> Result<Self, B>
+    where
+        A: Allocator + Clone,
+    {
+        debug_assert!(self.next().is_none());
+
+        if !S::GROWS {
+            return Err(B::allocation(layout));
+        }
+
+        let required_size = ChunkSizeHint::for_capacity(layout).ok_or_else(B::capacity_overflow)?;
+        let own_size = ChunkSizeHint::new(self.size().get());
+        let size = required_size.max(own_size).calc_size().ok_or_else(B::capacity_overflow)?;
+        self.append(size)
+    }
+
+    fn append<B: ErrorBehavior>(self, size: ChunkSize<A, S>) -> Result<Self, B>
+    where
+        A: Allocator + Clone,
+    {
         let allocator = unsafe { self.header.as_ref().allocator.clone() };
         let new_chunk = Self::new::<B>(size, Some(self), allocator)?;
 
         unsafe {
-            self.header.as_ref().next.set(Some(new_chunk.header));
+            self.header.as_ref().store_next(Some(new_chunk.header));
         }
 
         Ok(new_chunk)
     }
 
+    /// Returns the size hint for the chunk that follows this one according to the
+    /// `CHUNK_GROWTH_FACTOR`, `CHUNK_GROWTH_STEP` and `MAXIMUM_CHUNK_SIZE` settings.
+    ///
+    /// Saturating arithmetic is fine here. A hint that is too large will make
+    /// [`calc_size`](ChunkSizeHint::calc_size) fail with a capacity overflow.
     #[inline(always)]
-    fn grow_size<B: ErrorBehavior>(self) -> Result<ChunkSizeHint<A, S>, B> {
-        let Some(size) = self.size().get().checked_mul(2) else {
-            return Err(B::capacity_overflow());
-        };
+    fn grow_size(self) -> ChunkSizeHint<A, S> {
+        let size = self
+            .size()
+            .get()
+            .saturating_mul(S::CHUNK_GROWTH_FACTOR)
+            .saturating_add(S::CHUNK_GROWTH_STEP)
+            .min(S::MAXIMUM_CHUNK_SIZE);
 
-        Ok(ChunkSizeHint::new(size))
+        ChunkSizeHint::new(size)
     }
 
     /// The caller must ensure the returned reference is dead before calling [`deallocate`](Self::deallocate).
@@ -1016,14 +1726,21 @@ where
         end - start
     }
 
+    /// Resets the bump position to the start and clears the waste and red zones records.
+    ///
+    /// Unlike [`set_pos`](Self::set_pos) this doesn't update the peak,
+    /// the callers take care of that with [`RawBump::update_peak`].
     #[inline(always)]
-    fn reset(self) {
-        unsafe {
-            if S::UP {
-                self.set_pos(self.content_start());
-            } else {
-                self.set_pos(self.content_end());
-            }
+    pub(crate) fn reset(self) {
+        let start = if S::UP { self.content_start() } else { self.content_end() };
+        unsafe { self.header.as_ref().pos.set(start) };
+
+        if S::TRACK_WASTE {
+            self.waste().clear();
+        }
+
+        if S::RED_ZONES {
+            self.red_zones().set_last(None);
         }
     }
 
@@ -1045,12 +1762,261 @@ where
 
     #[inline(always)]
     pub(crate) fn content_start(self) -> NonNull<u8> {
-        if S::UP { self.after_header() } else { self.chunk_start() }
+        if S::UP {
+            unsafe { self.after_header().add(header_extension_size::<S>()) }
+        } else {
+            self.chunk_start()
+        }
     }
 
     #[inline(always)]
     pub(crate) fn content_end(self) -> NonNull<u8> {
-        if S::UP { self.chunk_end() } else { self.header.cast() }
+        if S::UP {
+            self.chunk_end()
+        } else {
+            unsafe { self.header.cast::<u8>().sub(header_extension_size::<S>()) }
+        }
+    }
+
+    /// Returns the peak record that lives between the chunk header and the content.
+    ///
+    /// Only call this when `TRACK_PEAK` is enabled.
+    #[inline(always)]
+    fn peak_ptr(self) -> NonNull<Peak> {
+        debug_assert!(S::TRACK_PEAK);
+
+        unsafe {
+            if S::UP {
+                self.after_header().cast()
+            } else {
+                self.header.cast::<Peak>().sub(1)
+            }
+        }
+    }
+
+    /// Returns the peak record of this chunk.
+    ///
+    /// Only the one of the last chunk is up to date, see [`last`](Self::last).
+    #[inline(always)]
+    pub(crate) fn peak<'a>(self) -> &'a Peak {
+        unsafe { self.peak_ptr().as_ref() }
+    }
+
+    /// Returns the waste record that lives between the peak record and the content.
+    ///
+    /// Only call this when `TRACK_WASTE` is enabled.
+    #[inline(always)]
+    fn waste_ptr(self) -> NonNull<Waste> {
+        debug_assert!(S::TRACK_WASTE);
+
+        unsafe {
+            if S::UP {
+                self.after_header().add(Peak::size_for::<S>()).cast()
+            } else {
+                self.header.cast::<u8>().sub(Peak::size_for::<S>()).cast::<Waste>().sub(1)
+            }
+        }
+    }
+
+    /// Returns the waste record of this chunk.
+    #[inline(always)]
+    pub(crate) fn waste<'a>(self) -> &'a Waste {
+        unsafe { self.waste_ptr().as_ref() }
+    }
+
+    /// Returns the red zones record that lives between the waste record and the content.
+    ///
+    /// Only call this when `RED_ZONES` is enabled.
+    #[inline(always)]
+    fn red_zones_ptr(self) -> NonNull<RedZones> {
+        debug_assert!(S::RED_ZONES);
+
+        unsafe {
+            if S::UP {
+                self.after_header().add(Peak::size_for::<S>() + Waste::size_for::<S>()).cast()
+            } else {
+                self.header
+                    .cast::<u8>()
+                    .sub(Peak::size_for::<S>() + Waste::size_for::<S>())
+                    .cast::<RedZones>()
+                    .sub(1)
+            }
+        }
+    }
+
+    /// Returns the red zones record of this chunk.
+    #[inline(always)]
+    pub(crate) fn red_zones<'a>(self) -> &'a RedZones {
+        unsafe { self.red_zones_ptr().as_ref() }
+    }
+
+    /// Writes the guard and red zones into a memory block that was just allocated in this chunk
+    /// and returns the pointer to the allocation.
+    ///
+    /// # Safety
+    /// `block` must be the most recent allocation in this chunk and fit [`red_zone::block_layout`].
+    pub(crate) unsafe fn guard(self, block: NonNull<u8>, layout: Layout) -> NonNull<u8> {
+        let red_zones = self.red_zones();
+
+        unsafe {
+            let ptr = red_zone::write::<S>(block, layout, red_zones.last());
+            red_zones.set_last(Some(block.cast()));
+            ptr
+        }
+    }
+
+    /// Turns the start of the prepared memory `range` into an allocation of `layout` with red zones.
+    ///
+    /// `src` points to the data of the allocation within `range`.
+    /// The data is moved so there is space for the guard and the red zones.
+    ///
+    /// # Safety
+    /// - `range` must be part of a range returned from [`RawBump::prepare_allocation_range`] or
+    ///   [`red_zone::prepared_overhead`] bytes smaller than a range returned from [`RawChunk::prepare_allocation_range`]
+    /// - `layout` must fit into `range` and must not have a greater alignment than the prepared allocation
+    pub(crate) unsafe fn allocate_prepared_guarded(
+        self,
+        range: Range<NonNull<u8>>,
+        src: NonNull<u8>,
+        layout: Layout,
+    ) -> NonNull<u8> {
+        unsafe {
+            let block_layout = red_zone::block_layout::<S>(layout).unwrap_unchecked();
+
+            // The prepared range leaves room for the overhead after its end when bumping upwards
+            // and before its start when bumping downwards.
+            let block_addr = if S::UP {
+                up_align_usize_unchecked(range.start.addr().get(), block_layout.align())
+            } else {
+                down_align_usize(range.end.addr().get() - block_layout.size(), block_layout.align())
+            };
+
+            let block = range.start.with_addr(NonZeroUsize::new_unchecked(block_addr));
+
+            // The data has to be moved before writing the guard, which may overlap it.
+            src.copy_to(red_zone::allocation_in::<S>(block, layout.align()), layout.size());
+            let ptr = self.guard(block, layout);
+
+            if S::UP {
+                self.set_pos_addr_and_align(block.addr().get() + block_layout.size());
+            } else {
+                self.set_pos(block);
+            }
+
+            self.poison_remaining();
+            ptr
+        }
+    }
+
+    /// Poisons the remaining memory of this chunk for the address sanitizer.
+    ///
+    /// This is called after allocating prepared memory, because the rest of the prepared memory stays unpoisoned otherwise.
+    #[inline(always)]
+    pub(crate) fn poison_remaining(self) {
+        unsafe { asan::poison_range(self.remaining_range()) };
+    }
+
+    /// Prepares the end of the allocated range of this chunk for being freed, see [`RawBump::before_free`].
+    ///
+    /// # Safety
+    /// `range` must be the end of the allocated range of this chunk.
+    #[track_caller]
+    unsafe fn free_range(self, range: Range<NonNull<u8>>) {
+        #[cfg(feature = "track-allocations")]
+        crate::tracking::free_range(range.start.addr().get()..range.end.addr().get());
+
+        unsafe {
+            if S::RED_ZONES {
+                self.release_guards(range.clone());
+            }
+
+            poison::freed_range::<S>(range);
+        }
+    }
+
+    /// Checks the red zones of the allocations in `range` and removes their guards.
+    ///
+    /// # Panics
+    /// Panics if a red zone was overwritten.
+    ///
+    /// # Safety
+    /// `range` must be the end of the allocated range of this chunk.
+    #[track_caller]
+    unsafe fn release_guards(self, range: Range<NonNull<u8>>) {
+        let red_zones = self.red_zones();
+        let mut last = red_zones.last();
+
+        while let Some(guard) = last {
+            let released = if S::UP {
+                guard.cast() >= range.start
+            } else {
+                guard.cast() < range.end
+            };
+
+            if !released {
+                break;
+            }
+
+            unsafe {
+                if let Err(error) = Guard::check::<S>(guard) {
+                    red_zone::violated(error);
+                }
+
+                last = guard.as_ref().prev;
+            }
+        }
+
+        red_zones.set_last(last);
+    }
+
+    /// Checks the red zones of all allocations in this chunk.
+    fn check_guards(self) -> Result<(), RedZoneError> {
+        let mut last = self.red_zones().last();
+
+        while let Some(guard) = last {
+            unsafe {
+                Guard::check::<S>(guard)?;
+                last = guard.as_ref().prev;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub(crate) fn last(self) -> Self {
+        let mut chunk = self;
+
+        while let Some(next) = chunk.next() {
+            chunk = next;
+        }
+
+        chunk
+    }
+
+    /// Raises the peak record of this chunk to the current usage, treating this chunk as the current chunk.
+    #[inline(always)]
+    pub(crate) fn record_peak(self) {
+        self.peak().update_allocated_in_chunk(self.allocated());
+    }
+
+    /// Raises the peak to the current usage and merges the peak records of all chunks into
+    /// the one of the last chunk, treating this chunk as the current chunk.
+    fn merge_peaks(self) {
+        self.record_peak();
+
+        let last = self.last();
+        let mut allocated = last.peak().allocated();
+        last.for_each_prev(|chunk| allocated = allocated.max(chunk.peak().allocated()));
+
+        let capacity = Stats::from_raw_chunk(self.raw).capacity();
+        last.peak().update(allocated, capacity);
+    }
+
+    /// Copies the peak record of `other` into this chunk's one.
+    fn copy_peak_from(self, other: Self) {
+        let peak = other.peak();
+        self.peak().set(peak.allocated(), peak.capacity());
     }
 
     /// # Safety
@@ -1060,10 +2026,34 @@ where
         unsafe { self.set_pos_addr(ptr.addr().get()) };
     }
 
+    /// If `TRACK_PEAK` is enabled and this moves the bump position back, the peak is updated first.
+    /// So this must only be called on the current chunk.
+    ///
+    /// With the `nightly-asan` feature, the memory the bump position moves forward over is unpoisoned.
+    ///
     /// # Safety
     /// [`contains_addr_or_end`](RawChunk::contains_addr_or_end) must return true
     #[inline(always)]
     pub(crate) unsafe fn set_pos_addr(self, addr: usize) {
+        if S::TRACK_PEAK {
+            let pos = self.pos().addr().get();
+            let moves_back = if S::UP { addr < pos } else { addr > pos };
+
+            if moves_back {
+                self.record_peak();
+            }
+        }
+
+        if asan::ENABLED {
+            let pos = self.pos().addr().get();
+
+            if S::UP && addr > pos {
+                unsafe { asan::unpoison_range(self.content_ptr_from_addr_range(pos..addr)) };
+            } else if !S::UP && addr < pos {
+                unsafe { asan::unpoison_range(self.content_ptr_from_addr_range(addr..pos)) };
+            }
+        }
+
         unsafe { self.header.as_ref().pos.set(self.content_ptr_from_addr(addr)) };
     }
 
@@ -1095,7 +2085,7 @@ where
     /// # Safety
     /// [`contains_addr_or_end`](RawChunk::contains_addr_or_end) must return true
     #[inline(always)]
-    unsafe fn content_ptr_from_addr(self, addr: usize) -> NonNull<u8> {
+    pub(crate) unsafe fn content_ptr_from_addr(self, addr: usize) -> NonNull<u8> {
         unsafe {
             debug_assert!(self.contains_addr_or_end(addr));
             let ptr = self.header.cast();
@@ -1143,6 +2133,30 @@ where
         }
     }
 
+    /// Reads and writes back one byte per page of `range` so the operating system maps in the pages.
+    ///
+    /// The memory keeps its contents, so poisoned and zeroized memory stays that way.
+    fn prefault(self, range: Range<NonNull<u8>>) {
+        debug_assert!(self.content_start() <= range.start && range.end <= self.content_end());
+
+        let end = range.end.addr().get();
+        let mut ptr = range.start;
+
+        while ptr.addr().get() < end {
+            unsafe {
+                asan::unpoison(ptr, 1);
+
+                let byte = ptr.cast::<MaybeUninit<u8>>();
+                byte.write_volatile(byte.read_volatile());
+
+                asan::poison(ptr, 1);
+
+                let next_page = down_align_usize(ptr.addr().get(), ASSUMED_PAGE_SIZE).saturating_add(ASSUMED_PAGE_SIZE);
+                ptr = ptr.add(next_page.min(end) - ptr.addr().get());
+            }
+        }
+    }
+
     #[inline(always)]
     fn after_header(self) -> NonNull<u8> {
         unsafe { self.header.add(1).cast() }
@@ -1168,9 +2182,12 @@ where
         }
     }
 
+    /// Deallocates this chunk and reports it to the observer along with `stats`.
+    ///
     /// # Safety
     /// - self must not be used after calling this.
-    unsafe fn deallocate(self)
+    /// - `stats` must not include this chunk.
+    unsafe fn deallocate(self, stats: AnyStats<'_>)
     where
         A: Allocator,
     {
@@ -1179,9 +2196,23 @@ where
         let ptr = self.chunk_start();
         let layout = self.layout();
 
+        #[cfg(feature = "track-allocations")]
+        crate::tracking::remove_chunk(ptr.addr().get()..self.chunk_end().addr().get());
+
         unsafe {
+            // The base allocator may hand out this memory again.
+            asan::unpoison_range(ptr..self.chunk_end());
+
+            if S::ZEROIZE {
+                // This also covers prepared memory that was never allocated.
+                let content = self.content_start();
+                zeroize::zero(content, self.content_end().addr().get() - content.addr().get());
+            }
+
             allocator.deallocate(ptr, layout);
         }
+
+        S::Observer::on_chunk_deallocated(layout.size(), stats);
     }
 
     #[inline(always)]
@@ -0,0 +1,204 @@
+//! Guard bytes around allocations for the `RED_ZONES` setting.
+//!
+//! With red zones, every allocation is placed in a larger memory block that looks like this:
+//! `[guard][front red zone][allocation][back red zone]`.
+//!
+//! The [`Guard`] records the layout of the allocation and links to the guard of the previous
+//! allocation in the same chunk. The guard of a chunk's most recent allocation is stored in the
+//! chunk's [`RedZones`](crate::chunk::RedZones) record, so all allocations of a chunk can be checked.
+
+use core::{alloc::Layout, error::Error, fmt, ops::Range, ptr::NonNull};
+
+use crate::settings::{BumpAllocatorSettings, RED_ZONE};
+
+/// The minimum size of each red zone.
+const SIZE: usize = 16;
+
+/// Sits at the start of a memory block allocated with red zones.
+#[repr(C)]
+pub(crate) struct Guard {
+    pub(crate) prev: Option<NonNull<Guard>>,
+    pub(crate) layout: Layout,
+}
+
+/// The alignment of the memory block for an allocation of `align`.
+///
+/// It is at least `MIN_ALIGN` so the bump position stays aligned when placing a block in prepared memory.
+const fn block_align<S: BumpAllocatorSettings>(align: usize) -> usize {
+    let mut block_align = align_of::<Guard>();
+
+    if align > block_align {
+        block_align = align;
+    }
+
+    if S::MIN_ALIGN > block_align {
+        block_align = S::MIN_ALIGN;
+    }
+
+    block_align
+}
+
+/// The offset from the start of the memory block to the allocation.
+const fn prefix<S: BumpAllocatorSettings>(align: usize) -> usize {
+    (size_of::<Guard>() + SIZE).next_multiple_of(block_align::<S>(align))
+}
+
+/// The layout of the memory block that holds an allocation of `layout` and its red zones.
+pub(crate) fn block_layout<S: BumpAllocatorSettings>(layout: Layout) -> Option<Layout> {
+    let size = prefix::<S>(layout.align()).checked_add(layout.size())?.checked_add(SIZE)?;
+
+    // Padding the size keeps the blocks of consecutive allocations adjacent when bumping downwards,
+    // so the bump position can be restored when deallocating.
+    Layout::from_size_align(size, block_align::<S>(layout.align()))
+        .ok()
+        .map(|layout| layout.pad_to_align())
+}
+
+/// The additional space that is requested when preparing an allocation of `align`.
+///
+/// It covers the padding of the memory block and is enough to place it anywhere around the prepared allocation,
+/// see [`NonDummyChunk::allocate_prepared_guarded`](crate::raw_bump::NonDummyChunk::allocate_prepared_guarded).
+pub(crate) const fn prepared_overhead<S: BumpAllocatorSettings>(align: usize) -> usize {
+    (prefix::<S>(align) + SIZE + 2 * (block_align::<S>(align) - 1)).next_multiple_of(align)
+}
+
+/// Returns the pointer to the allocation in a memory block.
+///
+/// # Safety
+/// `block` must point to a memory block that fits [`block_layout`].
+pub(crate) unsafe fn allocation_in<S: BumpAllocatorSettings>(block: NonNull<u8>, align: usize) -> NonNull<u8> {
+    unsafe { block.add(prefix::<S>(align)) }
+}
+
+/// Writes the guard and the red zones into the memory block and returns the pointer to the allocation.
+///
+/// # Safety
+/// `block` must point to a memory block that fits [`block_layout`].
+pub(crate) unsafe fn write<S: BumpAllocatorSettings>(
+    block: NonNull<u8>,
+    layout: Layout,
+    prev: Option<NonNull<Guard>>,
+) -> NonNull<u8> {
+    unsafe {
+        let prefix = prefix::<S>(layout.align());
+        let ptr = allocation_in::<S>(block, layout.align());
+
+        block.cast::<Guard>().write(Guard { prev, layout });
+        block
+            .add(size_of::<Guard>())
+            .write_bytes(RED_ZONE, prefix - size_of::<Guard>());
+        ptr.add(layout.size()).write_bytes(RED_ZONE, SIZE);
+
+        ptr
+    }
+}
+
+/// Returns the guard of an allocation.
+///
+/// # Safety
+/// `ptr` must have been allocated with red zones and an alignment of `align`.
+pub(crate) unsafe fn guard_of<S: BumpAllocatorSettings>(ptr: NonNull<u8>, align: usize) -> NonNull<Guard> {
+    unsafe { ptr.sub(prefix::<S>(align)).cast() }
+}
+
+impl Guard {
+    /// Returns the pointer to the allocation of this guard.
+    pub(crate) unsafe fn allocation<S: BumpAllocatorSettings>(this: NonNull<Self>) -> NonNull<u8> {
+        unsafe { this.cast::<u8>().add(prefix::<S>(this.as_ref().layout.align())) }
+    }
+
+    /// Returns the end of the memory block of this guard.
+    pub(crate) unsafe fn block_end<S: BumpAllocatorSettings>(this: NonNull<Self>) -> NonNull<u8> {
+        unsafe {
+            let block_layout = block_layout::<S>(this.as_ref().layout).unwrap_unchecked();
+            this.cast::<u8>().add(block_layout.size())
+        }
+    }
+
+    /// Shrinks the allocation of this guard to `new_size` by moving the back red zone.
+    ///
+    /// Returns the memory that was given up, which starts after the new back red zone.
+    pub(crate) unsafe fn shrink<S: BumpAllocatorSettings>(mut this: NonNull<Self>, new_size: usize) -> Range<NonNull<u8>> {
+        unsafe {
+            let old_size = this.as_ref().layout.size();
+            this.as_mut().layout = Layout::from_size_align_unchecked(new_size, this.as_ref().layout.align());
+
+            let ptr = Self::allocation::<S>(this);
+            ptr.add(new_size).write_bytes(RED_ZONE, SIZE);
+            ptr.add(new_size + SIZE)..ptr.add(old_size + SIZE)
+        }
+    }
+
+    /// Checks that the red zones around the allocation are intact.
+    pub(crate) unsafe fn check<S: BumpAllocatorSettings>(this: NonNull<Self>) -> Result<(), RedZoneError> {
+        unsafe {
+            let layout = this.as_ref().layout;
+            let ptr = Self::allocation::<S>(this);
+
+            let front_start = this.cast::<u8>().add(size_of::<Guard>());
+            let front_len = prefix::<S>(layout.align()) - size_of::<Guard>();
+            let back_start = ptr.add(layout.size());
+
+            let front = core::slice::from_raw_parts(front_start.as_ptr(), front_len);
+            let back = core::slice::from_raw_parts(back_start.as_ptr(), SIZE);
+
+            if front.iter().chain(back).all(|&byte| byte == RED_ZONE) {
+                Ok(())
+            } else {
+                Err(RedZoneError {
+                    address: ptr.addr().get(),
+                    layout,
+                })
+            }
+        }
+    }
+}
+
+/// Panics with the message of `error`.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn violated(error: RedZoneError) -> ! {
+    panic!("{error}")
+}
+
+/// The error returned from [`Bump::check_integrity`] when the `RED_ZONES` [setting] is enabled.
+///
+/// It describes an allocation whose red zones have been overwritten,
+/// which means that something wrote past the start or the end of it.
+///
+/// [`Bump::check_integrity`]: crate::Bump::check_integrity
+/// [setting]: crate::settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RedZoneError {
+    address: usize,
+    layout: Layout,
+}
+
+impl RedZoneError {
+    /// The address of the allocation.
+    #[must_use]
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    /// The layout the allocation was made with.
+    #[must_use]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+}
+
+impl Error for RedZoneError {}
+
+impl fmt::Display for RedZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "red zone of the allocation at {:#x} with a size of {} and an alignment of {} was overwritten",
+            self.address,
+            self.layout.size(),
+            self.layout.align()
+        )
+    }
+}
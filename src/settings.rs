@@ -46,6 +46,81 @@
 //!
 //!   The final chunk size is calculated like described in [`with_size`],
 //!   thus it can be slightly smaller than requested.
+//! - **`MAXIMUM_CHUNK_SIZE`** *default: `usize::MAX`* — Caps the size of chunks allocated due to growth.
+//!
+//!   When the current chunk is full, the size of the next chunk is calculated from the previous chunk's size
+//!   using `CHUNK_GROWTH_FACTOR` and `CHUNK_GROWTH_STEP`. This setting caps the result.
+//!
+//!   An allocation that does not fit into a chunk of the capped size will still get a chunk that is large enough.
+//!   The final chunk size is calculated like described in [`with_size`],
+//!   thus it can be slightly smaller or bigger than requested.
+//! - **`CHUNK_GROWTH_FACTOR`** *default: 2* — The factor by which the chunk size grows.
+//!
+//!   The size of a new chunk is `previous_size * CHUNK_GROWTH_FACTOR + CHUNK_GROWTH_STEP`.
+//!   Setting this to `1` results in linear growth.
+//! - **`CHUNK_GROWTH_STEP`** *default: 0* — The amount of bytes that is added to the chunk size when growing.
+//! - **`TRACK_PEAK`** *default: false* — Keeps track of the peak memory usage.
+//!
+//!   When this is `true`, [`Stats::peak_allocated`] and [`Stats::peak_capacity`] report the highest
+//!   amount of allocated bytes and the highest capacity since the bump allocator was created or
+//!   since the last call to [`reset_peak`]. This survives resets and exiting scopes,
+//!   so it can be used to pick a good size for [`with_size`] or [`with_capacity`].
+//!
+//!   The peak is stored in 32 bytes (16 on 32-bit targets) next to each chunk header and updated whenever the
+//!   allocated bytes or the capacity decrease, which makes deallocating, shrinking, resetting and exiting scopes
+//!   a bit more expensive. This takes constant time, except when chunks are deallocated.
+//! - **`TRACK_WASTE`** *default: false* — Keeps track of the memory that is lost to alignment padding
+//!   and to memory blocks that were left behind because they couldn't grow in place.
+//!
+//!   When this is `true`, [`Stats::wasted_padding`] and [`Stats::wasted_abandoned`] report these amounts,
+//!   which together with [`Stats::wasted_tail`] can help tuning `MIN_ALIGN`, `UP` and the chunk sizes.
+//!   The counters are stored in 16 bytes next to each chunk header and are updated on every allocation.
+//! - **`POISON`** *default: false* — Fills memory with recognizable byte patterns to make use-after-free
+//!   and reads of uninitialized memory stand out.
+//!
+//!   When this is `true`, new allocations are filled with [`POISON_UNINIT`] and memory that is given back
+//!   by [`deallocate`], [`shrink`], [`reset_to`], [`reset`] or by exiting a scope is filled with [`POISON_FREED`].
+//!   Memory that is reserved but not yet allocated, like the spare capacity of a [`MutBumpVec`],
+//!   is not filled.
+//!
+//!   This makes all of those operations as expensive as writing the memory, so it is meant for debugging.
+//! - **`RED_ZONES`** *default: false* — Surrounds allocations with guard bytes to detect buffer overruns.
+//!
+//!   When this is `true`, every allocation is surrounded by red zones filled with [`RED_ZONE`] and
+//!   the layout of the allocation is recorded right in front of it.
+//!   The red zones are checked by [`deallocate`], [`reset_to`], [`reset`], when exiting a scope and
+//!   on demand by [`check_integrity`], which reports the allocation that was overrun.
+//!   All but `check_integrity` panic when a red zone was overwritten.
+//!
+//!   This also applies to allocations made from prepared memory, like the ones of a [`MutBumpVec`].
+//!   Shrinking no longer frees memory and every allocation takes up at least 32 bytes more,
+//!   so this is meant for debugging.
+//!   A [`SyncBump`] does not support red zones.
+//! - **`ZEROIZE`** *default: false* — Wipes memory with volatile writes when it is given back,
+//!   so secrets like keys or tokens don't linger in the chunks.
+//!
+//!   When this is `true`, memory that is given back by [`deallocate`], [`shrink`], [`reset_to`], [`reset`]
+//!   or by exiting a scope is overwritten with zeroes, and so is the whole content of a chunk before
+//!   it is returned to the base allocator. The writes are volatile so the compiler can't optimize them away.
+//!   If `POISON` is enabled as well, freed memory ends up filled with [`POISON_FREED`].
+//!
+//!   Memory that was prepared but never allocated, like the buffer a [`MutBumpVec`] left behind when it grew,
+//!   is only wiped when its chunk is returned to the base allocator.
+//! - **`GROWS`** *default: true* — Whether the bump allocator allocates new chunks when it runs out of space.
+//!
+//!   When this is `false`, an allocation that doesn't fit into the chunks the bump allocator already owns
+//!   fails with an [`AllocError`] instead of allocating a chunk from the base allocator, and so does a
+//!   [`reserve`] that needs a new chunk. The check happens at compile time, so this costs nothing.
+//!
+//!   This is meant for code that must not call the base allocator, like a real-time thread.
+//!   Set up the chunks with [`with_size`], [`reserve`] or [`reserve_chunks`] first and then turn growing off with
+//!   [`with_settings`] or [`borrow_mut_with_settings`].
+//!   Memory is still reused by [`reset_to`] and when exiting a scope. [`reset`] and [`reset_to_fit`]
+//!   keep all chunks, only [`trim`] gives chunks back to the base allocator.
+//! - **`Observer`** *default: `()`* — A type implementing [`BumpObserver`] that gets notified
+//!   when chunks are allocated and deallocated, when the bump allocator is reset and when a scope is exited.
+//!
+//!   The default observer `()` does nothing.
 //!
 //! # Example
 //!
@@ -61,6 +136,16 @@
 //!     /* DEALLOCATES */ false,
 //!     /* SHRINKS */ false,
 //!     /* MINIMUM_CHUNK_SIZE */ 4096,
+//!     /* MAXIMUM_CHUNK_SIZE */ { 1024 * 1024 },
+//!     /* CHUNK_GROWTH_FACTOR */ 1,
+//!     /* CHUNK_GROWTH_STEP */ 4096,
+//!     /* TRACK_PEAK */ false,
+//!     /* TRACK_WASTE */ false,
+//!     /* POISON */ false,
+//!     /* RED_ZONES */ false,
+//!     /* ZEROIZE */ false,
+//!     /* GROWS */ true,
+//!     /* Observer */ (),
 //! >;
 //!
 //! type MyBump = Bump<Global, MyBumpSettings>;
@@ -83,6 +168,19 @@
 //! [`with_size`]: crate::Bump::with_size
 //! [with_size_in]: crate::Bump::with_size_in
 //! [with_capacity]: crate::Bump::with_capacity
+//! [`with_capacity`]: crate::Bump::with_capacity
+//! [`reset_peak`]: crate::Bump::reset_peak
+//! [`Stats::peak_allocated`]: crate::stats::Stats::peak_allocated
+//! [`Stats::peak_capacity`]: crate::stats::Stats::peak_capacity
+//! [`Stats::wasted_padding`]: crate::stats::Stats::wasted_padding
+//! [`check_integrity`]: crate::Bump::check_integrity
+//! [`SyncBump`]: crate::SyncBump
+//! [`Stats::wasted_abandoned`]: crate::stats::Stats::wasted_abandoned
+//! [`Stats::wasted_tail`]: crate::stats::Stats::wasted_tail
+//! [`deallocate`]: crate::alloc::Allocator::deallocate
+//! [`shrink`]: crate::alloc::Allocator::shrink
+//! [`reset`]: crate::Bump::reset
+//! [`MutBumpVec`]: crate::MutBumpVec
 //! [with_capacity_in]: crate::Bump::with_capacity_in
 //! [`scoped`]: crate::Bump::scoped
 //! [`scoped_aligned`]: crate::Bump::scoped_aligned
@@ -102,8 +200,17 @@
 //! [`Allocator::shrink`]: crate::alloc::Allocator::shrink
 //! [`BumpAllocatorTyped::shrink_slice`]: crate::traits::BumpAllocatorTyped::shrink_slice
 //! [`by_value`]: crate::BumpScope::by_value
+//! [`AllocError`]: crate::alloc::AllocError
+//! [`reserve`]: crate::traits::BumpAllocatorTyped::reserve
+//! [`reserve_chunks`]: crate::Bump::reserve_chunks
+//! [`with_settings`]: crate::Bump::with_settings
+//! [`borrow_mut_with_settings`]: crate::Bump::borrow_mut_with_settings
+//! [`trim`]: crate::Bump::trim
+//! [`reset_to_fit`]: crate::Bump::reset_to_fit
+
+use core::marker::PhantomData;
 
-use crate::ArrayLayout;
+use crate::{ArrayLayout, stats::AnyStats};
 
 trait Sealed {}
 
@@ -155,6 +262,33 @@ pub trait BumpAllocatorSettings: Sealed {
     /// The minimum size for bump allocation chunk.
     const MINIMUM_CHUNK_SIZE: usize;
 
+    /// The maximum size for a bump allocation chunk that is allocated because the previous chunk is full.
+    const MAXIMUM_CHUNK_SIZE: usize;
+
+    /// The factor the size of the previous chunk is multiplied by to get the size of the next chunk.
+    const CHUNK_GROWTH_FACTOR: usize;
+
+    /// The amount of bytes added to the size of the next chunk after multiplying by the growth factor.
+    const CHUNK_GROWTH_STEP: usize;
+
+    /// Whether the allocator keeps track of its peak memory usage.
+    const TRACK_PEAK: bool = Self::TrackPeak::VALUE;
+
+    /// Whether the allocator keeps track of the memory it wastes.
+    const TRACK_WASTE: bool = Self::TrackWaste::VALUE;
+
+    /// Whether the allocator fills allocated and freed memory with a byte pattern.
+    const POISON: bool = Self::Poison::VALUE;
+
+    /// Whether the allocator surrounds allocations with guard bytes.
+    const RED_ZONES: bool = Self::RedZones::VALUE;
+
+    /// Whether the allocator wipes freed memory with volatile writes.
+    const ZEROIZE: bool = Self::Zeroize::VALUE;
+
+    /// Whether the allocator allocates new chunks when it runs out of space.
+    const GROWS: bool = Self::Grows::VALUE;
+
     /// The minimum alignment.
     type MinimumAlignment: SupportedMinimumAlignment;
 
@@ -175,6 +309,27 @@ pub trait BumpAllocatorSettings: Sealed {
     /// Whether the allocator tries to shrink allocations.
     type Shrinks: Boolean;
 
+    /// Whether the allocator keeps track of its peak memory usage.
+    type TrackPeak: Boolean;
+
+    /// Whether the allocator keeps track of the memory it wastes.
+    type TrackWaste: Boolean;
+
+    /// Whether the allocator fills allocated and freed memory with a byte pattern.
+    type Poison: Boolean;
+
+    /// Whether the allocator surrounds allocations with guard bytes.
+    type RedZones: Boolean;
+
+    /// Whether the allocator wipes freed memory with volatile writes.
+    type Zeroize: Boolean;
+
+    /// Whether the allocator allocates new chunks when it runs out of space.
+    type Grows: Boolean;
+
+    /// The observer that is notified about the chunk lifecycle.
+    type Observer: BumpObserver;
+
     /// Changes the minimum alignment.
     type WithMinimumAlignment<const NEW_MIN_ALIGN: usize>: BumpAllocatorSettings<
             MinimumAlignment = MinimumAlignment<NEW_MIN_ALIGN>,
@@ -183,6 +338,13 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Self::Claimable,
             Deallocates = Self::Deallocates,
             Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
         >
     where
         MinimumAlignment<NEW_MIN_ALIGN>: SupportedMinimumAlignment;
@@ -195,6 +357,13 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Self::Claimable,
             Deallocates = Self::Deallocates,
             Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
         >;
 
     /// Changes whether the allocator is guaranteed to have a chunk allocated.
@@ -205,6 +374,13 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Self::Claimable,
             Deallocates = Self::Deallocates,
             Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
         >;
 
     /// Changes whether the allocator can be [claimed].
@@ -217,6 +393,13 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Bool<VALUE>,
             Deallocates = Self::Deallocates,
             Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
         >;
 
     /// Changes whether the allocator tries to free allocations.
@@ -227,6 +410,13 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Self::Claimable,
             Deallocates = Bool<VALUE>,
             Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
         >;
 
     /// Changes whether the allocator tries to shrink allocations.
@@ -237,6 +427,13 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Self::Claimable,
             Deallocates = Self::Deallocates,
             Shrinks = Bool<VALUE>,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
         >;
 
     /// Changes the minimum chunk size.
@@ -247,6 +444,183 @@ pub trait BumpAllocatorSettings: Sealed {
             Claimable = Self::Claimable,
             Deallocates = Self::Deallocates,
             Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes the maximum chunk size.
+    type WithMaximumChunkSize<const VALUE: usize>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes the chunk growth factor.
+    type WithChunkGrowthFactor<const VALUE: usize>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes the chunk growth step.
+    type WithChunkGrowthStep<const VALUE: usize>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes whether the allocator keeps track of its peak memory usage.
+    type WithTrackPeak<const VALUE: bool>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Bool<VALUE>,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes whether the allocator keeps track of the memory it wastes.
+    type WithTrackWaste<const VALUE: bool>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Bool<VALUE>,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes whether the allocator fills allocated and freed memory with a byte pattern.
+    type WithPoison<const VALUE: bool>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Bool<VALUE>,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes whether the allocator surrounds allocations with guard bytes.
+    type WithRedZones<const VALUE: bool>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Bool<VALUE>,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes whether the allocator wipes freed memory with volatile writes.
+    type WithZeroize<const VALUE: bool>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Bool<VALUE>,
+            Grows = Self::Grows,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes whether the allocator allocates new chunks when it runs out of space.
+    type WithGrows<const VALUE: bool>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Bool<VALUE>,
+            Observer = Self::Observer,
+        >;
+
+    /// Changes the observer.
+    type WithObserver<NewObserver: BumpObserver>: BumpAllocatorSettings<
+            MinimumAlignment = Self::MinimumAlignment,
+            Up = Self::Up,
+            GuaranteedAllocated = Self::GuaranteedAllocated,
+            Claimable = Self::Claimable,
+            Deallocates = Self::Deallocates,
+            Shrinks = Self::Shrinks,
+            TrackPeak = Self::TrackPeak,
+            TrackWaste = Self::TrackWaste,
+            Poison = Self::Poison,
+            RedZones = Self::RedZones,
+            Zeroize = Self::Zeroize,
+            Grows = Self::Grows,
+            Observer = NewObserver,
         >;
 }
 
@@ -261,7 +635,19 @@ pub struct BumpSettings<
     const DEALLOCATES: bool = true,
     const SHRINKS: bool = true,
     const MINIMUM_CHUNK_SIZE: usize = 512,
->;
+    const MAXIMUM_CHUNK_SIZE: usize = { usize::MAX },
+    const CHUNK_GROWTH_FACTOR: usize = 2,
+    const CHUNK_GROWTH_STEP: usize = 0,
+    const TRACK_PEAK: bool = false,
+    const TRACK_WASTE: bool = false,
+    const POISON: bool = false,
+    const RED_ZONES: bool = false,
+    const ZEROIZE: bool = false,
+    const GROWS: bool = true,
+    O = (),
+> {
+    marker: PhantomData<fn() -> O>,
+}
 
 impl<
     const MIN_ALIGN: usize,
@@ -271,7 +657,36 @@ impl<
     const DEALLOCATES: bool,
     const SHRINKS: bool,
     const MINIMUM_CHUNK_SIZE: usize,
-> Sealed for BumpSettings<MIN_ALIGN, UP, GUARANTEED_ALLOCATED, CLAIMABLE, DEALLOCATES, SHRINKS, MINIMUM_CHUNK_SIZE>
+    const MAXIMUM_CHUNK_SIZE: usize,
+    const CHUNK_GROWTH_FACTOR: usize,
+    const CHUNK_GROWTH_STEP: usize,
+    const TRACK_PEAK: bool,
+    const TRACK_WASTE: bool,
+    const POISON: bool,
+    const RED_ZONES: bool,
+    const ZEROIZE: bool,
+    const GROWS: bool,
+    O: BumpObserver,
+> Sealed
+    for BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >
 {
 }
 
@@ -283,12 +698,43 @@ impl<
     const DEALLOCATES: bool,
     const SHRINKS: bool,
     const MINIMUM_CHUNK_SIZE: usize,
+    const MAXIMUM_CHUNK_SIZE: usize,
+    const CHUNK_GROWTH_FACTOR: usize,
+    const CHUNK_GROWTH_STEP: usize,
+    const TRACK_PEAK: bool,
+    const TRACK_WASTE: bool,
+    const POISON: bool,
+    const RED_ZONES: bool,
+    const ZEROIZE: bool,
+    const GROWS: bool,
+    O: BumpObserver,
 > BumpAllocatorSettings
-    for BumpSettings<MIN_ALIGN, UP, GUARANTEED_ALLOCATED, CLAIMABLE, DEALLOCATES, SHRINKS, MINIMUM_CHUNK_SIZE>
+    for BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >
 where
     MinimumAlignment<MIN_ALIGN>: SupportedMinimumAlignment,
 {
     const MINIMUM_CHUNK_SIZE: usize = MINIMUM_CHUNK_SIZE;
+    const MAXIMUM_CHUNK_SIZE: usize = MAXIMUM_CHUNK_SIZE;
+    const CHUNK_GROWTH_FACTOR: usize = CHUNK_GROWTH_FACTOR;
+    const CHUNK_GROWTH_STEP: usize = CHUNK_GROWTH_STEP;
 
     type MinimumAlignment = MinimumAlignment<MIN_ALIGN>;
     type Up = Bool<UP>;
@@ -296,25 +742,450 @@ where
     type Claimable = Bool<CLAIMABLE>;
     type Deallocates = Bool<DEALLOCATES>;
     type Shrinks = Bool<SHRINKS>;
+    type TrackPeak = Bool<TRACK_PEAK>;
+    type TrackWaste = Bool<TRACK_WASTE>;
+    type Poison = Bool<POISON>;
+    type RedZones = Bool<RED_ZONES>;
+    type Zeroize = Bool<ZEROIZE>;
+    type Grows = Bool<GROWS>;
+    type Observer = O;
 
     type WithMinimumAlignment<const VALUE: usize>
-        = BumpSettings<VALUE, UP, GUARANTEED_ALLOCATED, CLAIMABLE, DEALLOCATES, SHRINKS, MINIMUM_CHUNK_SIZE>
+        = BumpSettings<
+        VALUE,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >
     where
         MinimumAlignment<VALUE>: SupportedMinimumAlignment;
-    type WithUp<const VALUE: bool> =
-        BumpSettings<MIN_ALIGN, VALUE, GUARANTEED_ALLOCATED, CLAIMABLE, DEALLOCATES, SHRINKS, MINIMUM_CHUNK_SIZE>;
-    type WithGuaranteedAllocated<const VALUE: bool> =
-        BumpSettings<MIN_ALIGN, UP, VALUE, CLAIMABLE, DEALLOCATES, SHRINKS, MINIMUM_CHUNK_SIZE>;
-    type WithClaimable<const VALUE: bool> =
-        BumpSettings<MIN_ALIGN, UP, GUARANTEED_ALLOCATED, VALUE, DEALLOCATES, SHRINKS, MINIMUM_CHUNK_SIZE>;
-    type WithDeallocates<const VALUE: bool> =
-        BumpSettings<MIN_ALIGN, UP, GUARANTEED_ALLOCATED, CLAIMABLE, VALUE, SHRINKS, MINIMUM_CHUNK_SIZE>;
-    type WithShrinks<const VALUE: bool> =
-        BumpSettings<MIN_ALIGN, UP, GUARANTEED_ALLOCATED, CLAIMABLE, DEALLOCATES, VALUE, MINIMUM_CHUNK_SIZE>;
-    type WithMinimumChunkSize<const VALUE: usize> =
-        BumpSettings<MIN_ALIGN, UP, GUARANTEED_ALLOCATED, CLAIMABLE, DEALLOCATES, SHRINKS, VALUE>;
+    type WithUp<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        VALUE,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithGuaranteedAllocated<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        VALUE,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithClaimable<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        VALUE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithDeallocates<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        VALUE,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithShrinks<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        VALUE,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithMinimumChunkSize<const VALUE: usize> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        VALUE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithMaximumChunkSize<const VALUE: usize> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        VALUE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithChunkGrowthFactor<const VALUE: usize> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        VALUE,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithChunkGrowthStep<const VALUE: usize> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        VALUE,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithTrackPeak<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        VALUE,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithTrackWaste<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        VALUE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithPoison<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        VALUE,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithRedZones<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        VALUE,
+        ZEROIZE,
+        GROWS,
+        O,
+    >;
+    type WithZeroize<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        VALUE,
+        GROWS,
+        O,
+    >;
+    type WithGrows<const VALUE: bool> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        VALUE,
+        O,
+    >;
+    type WithObserver<NewObserver: BumpObserver> = BumpSettings<
+        MIN_ALIGN,
+        UP,
+        GUARANTEED_ALLOCATED,
+        CLAIMABLE,
+        DEALLOCATES,
+        SHRINKS,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+        TRACK_PEAK,
+        TRACK_WASTE,
+        POISON,
+        RED_ZONES,
+        ZEROIZE,
+        GROWS,
+        NewObserver,
+    >;
 }
 
+/// The byte that new allocations are filled with when the `POISON` [setting](crate::settings) is enabled.
+pub const POISON_UNINIT: u8 = 0xCD;
+
+/// The byte that freed memory is filled with when the `POISON` [setting](crate::settings) is enabled.
+pub const POISON_FREED: u8 = 0xDD;
+
+/// The byte that red zones are filled with when the `RED_ZONES` [setting](crate::settings) is enabled.
+pub const RED_ZONE: u8 = 0xFD;
+
+/// Gets notified about the chunk lifecycle of a bump allocator.
+///
+/// Set it using the `Observer` setting. Read the [module documentation] for how to do that.
+///
+/// All methods have an empty default implementation.
+/// The `()` observer that is used by default doesn't override any of them,
+/// so it has no runtime cost.
+///
+/// The methods are associated functions without a receiver because the observer
+/// is part of the type-level settings. To collect data you can use statics
+/// or thread locals.
+///
+/// The `stats` parameter reflects the state of the bump allocator at the time of the call.
+/// It can be used to inspect the chunks but must not be stored.
+///
+/// # Example
+///
+/// ```
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+/// use bump_scope::{Bump, alloc::Global, settings::{BumpAllocatorSettings, BumpObserver, BumpSettings}, stats::AnyStats};
+///
+/// static LIVE_CHUNK_BYTES: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct ChunkBytes;
+///
+/// impl BumpObserver for ChunkBytes {
+///     fn on_chunk_allocated(chunk_size: usize, _stats: AnyStats) {
+///         LIVE_CHUNK_BYTES.fetch_add(chunk_size, Ordering::Relaxed);
+///     }
+///
+///     fn on_chunk_deallocated(chunk_size: usize, _stats: AnyStats) {
+///         LIVE_CHUNK_BYTES.fetch_sub(chunk_size, Ordering::Relaxed);
+///     }
+/// }
+///
+/// type Settings = <BumpSettings as BumpAllocatorSettings>::WithObserver<ChunkBytes>;
+///
+/// let bump: Bump<Global, Settings> = Bump::new();
+/// assert_eq!(LIVE_CHUNK_BYTES.load(Ordering::Relaxed), bump.stats().size());
+///
+/// bump.alloc_uninit_slice::<u8>(1000);
+/// assert_eq!(LIVE_CHUNK_BYTES.load(Ordering::Relaxed), bump.stats().size());
+///
+/// drop(bump);
+/// assert_eq!(LIVE_CHUNK_BYTES.load(Ordering::Relaxed), 0);
+/// ```
+///
+/// [module documentation]: crate::settings
+pub trait BumpObserver {
+    /// Called after a chunk has been allocated from the base allocator.
+    ///
+    /// `chunk_size` is the size of the chunk's memory block including the chunk header.
+    /// `stats` already includes the new chunk.
+    #[inline(always)]
+    fn on_chunk_allocated(chunk_size: usize, stats: AnyStats<'_>) {
+        _ = (chunk_size, stats);
+    }
+
+    /// Called after a chunk has been deallocated.
+    ///
+    /// `chunk_size` is the size of the chunk's memory block including the chunk header.
+    /// `stats` no longer includes the deallocated chunk.
+    /// When the bump allocator is dropped, `stats` is empty.
+    #[inline(always)]
+    fn on_chunk_deallocated(chunk_size: usize, stats: AnyStats<'_>) {
+        _ = (chunk_size, stats);
+    }
+
+    /// Called after the bump allocator has been reset by
+    /// [`reset`], [`reset_to_start`] or [`reset_to_fit`].
+    ///
+    /// The chunks freed by the reset have already been reported to [`on_chunk_deallocated`](Self::on_chunk_deallocated).
+    ///
+    /// [`reset`]: crate::Bump::reset
+    /// [`reset_to_start`]: crate::Bump::reset_to_start
+    /// [`reset_to_fit`]: crate::Bump::reset_to_fit
+    #[inline(always)]
+    fn on_reset(stats: AnyStats<'_>) {
+        _ = stats;
+    }
+
+    /// Called after a scope has been exited and its allocations have been freed.
+    ///
+    /// This is called when the guard returned by [`scope_guard`] or [`BumpPool::get_scoped`] is dropped,
+    /// which includes exiting [`scoped`] and [`scoped_aligned`].
+    ///
+    /// [`scope_guard`]: crate::traits::BumpAllocator::scope_guard
+    /// [`scoped`]: crate::traits::BumpAllocator::scoped
+    /// [`scoped_aligned`]: crate::traits::BumpAllocator::scoped_aligned
+    /// [`BumpPool::get_scoped`]: crate::BumpPool::get_scoped
+    #[inline(always)]
+    fn on_scope_exit(stats: AnyStats<'_>) {
+        _ = stats;
+    }
+}
+
+/// Does nothing.
+impl BumpObserver for () {}
+
 /// Either [`True`] or [`False`].
 #[expect(private_bounds)]
 pub trait Boolean: Sealed {
@@ -15,16 +15,24 @@ use core::{
 };
 
 use crate::{
+    Checkpoint,
     raw_bump::{NonDummyChunk, RawChunk},
     settings::{BumpAllocatorSettings, BumpSettings, False},
 };
 
-#[cfg(debug_assertions)]
-use crate::chunk::ChunkHeader;
+#[cfg(feature = "track-allocations")]
+use alloc_crate::vec::Vec;
+
+#[cfg(feature = "track-allocations")]
+use crate::tracking::{AllocationSite, AllocationType};
 
 mod any;
+#[cfg(feature = "alloc")]
+mod snapshot;
 
 pub use any::{AnyChunk, AnyChunkNextIter, AnyChunkPrevIter, AnyStats};
+#[cfg(feature = "alloc")]
+pub use snapshot::{ChunkSnapshot, StatsDiff, StatsSnapshot};
 
 /// Provides statistics about the memory usage of the bump allocator.
 ///
@@ -140,6 +148,87 @@ where
         sum
     }
 
+    /// Returns the amount of bytes allocated since the [`checkpoint`] was created.
+    ///
+    /// Like [`allocated`](Self::allocated) this includes padding, wasted space due to reallocations
+    /// and the unused capacity at the end of chunks the bump allocator moved on from.
+    ///
+    /// Returns `None` if the checkpoint does not belong to the current or a previous chunk of this bump allocator,
+    /// or if it lies ahead of the bump position, for example after a [`reset_to`] to an earlier checkpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::Bump;
+    ///
+    /// let bump: Bump = Bump::new();
+    /// bump.alloc_str("parsing");
+    ///
+    /// let checkpoint = bump.checkpoint();
+    /// bump.alloc_slice_fill(100, 0u32);
+    ///
+    /// assert_eq!(bump.stats().allocated_since(checkpoint), Some(401));
+    /// ```
+    ///
+    /// [`checkpoint`]: crate::traits::BumpAllocatorCore::checkpoint
+    /// [`reset_to`]: crate::traits::BumpAllocatorCore::reset_to
+    #[must_use]
+    pub fn allocated_since(self, checkpoint: Checkpoint) -> Option<usize> {
+        AnyStats::from(self).allocated_since(checkpoint)
+    }
+
+    /// Returns the number of chunks the bump allocator moved on to since the [`checkpoint`] was created.
+    ///
+    /// This is `0` if all allocations since the checkpoint went into the chunk that was current back then.
+    /// For a checkpoint that was created before any chunk was allocated, every chunk up to the current chunk counts.
+    ///
+    /// Returns `None` in the same cases as [`allocated_since`](Self::allocated_since).
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::Bump;
+    ///
+    /// let bump: Bump = Bump::with_size(512);
+    /// let checkpoint = bump.checkpoint();
+    ///
+    /// bump.alloc_str("hello");
+    /// assert_eq!(bump.stats().chunks_since(checkpoint), Some(0));
+    ///
+    /// bump.alloc_uninit_slice::<u8>(1000);
+    /// assert_eq!(bump.stats().chunks_since(checkpoint), Some(1));
+    /// ```
+    ///
+    /// [`checkpoint`]: crate::traits::BumpAllocatorCore::checkpoint
+    #[must_use]
+    pub fn chunks_since(self, checkpoint: Checkpoint) -> Option<usize> {
+        AnyStats::from(self).chunks_since(checkpoint)
+    }
+
+    /// Returns `true` if `ptr` points into memory that was allocated since the [`checkpoint`] was created.
+    ///
+    /// This only compares addresses. It does not matter whether the allocation is still live.
+    ///
+    /// Returns `false` in the same cases [`allocated_since`](Self::allocated_since) returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::Bump;
+    ///
+    /// let bump: Bump = Bump::new();
+    /// let before = bump.alloc(1);
+    ///
+    /// let checkpoint = bump.checkpoint();
+    /// let after = bump.alloc(2);
+    ///
+    /// assert!(!bump.stats().is_allocated_since(checkpoint, &*before));
+    /// assert!(bump.stats().is_allocated_since(checkpoint, &*after));
+    /// ```
+    ///
+    /// [`checkpoint`]: crate::traits::BumpAllocatorCore::checkpoint
+    #[must_use]
+    pub fn is_allocated_since<T: ?Sized>(self, checkpoint: Checkpoint, ptr: *const T) -> bool {
+        AnyStats::from(self).is_allocated_since(checkpoint, ptr)
+    }
+
     /// Returns an iterator from smallest to biggest chunk.
     #[must_use]
     pub fn small_to_big(self) -> ChunkNextIter<'a, A, S> {
@@ -183,6 +272,156 @@ where
     pub fn allocator(self) -> Option<&'a A> {
         Some(self.current_chunk()?.allocator())
     }
+
+    /// Returns the highest amount of [allocated](Self::allocated) bytes since the bump allocator
+    /// was created or since [`reset_peak`](crate::Bump::reset_peak) was called.
+    ///
+    /// Returns `None` if the `TRACK_PEAK` [setting](crate::settings) is disabled or if no chunk is allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::{Bump, alloc::Global, settings::{BumpAllocatorSettings, BumpSettings}};
+    ///
+    /// type Settings = <BumpSettings as BumpAllocatorSettings>::WithTrackPeak<true>;
+    ///
+    /// let mut bump: Bump<Global, Settings> = Bump::new();
+    ///
+    /// bump.scoped(|bump| {
+    ///     bump.alloc_slice_fill(100, 0u32);
+    /// });
+    ///
+    /// bump.alloc_str("hello");
+    ///
+    /// assert_eq!(bump.stats().allocated(), 5);
+    /// assert_eq!(bump.stats().peak_allocated(), Some(400));
+    ///
+    /// bump.reset_peak();
+    /// assert_eq!(bump.stats().peak_allocated(), Some(5));
+    /// ```
+    #[must_use]
+    pub fn peak_allocated(self) -> Option<usize> {
+        AnyStats::from(self).peak_allocated()
+    }
+
+    /// Returns the highest total [capacity](Self::capacity) of all chunks since the bump allocator
+    /// was created or since [`reset_peak`](crate::Bump::reset_peak) was called.
+    ///
+    /// Returns `None` if the `TRACK_PEAK` [setting](crate::settings) is disabled or if no chunk is allocated.
+    #[must_use]
+    pub fn peak_capacity(self) -> Option<usize> {
+        AnyStats::from(self).peak_capacity()
+    }
+
+    /// Returns the unused capacity at the end of all chunks that come before the current chunk.
+    ///
+    /// This memory is lost because an allocation didn't fit into the rest of a chunk
+    /// and the bump allocator moved on to the next one.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::Bump;
+    ///
+    /// let bump: Bump = Bump::with_size(512);
+    /// let capacity = bump.stats().capacity();
+    ///
+    /// bump.alloc_uninit_slice::<u8>(capacity - 10);
+    /// assert_eq!(bump.stats().wasted_tail(), 0);
+    ///
+    /// // this doesn't fit into the first chunk
+    /// bump.alloc_uninit_slice::<u8>(20);
+    /// assert_eq!(bump.stats().wasted_tail(), 10);
+    /// ```
+    #[must_use]
+    pub fn wasted_tail(self) -> usize {
+        AnyStats::from(self).wasted_tail()
+    }
+
+    /// Returns the amount of bytes that were lost to alignment padding and `MIN_ALIGN` rounding
+    /// in the current and all previous chunks.
+    ///
+    /// A chunk's count is only cleared when the chunk is reset, so this includes the padding
+    /// of allocations that were since freed by exiting a scope or deallocating.
+    ///
+    /// Returns `None` if the `TRACK_WASTE` [setting](crate::settings) is disabled or if no chunk is allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::{Bump, alloc::Global, settings::{BumpAllocatorSettings, BumpSettings}};
+    ///
+    /// type Settings = <BumpSettings as BumpAllocatorSettings>::WithTrackWaste<true>;
+    ///
+    /// let bump: Bump<Global, Settings> = Bump::new();
+    /// bump.alloc(1u8);
+    /// bump.alloc(2u32);
+    ///
+    /// assert_eq!(bump.stats().wasted_padding(), Some(3));
+    /// ```
+    #[must_use]
+    pub fn wasted_padding(self) -> Option<usize> {
+        AnyStats::from(self).wasted_padding()
+    }
+
+    /// Returns the amount of bytes of memory blocks that were left behind in the current and all previous chunks
+    /// because they couldn't grow in place.
+    ///
+    /// A chunk's count is only cleared when the chunk is reset.
+    ///
+    /// Returns `None` if the `TRACK_WASTE` [setting](crate::settings) is disabled or if no chunk is allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::{Bump, BumpVec, alloc::Global, settings::{BumpAllocatorSettings, BumpSettings}};
+    ///
+    /// type Settings = <BumpSettings as BumpAllocatorSettings>::WithTrackWaste<true>;
+    ///
+    /// let bump: Bump<Global, Settings> = Bump::new();
+    ///
+    /// let mut vec = BumpVec::<u8, _>::with_capacity_in(4, &bump);
+    /// vec.extend_from_slice_copy(&[1, 2, 3, 4]);
+    ///
+    /// // now the vector is no longer the last allocation and can't grow in place
+    /// bump.alloc(5u8);
+    /// vec.push(5);
+    ///
+    /// assert_eq!(bump.stats().wasted_abandoned(), Some(4));
+    /// ```
+    #[must_use]
+    pub fn wasted_abandoned(self) -> Option<usize> {
+        AnyStats::from(self).wasted_abandoned()
+    }
+
+    /// Returns an owned copy of these statistics.
+    ///
+    /// See [`StatsSnapshot`].
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn snapshot(self) -> StatsSnapshot {
+        StatsSnapshot::new(self.into())
+    }
+
+    /// Returns the live allocations grouped by the location of the caller that made them,
+    /// sorted by their combined size in descending order.
+    ///
+    /// Only the current and previous chunks are considered.
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_sites(self) -> Vec<AllocationSite> {
+        AnyStats::from(self).allocation_sites()
+    }
+
+    /// Returns the live allocations grouped by their type,
+    /// sorted by their combined size in descending order.
+    ///
+    /// Only the current and previous chunks are considered.
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_types(self) -> Vec<AllocationType> {
+        AnyStats::from(self).allocation_types()
+    }
 }
 
 impl<'a, A, S> From<Chunk<'a, A, S>> for Stats<'a, A, S>
@@ -259,11 +498,6 @@ impl<'a, A, S> Chunk<'a, A, S>
 where
     S: BumpAllocatorSettings,
 {
-    #[cfg(debug_assertions)]
-    pub(crate) fn header(self) -> NonNull<ChunkHeader<A>> {
-        self.chunk.header()
-    }
-
     /// Returns the previous (smaller) chunk.
     #[must_use]
     #[inline(always)]
@@ -335,6 +569,37 @@ where
         self.chunk.remaining()
     }
 
+    /// Returns the unused capacity at the end of this chunk.
+    ///
+    /// For chunks that come before the current chunk, this memory is lost because an allocation
+    /// didn't fit and the bump allocator moved on to the next chunk.
+    /// For the current chunk and the chunks after it this is just their [`remaining`](Self::remaining) capacity.
+    #[inline]
+    #[must_use]
+    pub fn wasted_tail(self) -> usize {
+        self.chunk.remaining()
+    }
+
+    /// Returns the amount of bytes in this chunk that were lost to alignment padding
+    /// and `MIN_ALIGN` rounding since the chunk was last reset.
+    ///
+    /// Returns `None` if the `TRACK_WASTE` [setting](crate::settings) is disabled.
+    #[inline]
+    #[must_use]
+    pub fn wasted_padding(self) -> Option<usize> {
+        S::TRACK_WASTE.then(|| self.chunk.waste().padding())
+    }
+
+    /// Returns the amount of bytes of memory blocks that were left behind because they couldn't grow in place
+    /// while this chunk was the current chunk, since the chunk was last reset.
+    ///
+    /// Returns `None` if the `TRACK_WASTE` [setting](crate::settings) is disabled.
+    #[inline]
+    #[must_use]
+    pub fn wasted_abandoned(self) -> Option<usize> {
+        S::TRACK_WASTE.then(|| self.chunk.waste().abandoned())
+    }
+
     /// Returns a pointer to the start of the chunk.
     #[inline]
     #[must_use]
@@ -379,6 +644,32 @@ where
     pub fn allocator(self) -> &'a A {
         self.chunk.allocator()
     }
+
+    /// Returns the live allocations of this chunk grouped by the location of the caller that made them,
+    /// sorted by their combined size in descending order.
+    ///
+    /// This property can be misleading for chunks that come after the current chunk for the
+    /// same reason as [`allocated`](Self::allocated).
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_sites(self) -> Vec<AllocationSite> {
+        AnyChunk::from(self).allocation_sites()
+    }
+
+    /// Returns the live allocations of this chunk grouped by their type,
+    /// sorted by their combined size in descending order.
+    ///
+    /// This property can be misleading for chunks that come after the current chunk for the
+    /// same reason as [`allocated`](Self::allocated).
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_types(self) -> Vec<AllocationType> {
+        AnyChunk::from(self).allocation_types()
+    }
 }
 
 /// Iterator that iterates over previous chunks by continuously calling [`Chunk::prev`].
@@ -1,9 +1,28 @@
-use core::{fmt, iter::FusedIterator, marker::PhantomData, ptr::NonNull};
-
-use crate::{chunk::ChunkHeader, settings::BumpAllocatorSettings};
+use core::{
+    fmt,
+    iter::{self, FusedIterator},
+    marker::PhantomData,
+    ops::Range,
+    ptr::NonNull,
+};
+
+#[cfg(feature = "track-allocations")]
+use alloc_crate::vec::Vec;
+
+use crate::{
+    Checkpoint,
+    chunk::{ChunkHeader, Peak, Waste, header_extension_size},
+    settings::BumpAllocatorSettings,
+};
+
+#[cfg(feature = "track-allocations")]
+use crate::tracking::{self, AllocationSite, AllocationType};
 
 use super::{Chunk, ChunkNextIter, ChunkPrevIter, Stats};
 
+#[cfg(feature = "alloc")]
+use super::StatsSnapshot;
+
 /// Provides statistics about the memory usage of the bump allocator.
 ///
 /// This is returned from [`BumpAllocatorCore::any_stats`](crate::traits::BumpAllocatorCore::any_stats).
@@ -90,6 +109,120 @@ impl<'a> AnyStats<'a> {
         sum
     }
 
+    /// Returns the amount of bytes allocated since the [`checkpoint`] was created.
+    ///
+    /// Like [`allocated`](Self::allocated) this includes padding, wasted space due to reallocations
+    /// and the unused capacity at the end of chunks the bump allocator moved on from.
+    ///
+    /// Returns `None` if the checkpoint does not belong to the current or a previous chunk of this bump allocator,
+    /// or if it lies ahead of the bump position, for example after a [`reset_to`] to an earlier checkpoint.
+    ///
+    /// [`checkpoint`]: crate::traits::BumpAllocatorCore::checkpoint
+    /// [`reset_to`]: crate::traits::BumpAllocatorCore::reset_to
+    #[must_use]
+    pub fn allocated_since(self, checkpoint: Checkpoint) -> Option<usize> {
+        Some(self.allocated_ranges_since(checkpoint)?.map(|range| range.len()).sum())
+    }
+
+    /// Returns the number of chunks the bump allocator moved on to since the [`checkpoint`] was created.
+    ///
+    /// This is `0` if all allocations since the checkpoint went into the chunk that was current back then.
+    /// For a checkpoint that was created before any chunk was allocated, every chunk up to the current chunk counts.
+    ///
+    /// Returns `None` in the same cases as [`allocated_since`](Self::allocated_since).
+    ///
+    /// [`checkpoint`]: crate::traits::BumpAllocatorCore::checkpoint
+    #[must_use]
+    pub fn chunks_since(self, checkpoint: Checkpoint) -> Option<usize> {
+        let count = self.allocated_ranges_since(checkpoint)?.count();
+
+        if ChunkHeader::is_unallocated(checkpoint.chunk) {
+            Some(count)
+        } else {
+            Some(count - 1)
+        }
+    }
+
+    /// Returns `true` if `ptr` points into memory that was allocated since the [`checkpoint`] was created.
+    ///
+    /// This only compares addresses. It does not matter whether the allocation is still live.
+    ///
+    /// Returns `false` in the same cases [`allocated_since`](Self::allocated_since) returns `None`.
+    ///
+    /// [`checkpoint`]: crate::traits::BumpAllocatorCore::checkpoint
+    #[must_use]
+    pub fn is_allocated_since<T: ?Sized>(self, checkpoint: Checkpoint, ptr: *const T) -> bool {
+        let addr = ptr.cast::<u8>().addr();
+
+        self.allocated_ranges_since(checkpoint)
+            .is_some_and(|mut ranges| ranges.any(|range| range.contains(&addr)))
+    }
+
+    /// Returns the address ranges of the memory allocated since the checkpoint,
+    /// one for each chunk from the current chunk back to the checkpoint's chunk.
+    fn allocated_ranges_since(self, checkpoint: Checkpoint) -> Option<impl Iterator<Item = Range<usize>> + 'a> {
+        let current = self.chunk;
+        let chunks = current
+            .into_iter()
+            .chain(current.map(AnyChunk::iter_prev).unwrap_or_default());
+
+        let checkpoint_chunk = if ChunkHeader::is_unallocated(checkpoint.chunk) {
+            None
+        } else {
+            let chunk = chunks.clone().find(|chunk| chunk.header == checkpoint.chunk)?;
+            let address = checkpoint.address.get();
+
+            let start = chunk.content_start().addr().get();
+            let end = chunk.content_end().addr().get();
+
+            if address < start || address > end {
+                return None;
+            }
+
+            if Some(chunk) == current {
+                let pos = chunk.bump_position().addr().get();
+                let is_ahead = if chunk.is_upwards_allocating() {
+                    address > pos
+                } else {
+                    address < pos
+                };
+
+                if is_ahead {
+                    return None;
+                }
+            }
+
+            Some(chunk)
+        };
+
+        let mut done = false;
+
+        Some(chunks.map_while(move |chunk| {
+            if done {
+                return None;
+            }
+
+            // Previous chunks are allocated up to their end.
+            let mut range = if Some(chunk) == current {
+                chunk.allocated_range()
+            } else {
+                chunk.content_start().addr().get()..chunk.content_end().addr().get()
+            };
+
+            if Some(chunk) == checkpoint_chunk {
+                done = true;
+
+                if chunk.is_upwards_allocating() {
+                    range.start = checkpoint.address.get();
+                } else {
+                    range.end = checkpoint.address.get();
+                }
+            }
+
+            Some(range)
+        }))
+    }
+
     /// Returns an iterator from smallest to biggest chunk.
     #[must_use]
     pub fn small_to_big(self) -> AnyChunkNextIter<'a> {
@@ -124,6 +257,132 @@ impl<'a> AnyStats<'a> {
         self.chunk
     }
 
+    /// Returns the highest amount of [allocated](Self::allocated) bytes since the bump allocator
+    /// was created or since [`reset_peak`](crate::Bump::reset_peak) was called.
+    ///
+    /// Returns `None` if the bump allocator does not have the `TRACK_PEAK` [setting](crate::settings)
+    /// enabled or if no chunk is allocated.
+    #[must_use]
+    pub fn peak_allocated(self) -> Option<usize> {
+        let peak = self.peaks()?.map(Peak::allocated).max()?;
+        Some(peak.max(self.allocated()))
+    }
+
+    /// Returns the highest total [capacity](Self::capacity) of all chunks since the bump allocator
+    /// was created or since [`reset_peak`](crate::Bump::reset_peak) was called.
+    ///
+    /// Returns `None` if the bump allocator does not have the `TRACK_PEAK` [setting](crate::settings)
+    /// enabled or if no chunk is allocated.
+    #[must_use]
+    pub fn peak_capacity(self) -> Option<usize> {
+        let peak = self.peaks()?.map(Peak::capacity).max()?;
+        Some(peak.max(self.capacity()))
+    }
+
+    /// Returns the peak records of all chunks, see [`Peak`].
+    fn peaks(self) -> Option<impl Iterator<Item = &'a Peak>> {
+        let current = self.chunk?;
+        current.peak()?;
+
+        Some(
+            iter::once(current)
+                .chain(current.iter_prev())
+                .chain(current.iter_next())
+                .filter_map(AnyChunk::peak),
+        )
+    }
+
+    /// Raises the peak record of the current chunk to the current usage if the `TRACK_PEAK` setting is enabled.
+    ///
+    /// Unlike `NonDummyChunk::record_peak` this only loads the bump position atomically,
+    /// so a [`SyncBump`](crate::SyncBump) can call it while other threads allocate.
+    pub(crate) fn record_peak(self) {
+        if let Some(chunk) = self.chunk {
+            if let Some(peak) = chunk.peak() {
+                peak.update_allocated_in_chunk(chunk.allocated());
+            }
+        }
+    }
+
+    /// Returns the unused capacity at the end of all chunks that come before the current chunk.
+    ///
+    /// This memory is lost because an allocation didn't fit into the rest of a chunk
+    /// and the bump allocator moved on to the next one.
+    #[must_use]
+    pub fn wasted_tail(self) -> usize {
+        let Some(current) = self.chunk else { return 0 };
+        current.iter_prev().map(AnyChunk::wasted_tail).sum()
+    }
+
+    /// Returns the amount of bytes that were lost to alignment padding and `MIN_ALIGN` rounding
+    /// in the current and all previous chunks.
+    ///
+    /// Returns `None` if the bump allocator does not have the `TRACK_WASTE` [setting](crate::settings)
+    /// enabled or if no chunk is allocated.
+    #[must_use]
+    pub fn wasted_padding(self) -> Option<usize> {
+        let current = self.chunk?;
+        let mut sum = current.wasted_padding()?;
+        current
+            .iter_prev()
+            .for_each(|chunk| sum += chunk.wasted_padding().unwrap_or(0));
+        Some(sum)
+    }
+
+    /// Returns the amount of bytes of memory blocks that were left behind in the current and all previous chunks
+    /// because they couldn't grow in place.
+    ///
+    /// Returns `None` if the bump allocator does not have the `TRACK_WASTE` [setting](crate::settings)
+    /// enabled or if no chunk is allocated.
+    #[must_use]
+    pub fn wasted_abandoned(self) -> Option<usize> {
+        let current = self.chunk?;
+        let mut sum = current.wasted_abandoned()?;
+        current
+            .iter_prev()
+            .for_each(|chunk| sum += chunk.wasted_abandoned().unwrap_or(0));
+        Some(sum)
+    }
+
+    /// Returns an owned copy of these statistics.
+    ///
+    /// See [`StatsSnapshot`].
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn snapshot(self) -> StatsSnapshot {
+        StatsSnapshot::new(self)
+    }
+
+    /// Returns the live allocations grouped by the location of the caller that made them,
+    /// sorted by their combined size in descending order.
+    ///
+    /// Only the current and previous chunks are considered.
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_sites(self) -> Vec<AllocationSite> {
+        tracking::allocation_sites(self.live_ranges())
+    }
+
+    /// Returns the live allocations grouped by their type,
+    /// sorted by their combined size in descending order.
+    ///
+    /// Only the current and previous chunks are considered.
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_types(self) -> Vec<AllocationType> {
+        tracking::allocation_types(self.live_ranges())
+    }
+
+    #[cfg(feature = "track-allocations")]
+    fn live_ranges(self) -> impl Iterator<Item = Range<usize>> + 'a {
+        let prev = self.chunk.into_iter().flat_map(AnyChunk::iter_prev);
+        self.chunk.into_iter().chain(prev).map(AnyChunk::allocated_range)
+    }
+
     pub(crate) fn debug_format(self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct(name)
             .field("allocated", &self.allocated())
@@ -144,6 +403,14 @@ impl<'a> From<AnyChunk<'a>> for AnyStats<'a> {
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct AnyChunk<'a> {
     header: NonNull<ChunkHeader>,
+    /// The size of the actual `ChunkHeader<A>`.
+    header_size: usize,
+    /// The size of the records between the chunk header and the content, see [`header_extension_size`].
+    extension_size: usize,
+    /// Whether the header extension starts with a peak record.
+    track_peak: bool,
+    /// Whether the header extension has a waste record that follows the peak record.
+    track_waste: bool,
     marker: PhantomData<&'a ()>,
 }
 
@@ -154,6 +421,10 @@ where
     fn from(value: Chunk<'_, A, S>) -> Self {
         Self {
             header: value.chunk.header().cast(),
+            header_size: size_of::<ChunkHeader<A>>(),
+            extension_size: header_extension_size::<S>(),
+            track_peak: S::TRACK_PEAK,
+            track_waste: S::TRACK_WASTE,
             marker: PhantomData,
         }
     }
@@ -186,7 +457,7 @@ impl<'a> AnyChunk<'a> {
     pub fn prev(self) -> Option<Self> {
         Some(AnyChunk {
             header: self.header().prev.get()?,
-            marker: PhantomData,
+            ..self
         })
     }
 
@@ -195,8 +466,8 @@ impl<'a> AnyChunk<'a> {
     #[inline(always)]
     pub fn next(self) -> Option<Self> {
         Some(AnyChunk {
-            header: self.header().next.get()?,
-            marker: PhantomData,
+            header: self.header().load_next()?,
+            ..self
         })
     }
 
@@ -271,6 +542,37 @@ impl<'a> AnyChunk<'a> {
         }
     }
 
+    /// Returns the unused capacity at the end of this chunk.
+    ///
+    /// For chunks that come before the current chunk, this memory is lost because an allocation
+    /// didn't fit and the bump allocator moved on to the next chunk.
+    /// For the current chunk and the chunks after it this is just their [`remaining`](Self::remaining) capacity.
+    #[must_use]
+    #[inline]
+    pub fn wasted_tail(self) -> usize {
+        self.remaining()
+    }
+
+    /// Returns the amount of bytes in this chunk that were lost to alignment padding
+    /// and `MIN_ALIGN` rounding since the chunk was last reset.
+    ///
+    /// Returns `None` if the bump allocator does not have the `TRACK_WASTE` [setting](crate::settings) enabled.
+    #[must_use]
+    #[inline]
+    pub fn wasted_padding(self) -> Option<usize> {
+        Some(self.waste()?.padding())
+    }
+
+    /// Returns the amount of bytes of memory blocks that were left behind because they couldn't grow in place
+    /// while this chunk was the current chunk, since the chunk was last reset.
+    ///
+    /// Returns `None` if the bump allocator does not have the `TRACK_WASTE` [setting](crate::settings) enabled.
+    #[must_use]
+    #[inline]
+    pub fn wasted_abandoned(self) -> Option<usize> {
+        Some(self.waste()?.abandoned())
+    }
+
     /// Returns a pointer to the start of the chunk.
     #[must_use]
     #[inline]
@@ -298,7 +600,7 @@ impl<'a> AnyChunk<'a> {
     #[inline]
     pub fn content_start(self) -> NonNull<u8> {
         if self.is_upwards_allocating() {
-            self.after_header()
+            unsafe { self.after_header().add(self.extension_size) }
         } else {
             self.chunk_start()
         }
@@ -311,7 +613,7 @@ impl<'a> AnyChunk<'a> {
         if self.is_upwards_allocating() {
             self.chunk_end()
         } else {
-            self.header.cast()
+            unsafe { self.header.cast::<u8>().sub(self.extension_size) }
         }
     }
 
@@ -322,11 +624,77 @@ impl<'a> AnyChunk<'a> {
     #[must_use]
     #[inline]
     pub fn bump_position(self) -> NonNull<u8> {
-        self.header().pos.get()
+        self.header().load_pos()
+    }
+
+    /// Returns the live allocations of this chunk grouped by the location of the caller that made them,
+    /// sorted by their combined size in descending order.
+    ///
+    /// This property can be misleading for chunks that come after the current chunk for the
+    /// same reason as [`allocated`](Self::allocated).
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_sites(self) -> Vec<AllocationSite> {
+        tracking::allocation_sites([self.allocated_range()])
+    }
+
+    /// Returns the live allocations of this chunk grouped by their type,
+    /// sorted by their combined size in descending order.
+    ///
+    /// This property can be misleading for chunks that come after the current chunk for the
+    /// same reason as [`allocated`](Self::allocated).
+    ///
+    /// See the [`tracking`](crate::tracking) module for details.
+    #[must_use]
+    #[cfg(feature = "track-allocations")]
+    pub fn allocation_types(self) -> Vec<AllocationType> {
+        tracking::allocation_types([self.allocated_range()])
+    }
+
+    fn allocated_range(self) -> Range<usize> {
+        let (start, end) = if self.is_upwards_allocating() {
+            (self.content_start(), self.bump_position())
+        } else {
+            (self.bump_position(), self.content_end())
+        };
+
+        start.addr().get()..end.addr().get()
     }
 
     fn after_header(self) -> NonNull<u8> {
-        unsafe { self.header.add(1).cast() }
+        unsafe { self.header.cast::<u8>().add(self.header_size) }
+    }
+
+    fn peak(self) -> Option<&'a Peak> {
+        if !self.track_peak {
+            return None;
+        }
+
+        let ptr = if self.is_upwards_allocating() {
+            self.after_header().cast::<Peak>()
+        } else {
+            unsafe { self.header.cast::<Peak>().sub(1) }
+        };
+
+        Some(unsafe { ptr.as_ref() })
+    }
+
+    fn waste(self) -> Option<&'a Waste> {
+        if !self.track_waste {
+            return None;
+        }
+
+        let peak_size = if self.track_peak { size_of::<Peak>() } else { 0 };
+
+        let ptr = if self.is_upwards_allocating() {
+            unsafe { self.after_header().add(peak_size).cast::<Waste>() }
+        } else {
+            unsafe { self.header.cast::<u8>().sub(peak_size).cast::<Waste>().sub(1) }
+        };
+
+        Some(unsafe { ptr.as_ref() })
     }
 }
 
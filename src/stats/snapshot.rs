@@ -0,0 +1,293 @@
+use core::fmt;
+
+use alloc_crate::vec::Vec;
+
+use super::AnyStats;
+
+/// An owned copy of the [statistics](super) of a bump allocator at some point in time.
+///
+/// Unlike [`Stats`](super::Stats) and [`AnyStats`] this does not borrow the bump allocator,
+/// so it can be stored, compared and sent elsewhere.
+/// With the `serde` feature it implements `Serialize`.
+///
+/// Its [`Display`](fmt::Display) implementation prints the totals followed by one line per chunk.
+///
+/// This is returned from [`Stats::snapshot`](super::Stats::snapshot) and [`AnyStats::snapshot`].
+///
+/// # Examples
+/// ```
+/// use bump_scope::Bump;
+///
+/// let bump: Bump = Bump::with_size(512);
+/// let before = bump.stats().snapshot();
+///
+/// bump.alloc_str("hello");
+///
+/// let after = bump.stats().snapshot();
+/// assert_eq!(after.allocated(), 5);
+/// assert_eq!(after.diff(&before).allocated(), 5);
+///
+/// println!("{after}");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StatsSnapshot {
+    chunks: Vec<ChunkSnapshot>,
+    current: Option<usize>,
+    size: usize,
+    capacity: usize,
+    allocated: usize,
+    remaining: usize,
+}
+
+impl StatsSnapshot {
+    pub(crate) fn new(stats: AnyStats<'_>) -> Self {
+        let mut chunks = Vec::new();
+        let mut current = None;
+
+        if let Some(current_chunk) = stats.current_chunk() {
+            for chunk in stats.small_to_big() {
+                if chunk == current_chunk {
+                    current = Some(chunks.len());
+                }
+
+                // The bump position of chunks after the current chunk is stale until they
+                // become the current chunk again, so we report them as empty.
+                let is_following = current.is_some() && chunk != current_chunk;
+
+                chunks.push(ChunkSnapshot {
+                    size: chunk.size(),
+                    capacity: chunk.capacity(),
+                    allocated: if is_following { 0 } else { chunk.allocated() },
+                    remaining: if is_following { chunk.capacity() } else { chunk.remaining() },
+                });
+            }
+        }
+
+        Self {
+            chunks,
+            current,
+            size: stats.size(),
+            capacity: stats.capacity(),
+            allocated: stats.allocated(),
+            remaining: stats.remaining(),
+        }
+    }
+
+    /// Returns the chunks ordered from smallest to biggest.
+    #[must_use]
+    pub fn chunks(&self) -> &[ChunkSnapshot] {
+        &self.chunks
+    }
+
+    /// Returns the index of the chunk that was current when the snapshot was taken.
+    ///
+    /// Returns `None` if no chunk was allocated.
+    #[must_use]
+    pub fn current_chunk_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns the chunk that was current when the snapshot was taken.
+    #[must_use]
+    pub fn current_chunk(&self) -> Option<&ChunkSnapshot> {
+        self.chunks.get(self.current?)
+    }
+
+    /// Returns the number of chunks.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the total size of all chunks.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the total capacity of all chunks.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the amount of allocated bytes, see [`Stats::allocated`](super::Stats::allocated).
+    #[must_use]
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Returns the remaining capacity in bytes, see [`Stats::remaining`](super::Stats::remaining).
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns how the totals changed from `earlier` to `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bump_scope::Bump;
+    ///
+    /// let bump: Bump = Bump::with_size(512);
+    /// let before = bump.stats().snapshot();
+    ///
+    /// bump.alloc_uninit_slice::<u8>(1000);
+    ///
+    /// let diff = bump.stats().snapshot().diff(&before);
+    /// assert_eq!(diff.count(), 1);
+    /// assert!(diff.capacity() > 0);
+    /// ```
+    #[must_use]
+    pub fn diff(&self, earlier: &Self) -> StatsDiff {
+        StatsDiff {
+            count: delta(self.count(), earlier.count()),
+            size: delta(self.size, earlier.size),
+            capacity: delta(self.capacity, earlier.capacity),
+            allocated: delta(self.allocated, earlier.allocated),
+            remaining: delta(self.remaining, earlier.remaining),
+        }
+    }
+}
+
+impl fmt::Display for StatsSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "chunks: {}, size: {}, capacity: {}, allocated: {}, remaining: {}",
+            self.count(),
+            self.size,
+            self.capacity,
+            self.allocated,
+            self.remaining
+        )?;
+
+        for (i, chunk) in self.chunks.iter().enumerate() {
+            write!(f, "\nchunk {i}: {chunk}")?;
+
+            if self.current == Some(i) {
+                f.write_str(" (current)")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An owned copy of the statistics of a single chunk.
+///
+/// See [`StatsSnapshot`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkSnapshot {
+    size: usize,
+    capacity: usize,
+    allocated: usize,
+    remaining: usize,
+}
+
+impl ChunkSnapshot {
+    /// Returns the size of this chunk in bytes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the capacity of this chunk in bytes.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the amount of allocated bytes.
+    ///
+    /// This is `0` for chunks that come after the current chunk.
+    #[must_use]
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Returns the remaining capacity.
+    ///
+    /// This is the whole capacity for chunks that come after the current chunk.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl fmt::Display for ChunkSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "size: {}, capacity: {}, allocated: {}, remaining: {}",
+            self.size, self.capacity, self.allocated, self.remaining
+        )
+    }
+}
+
+/// The difference between two [`StatsSnapshot`]s.
+///
+/// This is returned from [`StatsSnapshot::diff`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StatsDiff {
+    count: isize,
+    size: isize,
+    capacity: isize,
+    allocated: isize,
+    remaining: isize,
+}
+
+impl StatsDiff {
+    /// Returns the change in the number of chunks.
+    #[must_use]
+    pub fn count(&self) -> isize {
+        self.count
+    }
+
+    /// Returns the change in the total size of all chunks.
+    #[must_use]
+    pub fn size(&self) -> isize {
+        self.size
+    }
+
+    /// Returns the change in the total capacity of all chunks.
+    #[must_use]
+    pub fn capacity(&self) -> isize {
+        self.capacity
+    }
+
+    /// Returns the change in the amount of allocated bytes.
+    #[must_use]
+    pub fn allocated(&self) -> isize {
+        self.allocated
+    }
+
+    /// Returns the change in the remaining capacity.
+    #[must_use]
+    pub fn remaining(&self) -> isize {
+        self.remaining
+    }
+
+    /// Returns `true` if nothing changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for StatsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "chunks: {:+}, size: {:+}, capacity: {:+}, allocated: {:+}, remaining: {:+}",
+            self.count, self.size, self.capacity, self.allocated, self.remaining
+        )
+    }
+}
+
+/// Sizes of allocations and chunks never exceed `isize::MAX`, so the difference fits into an `isize`.
+#[inline]
+#[expect(clippy::cast_possible_wrap)]
+fn delta(now: usize, earlier: usize) -> isize {
+    now.wrapping_sub(earlier) as isize
+}
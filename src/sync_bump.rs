@@ -0,0 +1,1220 @@
+use core::{
+    alloc::Layout,
+    fmt::{self, Debug},
+    hint,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    num::NonZeroUsize,
+    ops::Range,
+    ptr::NonNull,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+use crate::{
+    BaseAllocator, Bump, Checkpoint, CheckpointError, ErrorBehavior,
+    alloc::{AllocError, Allocator},
+    asan, bump_down,
+    bumping::{self, BumpUp},
+    chunk::{ChunkHeader, ChunkSize},
+    layout::CustomLayout,
+    maybe_default_allocator, poison,
+    polyfill::non_null,
+    raw_bump::{ChunkClass, NonDummyChunk, RawChunk},
+    settings::{BumpAllocatorSettings, BumpSettings, False},
+    stats::{AnyStats, Stats},
+    traits::{BumpAllocatorCore, BumpAllocatorCoreScope, BumpAllocatorTyped, bump_allocator_typed::for_trait_object},
+    up_align_usize_unchecked,
+};
+
+#[cfg(feature = "panic-on-alloc")]
+use crate::panic_on_error;
+
+macro_rules! make_type {
+    ($($allocator_parameter:tt)*) => {
+        /// A bump allocator that can be shared between threads.
+        ///
+        /// Unlike [`Bump`], a `SyncBump` is `Sync`. The bump position of its chunks is updated
+        /// atomically so multiple threads can allocate from a `&SyncBump` at the same time.
+        /// When a chunk is full, a short lock is taken to switch to the next chunk.
+        ///
+        /// It implements [`BumpAllocatorCore`] and [`BumpAllocatorTyped`], so it can be used
+        /// with collections like [`BumpVec`](crate::BumpVec) and with the `alloc*` methods of
+        /// [`BumpAllocatorTypedScope`](crate::traits::BumpAllocatorTypedScope) when borrowed.
+        ///
+        /// Growing, shrinking and deallocating the most recent allocation works like in a `Bump`
+        /// as long as no other thread has allocated in the meantime. Shrinking in place is only
+        /// supported when bumping upwards.
+        ///
+        /// A `SyncBump` has the same chunk layout as a `Bump`, so converting between the two
+        /// with [`From`] and [`into_bump`](SyncBump::into_bump) is free.
+        ///
+        /// # Examples
+        /// ```
+        /// use bump_scope::{SyncBump, traits::BumpAllocatorTypedScope};
+        ///
+        /// let bump: SyncBump = SyncBump::new();
+        ///
+        /// let strings = std::thread::scope(|s| {
+        ///     let bump = &bump;
+        ///
+        ///     let handles = (0..4)
+        ///         .map(|i| s.spawn(move || bump.alloc_fmt(format_args!("thread {i}")).into_ref()))
+        ///         .collect::<Vec<_>>();
+        ///
+        ///     handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+        /// });
+        ///
+        /// assert_eq!(strings, ["thread 0", "thread 1", "thread 2", "thread 3"]);
+        /// ```
+        pub struct SyncBump<$($allocator_parameter)*, S = BumpSettings>
+        where
+            A: Allocator,
+            S: BumpAllocatorSettings,
+        {
+            /// Either a chunk allocated from the base allocator, or either a `CLAIMED`
+            /// or `UNALLOCATED` dummy chunk.
+            chunk: AtomicPtr<ChunkHeader<A>>,
+
+            /// Held while switching chunks, reserving capacity or resetting to a checkpoint.
+            lock: AtomicBool,
+
+            marker: PhantomData<(*const (), fn() -> (A, S))>,
+        }
+    };
+}
+
+maybe_default_allocator!(make_type);
+
+unsafe impl<A, S> Send for SyncBump<A, S>
+where
+    A: Send + Allocator,
+    S: BumpAllocatorSettings,
+{
+}
+
+// The base allocator is used and cloned from multiple threads.
+unsafe impl<A, S> Sync for SyncBump<A, S>
+where
+    A: Send + Sync + Allocator,
+    S: BumpAllocatorSettings,
+{
+}
+
+impl<A, S> Drop for SyncBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    fn drop(&mut self) {
+        let ptr = unsafe { NonNull::new_unchecked(*self.chunk.get_mut()) };
+        drop(unsafe { Bump::<A, S>::from_raw(ptr.cast()) });
+    }
+}
+
+impl<A, S> Debug for SyncBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.stats().debug_format("SyncBump", f)
+    }
+}
+
+impl<A, S> Default for SyncBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+    Bump<A, S>: Default,
+{
+    /// Does the same as <code>[Bump]::default</code>.
+    #[inline(always)]
+    fn default() -> Self {
+        Bump::default().into()
+    }
+}
+
+impl<A, S> From<Bump<A, S>> for SyncBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    fn from(bump: Bump<A, S>) -> Self {
+        let this = Self::from_raw_chunk(bump.raw.chunk.get());
+
+        // A `Bump` resets the position of a chunk when it moves into it, a `SyncBump` expects
+        // the chunks after the current one to be reset already.
+        if let Some(chunk) = bump.raw.chunk.get().as_non_dummy() {
+            reset_following(chunk);
+        }
+
+        _ = bump.into_raw();
+        this
+    }
+}
+
+impl<A, S> SyncBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings<GuaranteedAllocated = False>,
+{
+    /// Constructs a new `SyncBump` without allocating a chunk.
+    ///
+    /// This requires the `GUARANTEED_ALLOCATED` setting to be `false`, see [`settings`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_scope::{
+    ///     alloc::Global,
+    ///     SyncBump,
+    ///     settings::{BumpSettings, BumpAllocatorSettings}
+    /// };
+    ///
+    /// type Settings = <BumpSettings as BumpAllocatorSettings>::WithGuaranteedAllocated<false>;
+    ///
+    /// let bump: SyncBump<Global, Settings> = SyncBump::unallocated();
+    /// # _ = bump;
+    /// ```
+    ///
+    /// [`settings`]: crate::settings
+    #[must_use]
+    pub const fn unallocated() -> Self {
+        const {
+            assert!(!S::RED_ZONES, "`SyncBump` does not support the `RED_ZONES` setting");
+        }
+
+        Self {
+            chunk: AtomicPtr::new(ChunkHeader::unallocated::<S>().as_ptr().cast()),
+            lock: AtomicBool::new(false),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Methods for a `SyncBump` with a default base allocator.
+impl<A, S> SyncBump<A, S>
+where
+    A: Allocator + Default,
+    S: BumpAllocatorSettings,
+{
+    /// Constructs a new `SyncBump` with a default size hint for the first chunk.
+    ///
+    /// See [`Bump::new`].
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    #[must_use]
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn new() -> Self {
+        Bump::new().into()
+    }
+
+    /// Constructs a new `SyncBump` with a default size hint for the first chunk.
+    ///
+    /// See [`Bump::try_new`].
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    pub fn try_new() -> Result<Self, AllocError> {
+        Bump::try_new().map(Into::into)
+    }
+
+    /// Constructs a new `SyncBump` with a size hint for the first chunk.
+    ///
+    /// See [`Bump::with_size`].
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    #[must_use]
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn with_size(size: usize) -> Self {
+        Bump::with_size(size).into()
+    }
+
+    /// Constructs a new `SyncBump` with a size hint for the first chunk.
+    ///
+    /// See [`Bump::try_with_size`].
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    pub fn try_with_size(size: usize) -> Result<Self, AllocError> {
+        Bump::try_with_size(size).map(Into::into)
+    }
+
+    /// Constructs a new `SyncBump` with a chunk that has at least enough space for `layout`.
+    ///
+    /// See [`Bump::with_capacity`].
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    #[must_use]
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn with_capacity(layout: Layout) -> Self {
+        Bump::with_capacity(layout).into()
+    }
+
+    /// Constructs a new `SyncBump` with a chunk that has at least enough space for `layout`.
+    ///
+    /// See [`Bump::try_with_capacity`].
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity(layout: Layout) -> Result<Self, AllocError> {
+        Bump::try_with_capacity(layout).map(Into::into)
+    }
+}
+
+/// Methods that are always available.
+impl<A, S> SyncBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    /// Constructs a new `SyncBump` with a default size hint for the first chunk.
+    ///
+    /// See [`Bump::new_in`].
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    #[must_use]
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn new_in(allocator: A) -> Self {
+        Bump::new_in(allocator).into()
+    }
+
+    /// Constructs a new `SyncBump` with a default size hint for the first chunk.
+    ///
+    /// See [`Bump::try_new_in`].
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    pub fn try_new_in(allocator: A) -> Result<Self, AllocError> {
+        Bump::try_new_in(allocator).map(Into::into)
+    }
+
+    /// Constructs a new `SyncBump` with a size hint for the first chunk.
+    ///
+    /// See [`Bump::with_size_in`].
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    #[must_use]
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn with_size_in(size: usize, allocator: A) -> Self {
+        Bump::with_size_in(size, allocator).into()
+    }
+
+    /// Constructs a new `SyncBump` with a size hint for the first chunk.
+    ///
+    /// See [`Bump::try_with_size_in`].
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    pub fn try_with_size_in(size: usize, allocator: A) -> Result<Self, AllocError> {
+        Bump::try_with_size_in(size, allocator).map(Into::into)
+    }
+
+    /// Constructs a new `SyncBump` with a chunk that has at least enough space for `layout`.
+    ///
+    /// See [`Bump::with_capacity_in`].
+    ///
+    /// # Panics
+    /// Panics if the allocation fails.
+    #[must_use]
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    pub fn with_capacity_in(layout: Layout, allocator: A) -> Self {
+        Bump::with_capacity_in(layout, allocator).into()
+    }
+
+    /// Constructs a new `SyncBump` with a chunk that has at least enough space for `layout`.
+    ///
+    /// See [`Bump::try_with_capacity_in`].
+    ///
+    /// # Errors
+    /// Errors if the allocation fails.
+    #[inline(always)]
+    pub fn try_with_capacity_in(layout: Layout, allocator: A) -> Result<Self, AllocError> {
+        Bump::try_with_capacity_in(layout, allocator).map(Into::into)
+    }
+
+    /// Resets this bump allocator and deallocates all but the largest chunk.
+    ///
+    /// See [`Bump::reset`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::{SyncBump, traits::BumpAllocatorTypedScope};
+    /// let mut bump: SyncBump = SyncBump::with_size(512);
+    ///
+    /// // won't fit in the first chunk, will be allocated in another chunk
+    /// (&bump).alloc_uninit_slice::<u8>(600);
+    /// assert_eq!(bump.stats().count(), 2);
+    ///
+    /// bump.reset();
+    /// assert_eq!(bump.stats().count(), 1);
+    /// assert_eq!(bump.stats().allocated(), 0);
+    /// ```
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.with_bump(Bump::reset);
+    }
+
+    /// Resets this bump allocator.
+    ///
+    /// See [`Bump::reset_to_start`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::{SyncBump, traits::BumpAllocatorTypedScope};
+    /// let mut bump: SyncBump = SyncBump::with_size(512);
+    ///
+    /// // won't fit in the first chunk, will be allocated in another chunk
+    /// (&bump).alloc_uninit_slice::<u8>(600);
+    /// assert_eq!(bump.stats().count(), 2);
+    ///
+    /// bump.reset_to_start();
+    /// assert_eq!(bump.stats().count(), 2);
+    /// assert_eq!(bump.stats().allocated(), 0);
+    /// ```
+    #[inline(always)]
+    pub fn reset_to_start(&mut self) {
+        self.with_bump(Bump::reset_to_start);
+
+        if let Some(chunk) = self.current_chunk().as_non_dummy() {
+            reset_following(chunk);
+        }
+    }
+
+    /// Resets the bump position to a previously created checkpoint after checking
+    /// that the checkpoint belongs to this bump allocator.
+    ///
+    /// See [`Bump::try_reset_to`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the checkpoint does not belong to this bump allocator, if it lies ahead of
+    /// the current bump position or if the bump allocator is claimed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::{SyncBump, traits::{BumpAllocatorCore, BumpAllocatorTypedScope}};
+    /// let mut bump: SyncBump = SyncBump::new();
+    /// let checkpoint = bump.checkpoint();
+    ///
+    /// (&bump).alloc_str("hello");
+    /// assert_eq!(bump.stats().allocated(), 5);
+    ///
+    /// bump.try_reset_to(checkpoint).unwrap();
+    /// assert_eq!(bump.stats().allocated(), 0);
+    /// ```
+    #[inline]
+    pub fn try_reset_to(&mut self, checkpoint: Checkpoint) -> Result<(), CheckpointError>
+    where
+        A: BaseAllocator<S::GuaranteedAllocated>,
+    {
+        self.current_chunk().check_checkpoint(checkpoint)?;
+
+        // SAFETY: We checked that the checkpoint points into this bump allocator and is not ahead of
+        // the bump position. We have exclusive access so there are no references to newer allocations.
+        unsafe { self.reset_to(checkpoint) };
+        Ok(())
+    }
+
+    /// Returns a type which provides statistics about the memory usage of the bump allocator.
+    ///
+    /// The statistics may be outdated as soon as they are read when other threads are allocating.
+    #[must_use]
+    #[inline(always)]
+    pub fn stats(&self) -> AnyStats<'_> {
+        Stats::from_raw_chunk(self.current_chunk()).into()
+    }
+
+    /// Converts this `SyncBump` into a [`Bump`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::{Bump, SyncBump, traits::BumpAllocatorTypedScope};
+    /// let bump: SyncBump = SyncBump::new();
+    /// (&bump).alloc_str("hello");
+    ///
+    /// let bump: Bump = bump.into_bump();
+    /// assert_eq!(bump.stats().allocated(), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_bump(self) -> Bump<A, S> {
+        let this = ManuallyDrop::new(self);
+        let ptr = this.chunk.load(Ordering::Relaxed);
+        unsafe { Bump::from_raw(NonNull::new_unchecked(ptr).cast()) }
+    }
+
+    #[inline(always)]
+    fn from_raw_chunk(chunk: RawChunk<A, S>) -> Self {
+        const {
+            assert!(!S::RED_ZONES, "`SyncBump` does not support the `RED_ZONES` setting");
+        }
+
+        Self {
+            chunk: AtomicPtr::new(chunk.header.as_ptr()),
+            lock: AtomicBool::new(false),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn current_chunk(&self) -> RawChunk<A, S> {
+        RawChunk {
+            header: unsafe { NonNull::new_unchecked(self.chunk.load(Ordering::Acquire)) },
+            marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn set_current_chunk(&self, chunk: RawChunk<A, S>) {
+        self.chunk.store(chunk.header.as_ptr(), Ordering::Release);
+    }
+
+    /// Calls `f` with a `Bump` that views the same chunks.
+    ///
+    /// Exclusive access means that no other thread can be allocating, so we can use the `Bump` api.
+    fn with_bump<R>(&mut self, f: impl FnOnce(&mut Bump<A, S>) -> R) -> R {
+        let ptr = unsafe { NonNull::new_unchecked(*self.chunk.get_mut()) };
+        let mut bump = ManuallyDrop::new(unsafe { Bump::<A, S>::from_raw(ptr.cast()) });
+        let result = f(&mut bump);
+        *self.chunk.get_mut() = ManuallyDrop::into_inner(bump).into_raw().as_ptr().cast();
+        result
+    }
+
+    fn lock(&self) -> LockGuard<'_> {
+        while self
+            .lock
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            while self.lock.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+
+        LockGuard(&self.lock)
+    }
+}
+
+impl<A, S> SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn alloc<E: ErrorBehavior>(&self, layout: Layout) -> Result<NonNull<u8>, E> {
+        let result = match alloc_in(self.current_chunk(), layout) {
+            Some(ptr) => Ok(ptr),
+            None => self.alloc_in_another_chunk(layout),
+        };
+
+        if let Ok(ptr) = &result {
+            unsafe { poison::uninit::<S>(*ptr, layout.size()) };
+        }
+
+        result
+    }
+
+    /// Allocation slow path.
+    #[cold]
+    #[inline(never)]
+    fn alloc_in_another_chunk<E: ErrorBehavior>(&self, layout: Layout) -> Result<NonNull<u8>, E> {
+        let _guard = self.lock();
+        let chunk = self.current_chunk();
+
+        // Another thread may have switched chunks while we were waiting for the lock.
+        if let Some(ptr) = alloc_in(chunk, layout) {
+            return Ok(ptr);
+        }
+
+        let new_chunk = match chunk.classify() {
+            ChunkClass::Claimed => Err(E::claimed()),
+            ChunkClass::Unallocated if !S::GROWS => Err(E::allocation(layout)),
+            ChunkClass::Unallocated => NonDummyChunk::new(
+                ChunkSize::from_capacity(layout).ok_or_else(E::capacity_overflow)?,
+                None,
+                // When this bump allocator is unallocated, `A` is guaranteed to implement `Default`,
+                // `default_or_panic` will not panic.
+                A::default_or_panic(),
+            ),
+            ChunkClass::NonDummy(mut chunk) => {
+                while let Some(next_chunk) = chunk.next() {
+                    chunk = next_chunk;
+
+                    // Unlike `Bump` we don't reset the chunk position here. Other threads may still be
+                    // allocating in this chunk if it was current before a reset. Instead, chunks are
+                    // reset eagerly when the bump allocator is reset, see `reset_following`.
+                    self.set_current_chunk(*chunk);
+
+                    if let Some(ptr) = alloc_in(*chunk, layout) {
+                        return Ok(ptr);
+                    }
+                }
+
+                // there is no chunk that fits, we need a new chunk
+                chunk.append_for(layout)
+            }
+        }?;
+
+        // The new chunk is not visible to other threads yet, so we allocate before we publish it.
+        let ptr = alloc_in(*new_chunk, layout);
+        self.set_current_chunk(*new_chunk);
+
+        match ptr {
+            Some(ptr) => Ok(ptr),
+            _ => {
+                // SAFETY: We just appended a chunk for that specific layout, it must have enough space.
+                // We don't panic here so we don't produce any panic code when using `try_` apis.
+                unsafe { hint::unreachable_unchecked() }
+            }
+        }
+    }
+
+    fn generic_reserve<E: ErrorBehavior>(&self, additional: usize) -> Result<(), E> {
+        let _guard = self.lock();
+
+        match self.current_chunk().classify() {
+            ChunkClass::Claimed => Err(E::claimed()),
+            ChunkClass::Unallocated => {
+                let Ok(layout) = Layout::from_size_align(additional, 1) else {
+                    return Err(E::capacity_overflow());
+                };
+
+                if !S::GROWS {
+                    return Err(E::allocation(layout));
+                }
+
+                let new_chunk = NonDummyChunk::<A, S>::new(
+                    ChunkSize::<A, S>::from_capacity(layout).ok_or_else(E::capacity_overflow)?,
+                    None,
+                    // When this bump allocator is unallocated, `A` is guaranteed to implement `Default`,
+                    // `default_or_panic` will not panic.
+                    A::default_or_panic(),
+                )?;
+
+                self.set_current_chunk(*new_chunk);
+                Ok(())
+            }
+            ChunkClass::NonDummy(mut chunk) => {
+                let Some(additional) = additional.checked_sub(self.stats().remaining()) else {
+                    return Ok(());
+                };
+
+                if additional == 0 {
+                    return Ok(());
+                }
+
+                let Ok(layout) = Layout::from_size_align(additional, 1) else {
+                    return Err(E::capacity_overflow());
+                };
+
+                while let Some(next) = chunk.next() {
+                    chunk = next;
+                }
+
+                chunk.append_for(layout).map(drop)
+            }
+        }
+    }
+}
+
+unsafe impl<A, S> Allocator for SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Ok(NonNull::slice_from_raw_parts(
+            self.alloc::<AllocError>(layout)?,
+            layout.size(),
+        ))
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { poison::freed::<S>(ptr, layout.size()) };
+
+        if !S::DEALLOCATES {
+            return;
+        }
+
+        let Some(chunk) = self.current_chunk().as_non_dummy() else {
+            return;
+        };
+
+        // free allocated space if this is the last allocation
+        let addr = ptr.addr().get();
+
+        if S::UP {
+            try_set_pos(chunk, addr + layout.size(), align_pos::<S>(addr));
+        } else {
+            try_set_pos(chunk, addr, align_pos::<S>(addr + layout.size()));
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn grow(
+        &self,
+        old_ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(
+            new_layout.size() >= old_layout.size(),
+            "`new_layout.size()` must be greater than or equal to `old_layout.size()`"
+        );
+
+        unsafe {
+            if let Some(chunk) = self.current_chunk().as_non_dummy() {
+                let old_addr = old_ptr.addr().get();
+
+                if S::UP {
+                    let end = chunk.content_end().addr().get();
+
+                    if non_null::is_aligned_to(old_ptr, new_layout.align())
+                        && old_addr <= end
+                        && new_layout.size() <= end - old_addr
+                    {
+                        // Up-aligning a pointer inside a chunks content by `MIN_ALIGN` never overflows.
+                        let new_pos = up_align_usize_unchecked(old_addr + new_layout.size(), S::MIN_ALIGN);
+
+                        // This only succeeds if this is still the last allocation.
+                        if try_set_pos(chunk, old_addr + old_layout.size(), new_pos) {
+                            let additional_size = new_layout.size() - old_layout.size();
+                            asan::unpoison(old_ptr.add(old_layout.size()), additional_size);
+                            poison::uninit::<S>(old_ptr.add(old_layout.size()), additional_size);
+                            return Ok(NonNull::slice_from_raw_parts(old_ptr, new_layout.size()));
+                        }
+                    }
+                } else {
+                    let additional_size = new_layout.size() - old_layout.size();
+                    let new_addr = bump_down(old_ptr.addr(), additional_size, new_layout.align().max(S::MIN_ALIGN));
+                    let very_start = chunk.content_start().addr().get();
+
+                    // This only succeeds if this is still the last allocation.
+                    // The space between `new_addr` and `old_addr` then belongs to us.
+                    if new_addr >= very_start && try_set_pos(chunk, old_addr, new_addr) {
+                        let new_ptr = old_ptr.with_addr(NonZeroUsize::new_unchecked(new_addr));
+                        asan::unpoison(new_ptr, old_addr - new_addr);
+                        old_ptr.copy_to(new_ptr, old_layout.size());
+                        poison::freed_except::<S>(old_ptr, old_layout.size(), new_ptr, new_layout.size());
+                        poison::uninit::<S>(new_ptr.add(old_layout.size()), additional_size);
+                        return Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()));
+                    }
+                }
+            }
+
+            // We can't grow in place. We have to make a new allocation.
+            let old_chunk = self.current_chunk();
+            let new_ptr = self.alloc::<AllocError>(new_layout)?;
+            old_chunk.record_abandoned(old_layout.size());
+            old_ptr.copy_to_nonoverlapping(new_ptr, old_layout.size());
+            poison::freed::<S>(old_ptr, old_layout.size());
+            Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn grow_zeroed(
+        &self,
+        old_ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe {
+            let new_ptr = self.grow(old_ptr, old_layout, new_layout)?;
+
+            let delta = new_layout.size() - old_layout.size();
+            new_ptr.cast::<u8>().add(old_layout.size()).write_bytes(0, delta);
+
+            Ok(new_ptr)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn shrink(
+        &self,
+        old_ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(
+            new_layout.size() <= old_layout.size(),
+            "`new_layout.size()` must be smaller than or equal to `old_layout.size()`"
+        );
+
+        unsafe {
+            if !non_null::is_aligned_to(old_ptr, new_layout.align()) {
+                let new_ptr = self.alloc::<AllocError>(new_layout)?;
+                old_ptr.copy_to_nonoverlapping(new_ptr, new_layout.size());
+                poison::freed::<S>(old_ptr, old_layout.size());
+                return Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()));
+            }
+
+            // When bumping downwards, shrinking in place would move the data up into memory that
+            // other threads could allocate as soon as we move the bump position. So we only do it upwards.
+            if S::SHRINKS && S::UP {
+                // `as_non_dummy` can only fail if `old_ptr` was allocated by a different bump allocator
+                let chunk = self.current_chunk().as_non_dummy();
+                let Some(chunk) = chunk else {
+                    return Ok(NonNull::slice_from_raw_parts(old_ptr, old_layout.size()));
+                };
+
+                let old_addr = old_ptr.addr().get();
+
+                // Up-aligning a pointer inside a chunk by `MIN_ALIGN` never overflows.
+                let new_pos = up_align_usize_unchecked(old_addr + new_layout.size(), S::MIN_ALIGN);
+
+                // Once the bump position moved, other threads may allocate the freed memory,
+                // so we have to poison it before. The contents past `new_layout.size()` need
+                // not be preserved even if we end up returning the memory block as-is.
+                let freed_size = old_layout.size() - new_layout.size();
+                poison::freed::<S>(old_ptr.add(new_layout.size()), freed_size);
+
+                // This only succeeds if this is still the last allocation.
+                if try_set_pos(chunk, old_addr + old_layout.size(), new_pos) {
+                    return Ok(NonNull::slice_from_raw_parts(old_ptr, new_layout.size()));
+                }
+
+                // The memory block is returned as-is, so all of it has to be addressable.
+                asan::unpoison(old_ptr.add(new_layout.size()), freed_size);
+            }
+
+            // We can't shrink this allocation, so we return it as-is.
+            Ok(NonNull::slice_from_raw_parts(old_ptr, old_layout.size()))
+        }
+    }
+}
+
+unsafe impl<A, S> BumpAllocatorCore for SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn any_stats(&self) -> AnyStats<'_> {
+        self.stats()
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Checkpoint {
+        let chunk = self.current_chunk();
+        let address = unsafe { chunk.header.as_ref() }.load_pos().addr();
+
+        Checkpoint {
+            chunk: chunk.header.cast(),
+            address,
+        }
+    }
+
+    #[inline]
+    unsafe fn reset_to(&self, checkpoint: Checkpoint) {
+        let _guard = self.lock();
+
+        if S::TRACK_PEAK {
+            self.stats().record_peak();
+        }
+
+        // See `RawBump::reset_to` for why we only check for the unallocated chunk when `!GUARANTEED_ALLOCATED`.
+        if !S::GUARANTEED_ALLOCATED && checkpoint.chunk == ChunkHeader::unallocated::<S>() {
+            if let Some(mut chunk) = self.current_chunk().as_non_dummy() {
+                while let Some(prev) = chunk.prev() {
+                    chunk = prev;
+                }
+
+                reset_chunk(chunk);
+                reset_following(chunk);
+                self.set_current_chunk(*chunk);
+            }
+
+            return;
+        }
+
+        debug_assert_ne!(
+            checkpoint.chunk,
+            ChunkHeader::claimed::<S>(),
+            "the checkpoint must not have been created by a claimed bump allocator"
+        );
+
+        unsafe {
+            let chunk = RawChunk {
+                header: checkpoint.chunk.cast(),
+                marker: PhantomData,
+            }
+            .as_non_dummy_unchecked();
+
+            let pos = chunk.content_ptr_from_addr(checkpoint.address.get());
+            poison_allocated_since(chunk, pos);
+            chunk.header.as_ref().atomic_pos().store(pos.as_ptr(), Ordering::Release);
+            reset_following(chunk);
+
+            self.set_current_chunk(*chunk);
+        }
+    }
+
+    #[inline(always)]
+    fn is_claimed(&self) -> bool {
+        matches!(self.current_chunk().classify(), ChunkClass::Claimed)
+    }
+
+    /// Unlike other bump allocators, a `SyncBump` can't leave the free space unclaimed while
+    /// the caller writes to it, so this allocates exactly `layout.size()` bytes.
+    #[inline(always)]
+    fn prepare_allocation(&self, layout: Layout) -> Result<Range<NonNull<u8>>, AllocError> {
+        let ptr = self.alloc::<AllocError>(layout)?;
+        Ok(ptr..unsafe { ptr.add(layout.size()) })
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared(&self, layout: Layout, range: Range<NonNull<u8>>) -> NonNull<u8> {
+        debug_assert_eq!(range.start.addr().get() % layout.align(), 0);
+        debug_assert_eq!(range.end.addr().get() % layout.align(), 0);
+        debug_assert_eq!(layout.size() % layout.align(), 0);
+
+        unsafe {
+            // a successful `prepare_allocation` guarantees a non-dummy-chunk
+            let chunk = self.current_chunk().as_non_dummy_unchecked();
+
+            // Give back the unused part of the range if this is still the last allocation.
+            // It is poisoned before, because other threads may allocate it as soon as the bump position moved.
+            if S::UP {
+                let end = range.start.add(layout.size());
+                asan::poison_range(end..range.end);
+                try_set_pos(
+                    chunk,
+                    align_pos::<S>(range.end.addr().get()),
+                    align_pos::<S>(end.addr().get()),
+                );
+                range.start
+            } else {
+                let src = range.start;
+                let dst_end = range.end;
+                let dst = dst_end.sub(layout.size());
+                src.copy_to(dst, layout.size());
+                asan::poison_range(src..dst);
+                try_set_pos(chunk, src.addr().get(), align_pos::<S>(dst.addr().get()));
+                dst
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn prepare_allocation_rev(&self, layout: Layout) -> Result<Range<NonNull<u8>>, AllocError> {
+        // for now the implementation for both methods is the same
+        self.prepare_allocation(layout)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared_rev(&self, layout: Layout, range: Range<NonNull<u8>>) -> NonNull<u8> {
+        debug_assert_eq!(range.start.addr().get() % layout.align(), 0);
+        debug_assert_eq!(range.end.addr().get() % layout.align(), 0);
+        debug_assert_eq!(layout.size() % layout.align(), 0);
+
+        unsafe {
+            // a successful `prepare_allocation` guarantees a non-dummy-chunk
+            let chunk = self.current_chunk().as_non_dummy_unchecked();
+
+            // Give back the unused part of the range if this is still the last allocation.
+            // It is poisoned before, because other threads may allocate it as soon as the bump position moved.
+            if S::UP {
+                let dst = range.start;
+                let dst_end = dst.add(layout.size());
+
+                let src_end = range.end;
+                let src = src_end.sub(layout.size());
+
+                src.copy_to(dst, layout.size());
+                asan::poison_range(dst_end..src_end);
+
+                try_set_pos(
+                    chunk,
+                    align_pos::<S>(src_end.addr().get()),
+                    align_pos::<S>(dst_end.addr().get()),
+                );
+
+                dst
+            } else {
+                let dst_end = range.end;
+                let dst = dst_end.sub(layout.size());
+                asan::poison_range(range.start..dst);
+                try_set_pos(chunk, range.start.addr().get(), align_pos::<S>(dst.addr().get()));
+                dst
+            }
+        }
+    }
+}
+
+unsafe impl<A, S> BumpAllocatorTyped for SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    type TypedStats<'b>
+        = AnyStats<'b>
+    where
+        Self: 'b;
+
+    #[inline(always)]
+    fn typed_stats(&self) -> AnyStats<'_> {
+        self.stats()
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
+        panic_on_error(self.alloc(layout))
+    }
+
+    #[inline(always)]
+    fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.alloc(layout)
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn allocate_sized<T>(&self) -> NonNull<T> {
+        panic_on_error(for_trait_object::allocate_sized(self))
+    }
+
+    #[inline(always)]
+    fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
+        for_trait_object::allocate_sized(self)
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
+        panic_on_error(for_trait_object::allocate_slice(self, len))
+    }
+
+    #[inline(always)]
+    fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
+        for_trait_object::allocate_slice(self, len)
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
+        panic_on_error(for_trait_object::allocate_slice_for(self, slice))
+    }
+
+    #[inline(always)]
+    fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
+        for_trait_object::allocate_slice_for(self, slice)
+    }
+
+    #[inline(always)]
+    unsafe fn shrink_slice<T>(&self, ptr: NonNull<T>, old_len: usize, new_len: usize) -> Option<NonNull<T>> {
+        unsafe { for_trait_object::shrink_slice(self, ptr, old_len, new_len) }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn prepare_slice_allocation<T>(&self, len: usize) -> NonNull<[T]> {
+        panic_on_error(for_trait_object::prepare_slice_allocation(self, len))
+    }
+
+    #[inline(always)]
+    fn try_prepare_slice_allocation<T>(&self, len: usize) -> Result<NonNull<[T]>, AllocError> {
+        for_trait_object::prepare_slice_allocation(self, len)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared_slice<T>(&self, ptr: NonNull<T>, len: usize, cap: usize) -> NonNull<[T]> {
+        unsafe { for_trait_object::allocate_prepared_slice(self, ptr, len, cap) }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn prepare_slice_allocation_rev<T>(&self, len: usize) -> (NonNull<T>, usize) {
+        panic_on_error(for_trait_object::prepare_slice_allocation_rev(self, len))
+    }
+
+    #[inline(always)]
+    fn try_prepare_slice_allocation_rev<T>(&self, len: usize) -> Result<(NonNull<T>, usize), AllocError> {
+        for_trait_object::prepare_slice_allocation_rev(self, len)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared_slice_rev<T>(&self, ptr: NonNull<T>, len: usize, cap: usize) -> NonNull<[T]> {
+        unsafe { for_trait_object::allocate_prepared_slice_rev(self, ptr, len, cap) }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn reserve(&self, additional: usize) {
+        panic_on_error(self.generic_reserve(additional));
+    }
+
+    #[inline(always)]
+    fn try_reserve(&self, additional: usize) -> Result<(), AllocError> {
+        self.generic_reserve(additional)
+    }
+}
+
+unsafe impl<'a, A, S> BumpAllocatorCoreScope<'a> for &'a SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+}
+
+unsafe impl<'a, A, S> BumpAllocatorCoreScope<'a> for &'a mut SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+}
+
+struct LockGuard<'a>(&'a AtomicBool);
+
+impl Drop for LockGuard<'_> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+/// Attempts to allocate in `chunk` by atomically bumping its position.
+#[inline(always)]
+fn alloc_in<A, S>(chunk: RawChunk<A, S>, layout: Layout) -> Option<NonNull<u8>>
+where
+    S: BumpAllocatorSettings,
+{
+    let pos = unsafe { chunk.header.as_ref() }.atomic_pos();
+    let mut current = pos.load(Ordering::Relaxed);
+
+    loop {
+        let props = chunk.bump_props_at(current.addr(), CustomLayout(layout));
+
+        let (new_pos, ptr) = if S::UP {
+            let BumpUp { new_pos, ptr } = bumping::bump_up(props)?;
+            (new_pos, ptr)
+        } else {
+            let ptr = bumping::bump_down(props)?;
+            (ptr, ptr)
+        };
+
+        // SAFETY: allocations never succeed for a dummy chunk
+        let chunk = unsafe { chunk.as_non_dummy_unchecked() };
+        let new_pos = unsafe { chunk.content_ptr_from_addr(new_pos) };
+
+        // `Acquire` so we see the writes of a thread that deallocated or reset this memory.
+        match pos.compare_exchange_weak(current, new_pos.as_ptr(), Ordering::Acquire, Ordering::Relaxed) {
+            Ok(_) => {
+                if S::TRACK_WASTE {
+                    let consumed = if S::UP {
+                        new_pos.addr().get() - current.addr()
+                    } else {
+                        current.addr() - ptr
+                    };
+                    chunk.waste().add_padding(consumed - layout.size());
+                }
+
+                let ptr = unsafe { chunk.content_ptr_from_addr(ptr) };
+                unsafe { asan::unpoison(ptr, layout.size()) };
+                return Some(ptr);
+            }
+            Err(actual) => current = actual,
+        }
+    }
+}
+
+/// Sets the bump position of `chunk` to `new` if it is `current`.
+///
+/// Like [`NonDummyChunk::set_pos_addr`] this updates the peak when moving the bump position back.
+///
+/// Returns `false` if the bump position is not `current`, which means that the memory block
+/// we want to change is not the last allocation (anymore).
+#[inline(always)]
+fn try_set_pos<A, S>(chunk: NonDummyChunk<A, S>, current: usize, new: usize) -> bool
+where
+    S: BumpAllocatorSettings,
+{
+    if S::TRACK_PEAK {
+        let moves_back = if S::UP { new < current } else { new > current };
+
+        if moves_back {
+            AnyStats::from(Stats::from_raw_chunk(*chunk)).record_peak();
+        }
+    }
+
+    let pos = unsafe { chunk.header.as_ref() }.atomic_pos();
+    let base = chunk.header.cast::<u8>().as_ptr();
+
+    // `new` is only a valid position if `current` is, so we only use it if the exchange succeeds
+    pos.compare_exchange(
+        base.with_addr(current),
+        base.with_addr(new),
+        Ordering::AcqRel,
+        Ordering::Relaxed,
+    )
+    .is_ok()
+}
+
+/// Resets the bump position of `chunk` to the start and clears its waste record.
+#[inline(always)]
+fn reset_chunk<A, S>(chunk: NonDummyChunk<A, S>)
+where
+    S: BumpAllocatorSettings,
+{
+    let start = if S::UP { chunk.content_start() } else { chunk.content_end() };
+    unsafe { poison_allocated_since(chunk, start) };
+    unsafe { chunk.header.as_ref() }
+        .atomic_pos()
+        .store(start.as_ptr(), Ordering::Release);
+
+    if S::TRACK_WASTE {
+        chunk.waste().clear();
+    }
+}
+
+/// Wipes the memory of `chunk` that was allocated after `pos`, fills it with [`POISON_FREED`](crate::settings::POISON_FREED)
+/// and poisons it for the address sanitizer.
+///
+/// # Safety
+/// `pos` must point into the allocated range of `chunk`.
+#[inline(always)]
+unsafe fn poison_allocated_since<A, S>(chunk: NonDummyChunk<A, S>, pos: NonNull<u8>)
+where
+    S: BumpAllocatorSettings,
+{
+    if S::POISON || S::ZEROIZE || asan::ENABLED {
+        let current = unsafe { chunk.header.as_ref() }.load_pos();
+        let range = if S::UP { pos..current } else { current..pos };
+        unsafe { poison::freed_range::<S>(range) };
+    }
+}
+
+/// Resets the bump position of all chunks that come after `chunk`.
+fn reset_following<A, S>(mut chunk: NonDummyChunk<A, S>)
+where
+    S: BumpAllocatorSettings,
+{
+    while let Some(next) = chunk.next() {
+        chunk = next;
+        reset_chunk(chunk);
+    }
+}
+
+#[inline(always)]
+fn align_pos<S: BumpAllocatorSettings>(pos: usize) -> usize {
+    crate::align_pos(S::UP, S::MIN_ALIGN, pos)
+}
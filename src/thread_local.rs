@@ -0,0 +1,296 @@
+//! Thread-local bump allocators.
+//!
+//! This module provides a bump allocator for the current thread that can be used
+//! from anywhere without passing a `&Bump` around:
+//!
+//! ```
+//! use bump_scope::thread_local;
+//!
+//! fn to_uppercase_len(string: &str) -> usize {
+//!     // allocate scratch memory without needing a `&Bump` parameter
+//!     thread_local::with(|bump| {
+//!         let uppercase = bump.alloc_fmt(format_args!("{}", string.to_uppercase()));
+//!         uppercase.len()
+//!     })
+//! }
+//!
+//! thread_local::with(|bump| {
+//!     let hello = bump.alloc_str("hello");
+//!
+//!     // nested uses share the same bump allocator
+//!     assert_eq!(to_uppercase_len(&hello), 5);
+//!     assert!(bump.stats().allocated() > 5);
+//! });
+//!
+//! // when the outermost `with` returns, the bump allocator is reset
+//! thread_local::with(|bump| assert_eq!(bump.stats().allocated(), 0));
+//! ```
+//!
+//! You can [`replace`] the bump allocator of the current thread, for example to give it a bigger first chunk:
+//!
+//! ```
+//! use bump_scope::{Bump, thread_local};
+//!
+//! thread_local::replace(Bump::with_size(1024 * 1024));
+//!
+//! thread_local::with(|bump| {
+//!     assert!(bump.stats().capacity() >= 1000 * 1000);
+//! });
+//! ```
+//!
+//! To use different settings or a different base allocator you can define your own
+//! thread-local [`ThreadLocalBump`]:
+//!
+//! ```
+//! use bump_scope::{alloc::Global, settings::BumpSettings, thread_local::ThreadLocalBump};
+//!
+//! type Settings = BumpSettings<8, false, false>;
+//!
+//! std::thread_local! {
+//!     static BUMP: ThreadLocalBump<Global, Settings> = const { ThreadLocalBump::new() };
+//! }
+//!
+//! BUMP.with(|bump| bump.with(|bump| {
+//!     let value = bump.alloc(1u8);
+//!     assert_eq!((&raw const *value).addr() % 8, 0);
+//! }));
+//! ```
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    fmt::{self, Debug},
+};
+
+use crate::{
+    Bump,
+    alloc::{Allocator, Global},
+    settings::{BumpAllocatorSettings, BumpSettings, False},
+    stats::AnyStats,
+};
+
+/// The settings of the bump allocator used by [`with`] and [`replace`].
+///
+/// These are the default settings except that `GUARANTEED_ALLOCATED` is `false`
+/// so that the bump allocator can be created without allocating.
+pub type ThreadLocalSettings = BumpSettings<1, true, false>;
+
+std::thread_local! {
+    static BUMP: ThreadLocalBump = const { ThreadLocalBump::new() };
+}
+
+/// Calls `f` with the bump allocator of the current thread.
+///
+/// Calls to `with` can be nested. When the outermost call returns the bump allocator is [reset](Bump::reset).
+///
+/// See the [module documentation](self) for examples.
+///
+/// # Panics
+/// Panics if called during or after the destruction of the thread-local storage.
+#[inline]
+pub fn with<R>(f: impl FnOnce(&Bump<Global, ThreadLocalSettings>) -> R) -> R {
+    BUMP.with(|bump| bump.with(f))
+}
+
+/// Replaces the bump allocator of the current thread, returning the previous one.
+///
+/// See the [module documentation](self) for examples.
+///
+/// # Panics
+/// Panics if called from within [`with`] or during or after the destruction of the thread-local storage.
+#[inline]
+pub fn replace(bump: Bump<Global, ThreadLocalSettings>) -> Bump<Global, ThreadLocalSettings> {
+    BUMP.with(|thread_local| thread_local.replace(bump))
+}
+
+/// A bump allocator meant to be stored in a [`thread_local!`](std::thread_local).
+///
+/// It hands out a shared reference to its `Bump` from [`with`](Self::with) and resets the `Bump`
+/// once the outermost call to `with` returns.
+///
+/// See the [module documentation](self) for examples.
+pub struct ThreadLocalBump<A = Global, S = ThreadLocalSettings>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    bump: UnsafeCell<Bump<A, S>>,
+    depth: Cell<usize>,
+    resetting: Cell<bool>,
+}
+
+impl<A, S> ThreadLocalBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings<GuaranteedAllocated = False>,
+{
+    /// Constructs a new `ThreadLocalBump` without allocating a chunk.
+    ///
+    /// This requires the `GUARANTEED_ALLOCATED` setting to be `false`, see [`settings`](crate::settings).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::from_bump(Bump::unallocated())
+    }
+}
+
+impl<A, S> Default for ThreadLocalBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings<GuaranteedAllocated = False>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, S> From<Bump<A, S>> for ThreadLocalBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn from(bump: Bump<A, S>) -> Self {
+        Self::from_bump(bump)
+    }
+}
+
+impl<A, S> Debug for ThreadLocalBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // An observer may format this while the `Bump` is being reset.
+        if self.resetting.get() {
+            return f.debug_struct("ThreadLocalBump").finish_non_exhaustive();
+        }
+
+        // SAFETY: Only `replace` and the outermost `EnterGuard` mutably access the `Bump`,
+        // neither of which can run during this shared access.
+        let bump = unsafe { &*self.bump.get() };
+        AnyStats::from(bump.stats()).debug_format("ThreadLocalBump", f)
+    }
+}
+
+impl<A, S> ThreadLocalBump<A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    const fn from_bump(bump: Bump<A, S>) -> Self {
+        Self {
+            bump: UnsafeCell::new(bump),
+            depth: Cell::new(0),
+            resetting: Cell::new(false),
+        }
+    }
+
+    /// Calls `f` with the `Bump`.
+    ///
+    /// Calls to `with` can be nested. When the outermost call returns the `Bump` is [reset](Bump::reset).
+    ///
+    /// # Panics
+    /// Panics if called while the `Bump` is being reset, which can only happen from within
+    /// a [`BumpObserver`](crate::settings::BumpObserver).
+    #[inline]
+    pub fn with<R>(&self, f: impl FnOnce(&Bump<A, S>) -> R) -> R {
+        let _guard = EnterGuard::new(self);
+
+        // SAFETY: The `Bump` is only accessed mutably when no call to `with` is active.
+        f(unsafe { &*self.bump.get() })
+    }
+
+    /// Replaces the `Bump`, returning the previous one.
+    ///
+    /// # Panics
+    /// Panics if called from within [`with`](Self::with) or while the `Bump` is being reset.
+    pub fn replace(&self, bump: Bump<A, S>) -> Bump<A, S> {
+        assert!(
+            self.depth.get() == 0,
+            "the thread-local bump allocator can't be replaced while it is in use"
+        );
+
+        // SAFETY: No call to `with` is active, so there are no references to the `Bump`.
+        unsafe { core::mem::replace(&mut *self.bump.get(), bump) }
+    }
+
+    /// Returns the `Bump`.
+    #[must_use]
+    pub fn into_inner(self) -> Bump<A, S> {
+        self.bump.into_inner()
+    }
+}
+
+struct EnterGuard<'a, A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    thread_local: &'a ThreadLocalBump<A, S>,
+}
+
+impl<'a, A, S> EnterGuard<'a, A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn new(thread_local: &'a ThreadLocalBump<A, S>) -> Self {
+        assert!(
+            !thread_local.resetting.get(),
+            "the thread-local bump allocator can't be used while it is being reset"
+        );
+
+        thread_local.depth.set(thread_local.depth.get() + 1);
+        Self { thread_local }
+    }
+}
+
+impl<A, S> Drop for EnterGuard<'_, A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn drop(&mut self) {
+        let thread_local = self.thread_local;
+        let depth = thread_local.depth.get();
+
+        if depth != 1 {
+            thread_local.depth.set(depth - 1);
+            return;
+        }
+
+        // The observer is notified about the reset while we hold a `&mut Bump`.
+        // Until the reset is done, `depth` stays at `1` so `replace` panics,
+        // and `resetting` makes `with` panic.
+        thread_local.resetting.set(true);
+        let _reset_guard = ResetGuard { thread_local };
+
+        // SAFETY: The outermost call to `with` has returned. The closure's result can't
+        // borrow from the `Bump`, so there are no references to it left.
+        unsafe { (*thread_local.bump.get()).reset() }
+    }
+}
+
+/// Marks the end of a reset, even if the observer panics.
+struct ResetGuard<'a, A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    thread_local: &'a ThreadLocalBump<A, S>,
+}
+
+impl<A, S> Drop for ResetGuard<'_, A, S>
+where
+    A: Allocator,
+    S: BumpAllocatorSettings,
+{
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.thread_local.resetting.set(false);
+        self.thread_local.depth.set(0);
+    }
+}
@@ -0,0 +1,264 @@
+//! Per-allocation tracking for debugging memory usage.
+//!
+//! When the `track-allocations` feature is enabled, every allocation that goes through
+//! the allocation methods of [`Bump`] and [`BumpScope`] is recorded along with its size,
+//! its type name and the [`Location`] of the caller.
+//!
+//! The live allocations can then be broken down by call site and by type using
+//! [`Stats::allocation_sites`] and [`Stats::allocation_types`] or the equivalent
+//! methods of [`Chunk`], [`AnyStats`] and [`AnyChunk`].
+//!
+//! ```
+//! use bump_scope::Bump;
+//!
+//! let bump: Bump = Bump::new();
+//!
+//! fn make_numbers(bump: &Bump) {
+//!     bump.alloc_slice_fill(100, 0u32);
+//! }
+//!
+//! make_numbers(&bump);
+//! bump.alloc_str("hello");
+//!
+//! let sites = bump.stats().allocation_sites();
+//! assert_eq!(sites.len(), 2);
+//!
+//! // sorted by bytes in descending order
+//! assert_eq!(sites[0].bytes, 400);
+//! assert_eq!(sites[1].bytes, 5);
+//!
+//! // the allocation in `make_numbers` comes first in the source
+//! assert!(sites[0].location.line() < sites[1].location.line());
+//!
+//! let types = bump.stats().allocation_types();
+//! assert_eq!(types[0].type_name, Some("[u32]"));
+//! ```
+//!
+//! # Limitations
+//!
+//! Tracking is meant for debugging. Every tracked allocation takes a global lock,
+//! so expect allocations to be much slower with this feature enabled.
+//!
+//! Only allocations that go through the `Bump(Scope)` allocation methods are tracked with
+//! the caller's location. Allocations made through the [`Allocator`] api, like the ones made
+//! by growing collections, are tracked with a location inside this crate and without a type name.
+//! Memory that is reclaimed by growing or shrinking the most recent allocation in place is not
+//! reflected in the recorded size. Allocations of [`SyncBump`] are not tracked.
+//!
+//! [`Bump`]: crate::Bump
+//! [`BumpScope`]: crate::BumpScope
+//! [`SyncBump`]: crate::SyncBump
+//! [`Allocator`]: crate::alloc::Allocator
+//! [`Stats::allocation_sites`]: crate::stats::Stats::allocation_sites
+//! [`Stats::allocation_types`]: crate::stats::Stats::allocation_types
+//! [`Chunk`]: crate::stats::Chunk
+//! [`AnyStats`]: crate::stats::AnyStats
+//! [`AnyChunk`]: crate::stats::AnyChunk
+
+use core::{
+    cmp::Reverse,
+    ops::Range,
+    panic::Location,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use alloc_crate::{collections::BTreeMap, vec::Vec};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// The live allocations, keyed by their address.
+static ALLOCATIONS: Mutex<BTreeMap<usize, Record>> = Mutex::new(BTreeMap::new());
+
+/// The number of records that were removed while still owned by a [`BumpBox`](crate::BumpBox).
+static LEAKED_BOXES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy)]
+struct Record {
+    size: usize,
+    type_name: Option<&'static str>,
+    location: &'static Location<'static>,
+    /// Whether a `BumpBox` whose value needs to be dropped owns this allocation.
+    boxed: bool,
+}
+
+/// The live allocations made from a single call site.
+///
+/// Returned from [`Stats::allocation_sites`](crate::stats::Stats::allocation_sites).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationSite {
+    /// The location of the caller that made the allocations.
+    pub location: &'static Location<'static>,
+
+    /// The number of live allocations.
+    pub count: usize,
+
+    /// The combined size of the live allocations in bytes.
+    pub bytes: usize,
+}
+
+/// The live allocations of a single type.
+///
+/// Returned from [`Stats::allocation_types`](crate::stats::Stats::allocation_types).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationType {
+    /// The name of the allocated type as returned by [`type_name`](core::any::type_name).
+    ///
+    /// This is `None` for allocations that were made with just a [`Layout`](core::alloc::Layout).
+    pub type_name: Option<&'static str>,
+
+    /// The number of live allocations.
+    pub count: usize,
+
+    /// The combined size of the live allocations in bytes.
+    pub bytes: usize,
+}
+
+/// Returns the number of [`BumpBox`]es whose value needs to be dropped that were never dropped.
+///
+/// This includes `BumpBox`es that were [leaked] or passed to [`mem::forget`](core::mem::forget).
+/// Such a `BumpBox` is counted once the memory of its allocation is freed, which is when the
+/// bump allocator is reset, a scope ends, or its chunk is deallocated.
+/// `BumpBox`es that were turned into a raw pointer with [`into_raw`] are not counted.
+///
+/// The count is global and includes `BumpBox`es from all bump allocators.
+/// Allocations of [`SyncBump`] are not tracked, so its `BumpBox`es are never counted.
+///
+/// [`BumpBox`]: crate::BumpBox
+/// [leaked]: crate::BumpBox::leak
+/// [`into_raw`]: crate::BumpBox::into_raw
+/// [`SyncBump`]: crate::SyncBump
+#[must_use]
+pub fn leaked_boxes() -> usize {
+    LEAKED_BOXES.load(Ordering::Relaxed)
+}
+
+fn allocations() -> MutexGuard<'static, BTreeMap<usize, Record>> {
+    ALLOCATIONS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn remove_range(allocations: &mut BTreeMap<usize, Record>, range: Range<usize>) {
+    let stale: Vec<usize> = allocations.range(range).map(|(&addr, _)| addr).collect();
+    let mut leaked = 0;
+
+    for addr in stale {
+        if let Some(record) = allocations.remove(&addr) {
+            leaked += usize::from(record.boxed);
+        }
+    }
+
+    if leaked != 0 {
+        LEAKED_BOXES.fetch_add(leaked, Ordering::Relaxed);
+    }
+}
+
+/// Records an allocation at `addr` in the chunk that spans `chunk`.
+///
+/// Allocations in the same chunk that the new allocation follows in bump direction
+/// have been freed in the meantime, so their records are removed.
+pub(crate) fn record(
+    chunk: Range<usize>,
+    up: bool,
+    addr: usize,
+    size: usize,
+    type_name: Option<&'static str>,
+    location: &'static Location<'static>,
+) {
+    if size == 0 {
+        return;
+    }
+
+    let mut allocations = allocations();
+
+    if up {
+        remove_range(&mut allocations, addr..chunk.end);
+    } else {
+        remove_range(&mut allocations, chunk.start..addr + size);
+    }
+
+    allocations.insert(
+        addr,
+        Record {
+            size,
+            type_name,
+            location,
+            boxed: false,
+        },
+    );
+}
+
+/// Removes the records of a chunk that is being deallocated.
+pub(crate) fn remove_chunk(chunk: Range<usize>) {
+    remove_range(&mut allocations(), chunk);
+}
+
+/// Removes the records of the allocations in `range` whose memory is being freed.
+pub(crate) fn free_range(range: Range<usize>) {
+    remove_range(&mut allocations(), range);
+}
+
+/// Marks the allocation at `addr` as owned by a `BumpBox` whose value needs to be dropped.
+pub(crate) fn box_created(addr: usize) {
+    set_boxed(addr, true);
+}
+
+/// Marks the allocation at `addr` as no longer owned by a `BumpBox`.
+pub(crate) fn box_released(addr: usize) {
+    set_boxed(addr, false);
+}
+
+fn set_boxed(addr: usize, boxed: bool) {
+    if let Some(record) = allocations().get_mut(&addr) {
+        record.boxed = boxed;
+    }
+}
+
+/// Calls `f` for each allocation that lies within `live`, which is the allocated range of a chunk.
+fn for_each_live(live: Range<usize>, mut f: impl FnMut(&Record, usize)) {
+    let allocations = allocations();
+
+    for (&addr, record) in allocations.range(live.clone()) {
+        let end = addr.saturating_add(record.size).min(live.end);
+        f(record, end - addr);
+    }
+}
+
+pub(crate) fn allocation_sites(chunks: impl IntoIterator<Item = Range<usize>>) -> Vec<AllocationSite> {
+    let mut sites = BTreeMap::<&'static Location<'static>, AllocationSite>::new();
+
+    for live in chunks {
+        for_each_live(live, |record, bytes| {
+            let site = sites.entry(record.location).or_insert(AllocationSite {
+                location: record.location,
+                count: 0,
+                bytes: 0,
+            });
+
+            site.count += 1;
+            site.bytes += bytes;
+        });
+    }
+
+    let mut sites: Vec<AllocationSite> = sites.into_values().collect();
+    sites.sort_by_key(|site| Reverse(site.bytes));
+    sites
+}
+
+pub(crate) fn allocation_types(chunks: impl IntoIterator<Item = Range<usize>>) -> Vec<AllocationType> {
+    let mut types = BTreeMap::<Option<&'static str>, AllocationType>::new();
+
+    for live in chunks {
+        for_each_live(live, |record, bytes| {
+            let ty = types.entry(record.type_name).or_insert(AllocationType {
+                type_name: record.type_name,
+                count: 0,
+                bytes: 0,
+            });
+
+            ty.count += 1;
+            ty.bytes += bytes;
+        });
+    }
+
+    let mut types: Vec<AllocationType> = types.into_values().collect();
+    types.sort_by_key(|ty| Reverse(ty.bytes));
+    types
+}
@@ -1,7 +1,7 @@
 use core::ptr;
 
 use crate::{
-    BaseAllocator, Bump, BumpScope, BumpScopeGuard,
+    BaseAllocator, Bump, BumpScope, BumpScopeGuard, BumpScopeGuardWithLimit, BumpScopeWithLimit,
     polyfill::transmute_mut,
     settings::{BumpAllocatorSettings, MinimumAlignment, SupportedMinimumAlignment},
     traits::MutBumpAllocatorTyped,
@@ -78,6 +78,59 @@ pub trait BumpAllocator: MutBumpAllocatorTyped + Sized {
         f(guard.scope())
     }
 
+    /// Creates a new [`BumpScopeGuardWithLimit`].
+    ///
+    /// The scopes it hands out fail to allocate once they would use more than `limit` bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bump_scope::{Bump, traits::BumpAllocatorTypedScope};
+    /// let mut bump: Bump = Bump::new();
+    ///
+    /// {
+    ///     let mut guard = bump.scope_guard_with_limit(8);
+    ///     let bump = guard.scope();
+    ///     bump.alloc_str("Hello");
+    ///     assert!(bump.try_alloc_str(", world!").is_err());
+    ///     assert_eq!(bump.stats().allocated(), 5);
+    /// }
+    ///
+    /// assert_eq!(bump.stats().allocated(), 0);
+    /// ```
+    #[inline(always)]
+    fn scope_guard_with_limit(&mut self, limit: usize) -> BumpScopeGuardWithLimit<'_, Self::Allocator, Self::Settings> {
+        BumpScopeGuardWithLimit::new(self.scope_guard(), limit)
+    }
+
+    /// Calls `f` with a new child scope that fails to allocate once it would use more than `limit` bytes.
+    ///
+    /// See [`BumpScopeWithLimit`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bump_scope::{Bump, alloc::AllocError, traits::BumpAllocatorTypedScope};
+    /// let mut bump: Bump = Bump::new();
+    ///
+    /// let result = bump.scoped_with_limit(1024, |bump| -> Result<usize, AllocError> {
+    ///     // some parsing step that is not to be trusted
+    ///     let values = bump.try_alloc_slice_fill(4096, 0u8)?;
+    ///     Ok(values.len())
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(bump.stats().allocated(), 0);
+    /// ```
+    #[inline(always)]
+    fn scoped_with_limit<R>(
+        &mut self,
+        limit: usize,
+        f: impl FnOnce(&mut BumpScopeWithLimit<'_, Self::Allocator, Self::Settings>) -> R,
+    ) -> R {
+        let mut guard = self.scope_guard_with_limit(limit);
+        f(&mut guard.scope())
+    }
+
     /// Calls `f` with a new child scope of a new minimum alignment.
     ///
     /// # Examples
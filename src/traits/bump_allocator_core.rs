@@ -1,7 +1,7 @@
 use core::{alloc::Layout, ops::Range, ptr::NonNull};
 
 use crate::{
-    BaseAllocator, Bump, BumpScope, Checkpoint, WithoutDealloc, WithoutShrink,
+    BaseAllocator, Bump, BumpScope, BumpScopeWithLimit, Checkpoint, WithoutDealloc, WithoutShrink,
     alloc::{AllocError, Allocator},
     layout::CustomLayout,
     raw_bump::RawChunk,
@@ -31,6 +31,21 @@ where
 {
 }
 
+impl<A, S> Sealed for BumpScopeWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<A, S> Sealed for crate::SyncBump<A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+}
+
 /// A bump allocator.
 ///
 /// This trait provides additional methods and guarantees on top of an [`Allocator`].
@@ -74,6 +89,7 @@ pub unsafe trait BumpAllocatorCore: Allocator + Sealed {
     /// Resets the bump position to a previously created checkpoint.
     ///
     /// The memory that has been allocated since then will be reused by future allocations.
+    /// If the checkpoint's chunk has been deallocated by a [`trim`] in the meantime, this does nothing.
     ///
     /// # Safety
     ///
@@ -84,6 +100,7 @@ pub unsafe trait BumpAllocatorCore: Allocator + Sealed {
     /// - the bump allocator must be [unclaimed] at the time the checkpoint is created and when this function is called
     ///
     /// [`reset`]: crate::Bump::reset
+    /// [`trim`]: crate::Bump::trim
     /// [unclaimed]: crate::traits::BumpAllocatorScope::claim
     ///
     /// # Examples
@@ -415,6 +432,10 @@ where
             }
         }
 
+        if S::RED_ZONES {
+            return self.raw.prepare_guarded_allocation_range(Some(layout.size()), layout.align());
+        }
+
         match self.raw.chunk.get().prepare_allocation_range(CustomLayout(layout)) {
             Some(ptr) => Ok(ptr),
             None => unsafe { prepare_allocation_in_another_chunk(self, layout) },
@@ -431,7 +452,11 @@ where
             // a successful `prepare_allocation` guarantees a non-dummy-chunk
             let chunk = self.raw.chunk.get().as_non_dummy_unchecked();
 
-            if S::UP {
+            if S::RED_ZONES {
+                return chunk.allocate_prepared_guarded(range.clone(), range.start, layout);
+            }
+
+            let ptr = if S::UP {
                 let end = range.start.add(layout.size());
                 chunk.set_pos_addr_and_align(end.addr().get());
                 range.start
@@ -442,7 +467,10 @@ where
                 src.copy_to(dst, layout.size());
                 chunk.set_pos_addr_and_align(dst.addr().get());
                 dst
-            }
+            };
+
+            chunk.poison_remaining();
+            ptr
         }
     }
 
@@ -462,7 +490,11 @@ where
             // a successful `prepare_allocation` guarantees a non-dummy-chunk
             let chunk = self.raw.chunk.get().as_non_dummy_unchecked();
 
-            if S::UP {
+            if S::RED_ZONES {
+                return chunk.allocate_prepared_guarded(range.clone(), range.end.sub(layout.size()), layout);
+            }
+
+            let ptr = if S::UP {
                 let dst = range.start;
                 let dst_end = dst.add(layout.size());
 
@@ -479,7 +511,10 @@ where
                 let dst = dst_end.sub(layout.size());
                 chunk.set_pos_addr_and_align(dst.addr().get());
                 dst
-            }
+            };
+
+            chunk.poison_remaining();
+            ptr
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    BaseAllocator, Bump, BumpScope, WithoutDealloc, WithoutShrink,
+    BaseAllocator, Bump, BumpScope, BumpScopeWithLimit, WithoutDealloc, WithoutShrink,
     settings::BumpAllocatorSettings,
     traits::{BumpAllocatorCore, assert_dyn_compatible, assert_implements},
 };
@@ -62,6 +62,13 @@ where
 {
 }
 
+unsafe impl<'a, A, S> BumpAllocatorCoreScope<'a> for BumpScopeWithLimit<'a, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+}
+
 unsafe impl<'a, A, S> BumpAllocatorCoreScope<'a> for &'a Bump<A, S>
 where
     A: BaseAllocator<S::GuaranteedAllocated>,
@@ -3,9 +3,9 @@
 use core::{alloc::Layout, num::NonZeroUsize, ptr::NonNull};
 
 use crate::{
-    BaseAllocator, Bump, BumpBox, BumpScope, SizedTypeProperties, WithoutDealloc, WithoutShrink,
+    BaseAllocator, Bump, BumpBox, BumpScope, BumpScopeWithLimit, SizedTypeProperties, WithoutDealloc, WithoutShrink,
     alloc::AllocError,
-    bump_down,
+    bump_down, poison,
     polyfill::non_null,
     settings::BumpAllocatorSettings,
     stats::{AnyStats, Stats},
@@ -56,6 +56,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     ///
     /// Panics if the allocation fails.
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_layout(&self, layout: Layout) -> NonNull<u8>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -75,6 +76,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     /// # Errors
     ///
     /// Errors if the allocation fails.
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -95,6 +97,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     ///
     /// Panics if the allocation fails.
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_sized<T>(&self) -> NonNull<T>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -115,6 +118,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     /// # Errors
     ///
     /// Errors if the allocation fails.
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -135,6 +139,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     ///
     /// Panics if the allocation fails.
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice<T>(&self, len: usize) -> NonNull<T>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -155,6 +160,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     /// # Errors
     ///
     /// Errors if the allocation fails.
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -175,6 +181,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     ///
     /// Panics if the allocation fails.
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T>;
 
     /// A specialized version of [`allocate`](crate::alloc::Allocator::allocate).
@@ -195,6 +202,7 @@ pub unsafe trait BumpAllocatorTyped: BumpAllocatorCore {
     /// # Errors
     ///
     /// Errors if the allocation fails.
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError>;
 
     /// A specialized version of [`shrink`](crate::alloc::Allocator::shrink).
@@ -427,44 +435,52 @@ macro_rules! impl_for_trait_object {
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
                     panic_on_error(for_trait_object::allocate_layout(self, layout))
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
                     for_trait_object::allocate_layout(self, layout)
                 }
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_sized<T>(&self) -> NonNull<T> {
                     panic_on_error(for_trait_object::allocate_sized(self))
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
                     for_trait_object::allocate_sized(self)
                 }
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
                     panic_on_error(for_trait_object::allocate_slice(self, len))
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
                     for_trait_object::allocate_slice(self, len)
                 }
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
                     panic_on_error(for_trait_object::allocate_slice_for(self, slice))
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
                     for_trait_object::allocate_slice_for(self, slice)
                 }
@@ -521,13 +537,14 @@ macro_rules! impl_for_trait_object {
     };
 }
 
-mod for_trait_object {
+pub(crate) mod for_trait_object {
     use crate::error_behavior::ErrorBehavior;
 
     use super::*;
 
     #[inline]
-    pub(super) fn allocate_layout<E: ErrorBehavior>(bump: impl BumpAllocatorCore, layout: Layout) -> Result<NonNull<u8>, E> {
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    pub(crate) fn allocate_layout<E: ErrorBehavior>(bump: impl BumpAllocatorCore, layout: Layout) -> Result<NonNull<u8>, E> {
         match bump.allocate(layout) {
             Ok(ptr) => Ok(ptr.cast()),
             Err(AllocError) => Err(E::allocation(layout)),
@@ -535,7 +552,8 @@ mod for_trait_object {
     }
 
     #[inline]
-    pub(super) fn allocate_sized<E: ErrorBehavior, T>(bump: impl BumpAllocatorCore) -> Result<NonNull<T>, E> {
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    pub(crate) fn allocate_sized<E: ErrorBehavior, T>(bump: impl BumpAllocatorCore) -> Result<NonNull<T>, E> {
         match bump.allocate(Layout::new::<T>()) {
             Ok(ptr) => Ok(ptr.cast()),
             Err(AllocError) => Err(E::allocation(Layout::new::<T>())),
@@ -543,7 +561,8 @@ mod for_trait_object {
     }
 
     #[inline]
-    pub(super) fn allocate_slice<E: ErrorBehavior, T>(bump: impl BumpAllocatorCore, len: usize) -> Result<NonNull<T>, E> {
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    pub(crate) fn allocate_slice<E: ErrorBehavior, T>(bump: impl BumpAllocatorCore, len: usize) -> Result<NonNull<T>, E> {
         let Ok(layout) = Layout::array::<T>(len) else {
             return Err(E::invalid_slice_layout());
         };
@@ -555,7 +574,8 @@ mod for_trait_object {
     }
 
     #[inline]
-    pub(super) fn allocate_slice_for<E: ErrorBehavior, T>(
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    pub(crate) fn allocate_slice_for<E: ErrorBehavior, T>(
         bump: impl BumpAllocatorCore,
         slice: &[T],
     ) -> Result<NonNull<T>, E> {
@@ -569,7 +589,7 @@ mod for_trait_object {
 
     #[inline]
     #[expect(clippy::unnecessary_wraps)]
-    pub(super) unsafe fn shrink_slice<T>(
+    pub(crate) unsafe fn shrink_slice<T>(
         bump: impl BumpAllocatorCore,
         ptr: NonNull<T>,
         old_len: usize,
@@ -596,7 +616,7 @@ mod for_trait_object {
     }
 
     #[inline]
-    pub(super) fn prepare_slice_allocation<E: ErrorBehavior, T>(
+    pub(crate) fn prepare_slice_allocation<E: ErrorBehavior, T>(
         bump: impl BumpAllocatorCore,
         len: usize,
     ) -> Result<NonNull<[T]>, E> {
@@ -621,7 +641,7 @@ mod for_trait_object {
     }
 
     #[inline(always)]
-    pub(super) unsafe fn allocate_prepared_slice<T>(
+    pub(crate) unsafe fn allocate_prepared_slice<T>(
         bump: impl BumpAllocatorCore,
         ptr: NonNull<T>,
         len: usize,
@@ -636,7 +656,7 @@ mod for_trait_object {
     }
 
     #[inline(always)]
-    pub(super) fn prepare_slice_allocation_rev<E, T>(
+    pub(crate) fn prepare_slice_allocation_rev<E, T>(
         bump: impl BumpAllocatorCore,
         len: usize,
     ) -> Result<(NonNull<T>, usize), E>
@@ -664,7 +684,7 @@ mod for_trait_object {
     }
 
     #[inline(always)]
-    pub(super) unsafe fn allocate_prepared_slice_rev<T>(
+    pub(crate) unsafe fn allocate_prepared_slice_rev<T>(
         bump: impl BumpAllocatorCore,
         ptr: NonNull<T>,
         len: usize,
@@ -679,7 +699,7 @@ mod for_trait_object {
     }
 
     #[inline]
-    pub(super) fn reserve<E: ErrorBehavior>(bump: impl BumpAllocatorCore, additional: usize) -> Result<(), E> {
+    pub(crate) fn reserve<E: ErrorBehavior>(bump: impl BumpAllocatorCore, additional: usize) -> Result<(), E> {
         let Ok(layout) = Layout::array::<u8>(additional) else {
             return Err(E::invalid_slice_layout());
         };
@@ -714,44 +734,52 @@ macro_rules! impl_for_ref {
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
                     B::allocate_layout(self, layout)
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
                     B::try_allocate_layout(self, layout)
                 }
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_sized<T>(&self) -> NonNull<T> {
                     B::allocate_sized(self)
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
                     B::try_allocate_sized(self)
                 }
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
                     B::allocate_slice(self, len)
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
                     B::try_allocate_slice(self, len)
                 }
 
                 #[inline(always)]
                 #[cfg(feature = "panic-on-alloc")]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
                     B::allocate_slice_for(self, slice)
                 }
 
                 #[inline(always)]
+                #[cfg_attr(feature = "track-allocations", track_caller)]
                 fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
                     B::try_allocate_slice_for(self, slice)
                 }
@@ -826,44 +854,52 @@ unsafe impl<B: BumpAllocatorTyped> BumpAllocatorTyped for WithoutDealloc<B> {
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
         B::allocate_layout(&self.0, layout)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
         B::try_allocate_layout(&self.0, layout)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_sized<T>(&self) -> NonNull<T> {
         B::allocate_sized(&self.0)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
         B::try_allocate_sized(&self.0)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
         B::allocate_slice(&self.0, len)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
         B::try_allocate_slice(&self.0, len)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
         B::allocate_slice_for(&self.0, slice)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
         B::try_allocate_slice_for(&self.0, slice)
     }
@@ -930,44 +966,52 @@ unsafe impl<B: BumpAllocatorTyped> BumpAllocatorTyped for WithoutShrink<B> {
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
         B::allocate_layout(&self.0, layout)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
         B::try_allocate_layout(&self.0, layout)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_sized<T>(&self) -> NonNull<T> {
         B::allocate_sized(&self.0)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
         B::try_allocate_sized(&self.0)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
         B::allocate_slice(&self.0, len)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
         B::try_allocate_slice(&self.0, len)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
         B::allocate_slice_for(&self.0, slice)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
         B::try_allocate_slice_for(&self.0, slice)
     }
@@ -1023,6 +1067,122 @@ unsafe impl<B: BumpAllocatorTyped> BumpAllocatorTyped for WithoutShrink<B> {
     }
 }
 
+unsafe impl<A, S> BumpAllocatorTyped for BumpScopeWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+    type TypedStats<'b>
+        = Stats<'b, A, S>
+    where
+        Self: 'b;
+
+    #[inline(always)]
+    fn typed_stats(&self) -> Self::TypedStats<'_> {
+        self.stats()
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
+        panic_on_error(for_trait_object::allocate_layout(self, layout))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        for_trait_object::allocate_layout(self, layout)
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn allocate_sized<T>(&self) -> NonNull<T> {
+        panic_on_error(for_trait_object::allocate_sized(self))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
+        for_trait_object::allocate_sized(self)
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
+        panic_on_error(for_trait_object::allocate_slice(self, len))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
+        for_trait_object::allocate_slice(self, len)
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
+        panic_on_error(for_trait_object::allocate_slice_for(self, slice))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
+    fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
+        for_trait_object::allocate_slice_for(self, slice)
+    }
+
+    #[inline(always)]
+    unsafe fn shrink_slice<T>(&self, ptr: NonNull<T>, old_len: usize, new_len: usize) -> Option<NonNull<T>> {
+        unsafe { for_trait_object::shrink_slice(self, ptr, old_len, new_len) }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn prepare_slice_allocation<T>(&self, len: usize) -> NonNull<[T]> {
+        panic_on_error(for_trait_object::prepare_slice_allocation(self, len))
+    }
+
+    #[inline(always)]
+    fn try_prepare_slice_allocation<T>(&self, len: usize) -> Result<NonNull<[T]>, AllocError> {
+        for_trait_object::prepare_slice_allocation(self, len)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared_slice<T>(&self, ptr: NonNull<T>, len: usize, cap: usize) -> NonNull<[T]> {
+        unsafe { for_trait_object::allocate_prepared_slice(self, ptr, len, cap) }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn prepare_slice_allocation_rev<T>(&self, len: usize) -> (NonNull<T>, usize) {
+        panic_on_error(for_trait_object::prepare_slice_allocation_rev(self, len))
+    }
+
+    #[inline(always)]
+    fn try_prepare_slice_allocation_rev<T>(&self, len: usize) -> Result<(NonNull<T>, usize), AllocError> {
+        for_trait_object::prepare_slice_allocation_rev(self, len)
+    }
+
+    #[inline(always)]
+    unsafe fn allocate_prepared_slice_rev<T>(&self, ptr: NonNull<T>, len: usize, cap: usize) -> NonNull<[T]> {
+        unsafe { for_trait_object::allocate_prepared_slice_rev(self, ptr, len, cap) }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "panic-on-alloc")]
+    fn reserve(&self, additional: usize) {
+        panic_on_error(for_trait_object::reserve(self, additional));
+    }
+
+    #[inline(always)]
+    fn try_reserve(&self, additional: usize) -> Result<(), AllocError> {
+        for_trait_object::reserve(self, additional)
+    }
+}
+
 unsafe impl<A, S> BumpAllocatorTyped for BumpScope<'_, A, S>
 where
     A: BaseAllocator<S::GuaranteedAllocated>,
@@ -1040,51 +1200,59 @@ where
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
         panic_on_error(self.raw.alloc(layout))
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
         self.raw.alloc(layout)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_sized<T>(&self) -> NonNull<T> {
         panic_on_error(self.raw.alloc_sized())
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
         self.raw.alloc_sized()
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
         panic_on_error(self.raw.alloc_slice(len))
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
         self.raw.alloc_slice(len)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
         panic_on_error(self.raw.alloc_slice_for(slice))
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
         self.raw.alloc_slice_for(slice)
     }
 
     #[inline]
     unsafe fn shrink_slice<T>(&self, ptr: NonNull<T>, old_len: usize, new_len: usize) -> Option<NonNull<T>> {
-        if !S::SHRINKS {
+        if !S::SHRINKS || S::RED_ZONES {
             return None;
         }
 
@@ -1117,6 +1285,7 @@ where
                 let new_pos = up_align_usize_unchecked(end, S::MIN_ALIGN);
 
                 chunk.set_pos_addr(new_pos);
+                poison::freed_except::<S>(old_ptr, old_size, old_ptr, new_size);
                 Some(old_ptr.cast())
             } else {
                 let old_addr = old_ptr.addr();
@@ -1136,6 +1305,7 @@ where
                 }
 
                 chunk.set_pos(new_ptr);
+                poison::freed_except::<S>(old_ptr, old_size, new_ptr, new_size);
                 Some(new_ptr.cast())
             }
         }
@@ -1158,9 +1328,16 @@ where
             // a successful `prepare_allocation` guarantees a non-dummy-chunk
             let chunk = self.raw.chunk.get().as_non_dummy_unchecked();
 
+            if S::RED_ZONES {
+                let range = start.cast::<u8>()..start.add(cap).cast::<u8>();
+                let layout = Layout::from_size_align_unchecked(len * T::SIZE, T::ALIGN);
+                let ptr = chunk.allocate_prepared_guarded(range, start.cast(), layout);
+                return NonNull::slice_from_raw_parts(ptr.cast(), len);
+            }
+
             let end = start.add(len);
 
-            if S::UP {
+            let slice = if S::UP {
                 chunk.set_pos_addr_and_align_from(end.addr().get(), T::ALIGN);
                 NonNull::slice_from_raw_parts(start, len)
             } else {
@@ -1169,7 +1346,10 @@ where
                 start.copy_to(dst, len);
                 chunk.set_pos_addr_and_align_from(dst.addr().get(), T::ALIGN);
                 NonNull::slice_from_raw_parts(dst, len)
-            }
+            };
+
+            chunk.poison_remaining();
+            slice
         }
     }
 
@@ -1190,7 +1370,14 @@ where
             // a successful `prepare_allocation` guarantees a non-dummy-chunk
             let chunk = self.raw.chunk.get().as_non_dummy_unchecked();
 
-            if S::UP {
+            if S::RED_ZONES {
+                let range = end.sub(cap).cast::<u8>()..end.cast::<u8>();
+                let layout = Layout::from_size_align_unchecked(len * T::SIZE, T::ALIGN);
+                let ptr = chunk.allocate_prepared_guarded(range, end.sub(len).cast(), layout);
+                return NonNull::slice_from_raw_parts(ptr.cast(), len);
+            }
+
+            let slice = if S::UP {
                 let dst = end.sub(cap);
                 let dst_end = dst.add(len);
 
@@ -1204,7 +1391,10 @@ where
                 let dst = end.sub(len);
                 chunk.set_pos_addr_and_align_from(dst.addr().get(), T::ALIGN);
                 NonNull::slice_from_raw_parts(dst, len)
-            }
+            };
+
+            chunk.poison_remaining();
+            slice
         }
     }
 
@@ -1237,44 +1427,52 @@ where
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_layout(&self, layout: Layout) -> NonNull<u8> {
         self.as_scope().allocate_layout(layout)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
         self.as_scope().try_allocate_layout(layout)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_sized<T>(&self) -> NonNull<T> {
         self.as_scope().allocate_sized()
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_sized<T>(&self) -> Result<NonNull<T>, AllocError> {
         self.as_scope().try_allocate_sized()
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice<T>(&self, len: usize) -> NonNull<T> {
         self.as_scope().allocate_slice(len)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice<T>(&self, len: usize) -> Result<NonNull<T>, AllocError> {
         self.as_scope().try_allocate_slice(len)
     }
 
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn allocate_slice_for<T>(&self, slice: &[T]) -> NonNull<T> {
         self.as_scope().allocate_slice_for(slice)
     }
 
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_allocate_slice_for<T>(&self, slice: &[T]) -> Result<NonNull<T>, AllocError> {
         self.as_scope().try_allocate_slice_for(slice)
     }
@@ -29,6 +29,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc<T>(&self, value: T) -> BumpBox<'a, T> {
         self.alloc_uninit().init(value)
     }
@@ -47,6 +48,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc<T>(&self, value: T) -> Result<BumpBox<'a, T>, AllocError> {
         Ok(self.try_alloc_uninit()?.init(value))
     }
@@ -70,6 +72,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_with<T>(&self, f: impl FnOnce() -> T) -> BumpBox<'a, T> {
         self.alloc_uninit().init(f())
     }
@@ -93,6 +96,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_with<T>(&self, f: impl FnOnce() -> T) -> Result<BumpBox<'a, T>, AllocError> {
         Ok(self.try_alloc_uninit()?.init(f()))
     }
@@ -113,6 +117,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_default<T: Default>(&self) -> BumpBox<'a, T> {
         self.alloc_with(T::default)
     }
@@ -133,6 +138,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_default<T: Default>(&self) -> Result<BumpBox<'a, T>, AllocError> {
         self.try_alloc_with(T::default)
     }
@@ -197,6 +203,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[cfg(feature = "panic-on-alloc")]
     #[cfg(feature = "nightly-clone-to-uninit")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_clone<T: CloneToUninit + ?Sized>(&self, value: &T) -> BumpBox<'a, T> {
         let data = self.allocate_layout(Layout::for_value(value));
         let metadata = ptr::metadata(value);
@@ -270,6 +277,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[cfg(feature = "nightly-clone-to-uninit")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_clone<T: CloneToUninit + ?Sized>(&self, value: &T) -> Result<BumpBox<'a, T>, AllocError> {
         let data = self.try_allocate_layout(Layout::for_value(value))?;
         let metadata = ptr::metadata(value);
@@ -318,12 +326,13 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_uninit<T>(&self) -> BumpBox<'a, MaybeUninit<T>> {
         if T::IS_ZST {
             return BumpBox::zst_uninit();
         }
 
-        let ptr = self.allocate_sized();
+        let ptr = self.allocate_sized::<T>().cast();
         unsafe { BumpBox::from_raw(ptr) }
     }
 
@@ -364,12 +373,13 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_uninit<T>(&self) -> Result<BumpBox<'a, MaybeUninit<T>>, AllocError> {
         if T::IS_ZST {
             return Ok(BumpBox::zst_uninit());
         }
 
-        let ptr = self.try_allocate_sized()?;
+        let ptr = self.try_allocate_sized::<T>()?.cast();
         unsafe { Ok(BumpBox::from_raw(ptr)) }
     }
 
@@ -399,6 +409,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_slice_move<T>(&self, slice: impl OwnedSlice<Item = T>) -> BumpBox<'a, [T]> {
         BumpVec::from_owned_slice_in(slice, self).into_boxed_slice()
     }
@@ -429,6 +440,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_slice_move<T>(&self, slice: impl OwnedSlice<Item = T>) -> Result<BumpBox<'a, [T]>, AllocError> {
         Ok(BumpVec::try_from_owned_slice_in(slice, self)?.into_boxed_slice())
     }
@@ -447,6 +459,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_slice_copy<T: Copy>(&self, slice: &[T]) -> BumpBox<'a, [T]> {
         if T::IS_ZST {
             return BumpBox::zst_slice_clone(slice);
@@ -476,6 +489,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_slice_copy<T: Copy>(&self, slice: &[T]) -> Result<BumpBox<'a, [T]>, AllocError> {
         if T::IS_ZST {
             return Ok(BumpBox::zst_slice_clone(slice));
@@ -505,6 +519,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_slice_clone<T: Clone>(&self, slice: &[T]) -> BumpBox<'a, [T]> {
         if T::IS_ZST {
             return BumpBox::zst_slice_clone(slice);
@@ -527,6 +542,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_slice_clone<T: Clone>(&self, slice: &[T]) -> Result<BumpBox<'a, [T]>, AllocError> {
         if T::IS_ZST {
             return Ok(BumpBox::zst_slice_clone(slice));
@@ -549,6 +565,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_slice_fill<T: Clone>(&self, len: usize, value: T) -> BumpBox<'a, [T]> {
         if T::IS_ZST {
             return BumpBox::zst_slice_fill(len, value);
@@ -571,6 +588,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_slice_fill<T: Clone>(&self, len: usize, value: T) -> Result<BumpBox<'a, [T]>, AllocError> {
         if T::IS_ZST {
             return Ok(BumpBox::zst_slice_fill(len, value));
@@ -598,6 +616,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_slice_fill_with<T>(&self, len: usize, f: impl FnMut() -> T) -> BumpBox<'a, [T]> {
         if T::IS_ZST {
             return BumpBox::zst_slice_fill_with(len, f);
@@ -625,6 +644,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_slice_fill_with<T>(&self, len: usize, f: impl FnMut() -> T) -> Result<BumpBox<'a, [T]>, AllocError> {
         if T::IS_ZST {
             return Ok(BumpBox::zst_slice_fill_with(len, f));
@@ -676,8 +696,9 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_uninit_slice<T>(&self, len: usize) -> BumpBox<'a, [MaybeUninit<T>]> {
-        let ptr = self.allocate_slice::<MaybeUninit<T>>(len);
+        let ptr = self.allocate_slice::<T>(len).cast::<MaybeUninit<T>>();
 
         unsafe {
             let slice_ptr = NonNull::slice_from_raw_parts(ptr, len);
@@ -729,8 +750,9 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_uninit_slice<T>(&self, len: usize) -> Result<BumpBox<'a, [MaybeUninit<T>]>, AllocError> {
-        let ptr = self.try_allocate_slice::<MaybeUninit<T>>(len)?;
+        let ptr = self.try_allocate_slice::<T>(len)?.cast::<MaybeUninit<T>>();
 
         unsafe {
             let slice_ptr = NonNull::slice_from_raw_parts(ptr, len);
@@ -764,6 +786,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_uninit_slice_for<T>(&self, slice: &[T]) -> BumpBox<'a, [MaybeUninit<T>]> {
         let ptr = self.allocate_slice_for(slice).cast::<MaybeUninit<T>>();
 
@@ -799,6 +822,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_uninit_slice_for<T>(&self, slice: &[T]) -> Result<BumpBox<'a, [MaybeUninit<T>]>, AllocError> {
         let ptr = self.try_allocate_slice_for(slice)?.cast::<MaybeUninit<T>>();
 
@@ -822,6 +846,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_str(&self, src: &str) -> BumpBox<'a, str> {
         let slice = self.alloc_slice_copy(src.as_bytes());
 
@@ -843,6 +868,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_str(&self, src: &str) -> Result<BumpBox<'a, str>, AllocError> {
         let slice = self.try_alloc_slice_copy(src.as_bytes())?;
 
@@ -874,6 +900,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_fmt(&self, args: fmt::Arguments) -> BumpBox<'a, str> {
         if let Some(string) = args.as_str() {
             return self.alloc_str(string);
@@ -908,6 +935,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_fmt(&self, args: fmt::Arguments) -> Result<BumpBox<'a, str>, AllocError> {
         if let Some(string) = args.as_str() {
             return self.try_alloc_str(string);
@@ -932,6 +960,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_cstr(&self, src: &CStr) -> &'a CStr {
         let slice = self.alloc_slice_copy(src.to_bytes_with_nul()).into_ref();
 
@@ -953,6 +982,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_cstr(&self, src: &CStr) -> Result<&'a CStr, AllocError> {
         let slice = self.try_alloc_slice_copy(src.to_bytes_with_nul())?.into_ref();
 
@@ -979,6 +1009,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_cstr_from_str(&self, src: &str) -> &'a CStr {
         let src = src.as_bytes();
 
@@ -1019,6 +1050,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_cstr_from_str(&self, src: &str) -> Result<&'a CStr, AllocError> {
         let src = src.as_bytes();
 
@@ -1068,6 +1100,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_cstr_fmt(&self, args: fmt::Arguments) -> &'a CStr {
         if let Some(string) = args.as_str() {
             return self.alloc_cstr_from_str(string);
@@ -1106,6 +1139,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_cstr_fmt(&self, args: fmt::Arguments) -> Result<&'a CStr, AllocError> {
         if let Some(string) = args.as_str() {
             return self.try_alloc_cstr_from_str(string);
@@ -1137,6 +1171,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// [`alloc_iter_mut`]: crate::traits::MutBumpAllocatorTypedScope::alloc_iter_mut
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_iter<T>(&self, iter: impl IntoIterator<Item = T>) -> BumpBox<'a, [T]> {
         let iter = iter.into_iter();
         let capacity = iter.size_hint().0;
@@ -1171,6 +1206,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// [`try_alloc_iter_exact`]: crate::traits::BumpAllocatorTypedScope::try_alloc_iter_exact
     /// [`try_alloc_iter_mut`]: crate::traits::MutBumpAllocatorTypedScope::try_alloc_iter_mut
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_iter<T>(&self, iter: impl IntoIterator<Item = T>) -> Result<BumpBox<'a, [T]>, AllocError> {
         let iter = iter.into_iter();
         let capacity = iter.size_hint().0;
@@ -1198,6 +1234,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// ```
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_iter_exact<T, I>(&self, iter: impl IntoIterator<Item = T, IntoIter = I>) -> BumpBox<'a, [T]>
     where
         I: ExactSizeIterator<Item = T>,
@@ -1232,6 +1269,7 @@ pub trait BumpAllocatorTypedScope<'a>: BumpAllocatorCoreScope<'a> + BumpAllocato
     /// # Ok::<(), bump_scope::alloc::AllocError>(())
     /// ```
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_iter_exact<T, I>(
         &self,
         iter: impl IntoIterator<Item = T, IntoIter = I>,
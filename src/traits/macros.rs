@@ -15,6 +15,7 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorScope::allocator`].
         #[must_use]
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn allocator(&$self) -> Option<&$lifetime A> {
             BumpAllocatorScope::allocator($access)
         }
@@ -44,6 +45,18 @@ macro_rules! forward_methods {
             BumpAllocator::scoped($access_mut, f)
         }
 
+        /// Forwards to [`BumpAllocator::scope_guard_with_limit`].
+        #[inline(always)]
+        pub fn scope_guard_with_limit(&mut $self, limit: usize) -> BumpScopeGuardWithLimit<'_, A, S> {
+            BumpAllocator::scope_guard_with_limit($access_mut, limit)
+        }
+
+        /// Forwards to [`BumpAllocator::scoped_with_limit`].
+        #[inline(always)]
+        pub fn scoped_with_limit<R>(&mut $self, limit: usize, f: impl FnOnce(&mut BumpScopeWithLimit<A, S>) -> R) -> R {
+            BumpAllocator::scoped_with_limit($access_mut, limit, f)
+        }
+
         /// Forwards to [`BumpAllocator::scoped_aligned`].
         #[inline(always)]
         pub fn scoped_aligned<const NEW_MIN_ALIGN: usize, R>(
@@ -83,12 +96,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc<T>(&$self, value: T) -> BumpBox<$lifetime, T> {
             BumpAllocatorTypedScope::alloc($access, value)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc<T>(&$self, value: T) -> Result<BumpBox<$lifetime, T>, AllocError> {
             BumpAllocatorTypedScope::try_alloc($access, value)
         }
@@ -96,12 +111,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_with`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_with<T>(&$self, f: impl FnOnce() -> T) -> BumpBox<$lifetime, T> {
             BumpAllocatorTypedScope::alloc_with($access, f)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_with`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_with<T>(&$self, f: impl FnOnce() -> T) -> Result<BumpBox<$lifetime, T>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_with($access, f)
         }
@@ -109,12 +126,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_default`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_default<T: Default>(&$self) -> BumpBox<$lifetime, T> {
             BumpAllocatorTypedScope::alloc_default($access)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_default`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_default<T: Default>(&$self) -> Result<BumpBox<$lifetime, T>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_default($access)
         }
@@ -123,6 +142,7 @@ macro_rules! forward_methods {
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
         #[cfg(feature = "nightly-clone-to-uninit")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_clone<T: CloneToUninit + ?Sized>(&$self, value: &T) -> BumpBox<$lifetime, T> {
             BumpAllocatorTypedScope::alloc_clone($access, value)
         }
@@ -130,6 +150,7 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_clone`].
         #[inline(always)]
         #[cfg(feature = "nightly-clone-to-uninit")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_clone<T: CloneToUninit + ?Sized>(&$self, value: &T) -> Result<BumpBox<$lifetime, T>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_clone($access, value)
         }
@@ -137,12 +158,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_slice_move`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_slice_move<T>(&$self, slice: impl OwnedSlice<Item = T>) -> BumpBox<$lifetime, [T]> {
             BumpAllocatorTypedScope::alloc_slice_move($access, slice)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_slice_move`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_slice_move<T>(&$self, slice: impl OwnedSlice<Item = T>) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_slice_move($access, slice)
         }
@@ -150,12 +173,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_slice_copy`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_slice_copy<T: Copy>(&$self, slice: &[T]) -> BumpBox<$lifetime, [T]> {
             BumpAllocatorTypedScope::alloc_slice_copy($access, slice)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_slice_copy`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_slice_copy<T: Copy>(&$self, slice: &[T]) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_slice_copy($access, slice)
         }
@@ -163,12 +188,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_slice_clone`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_slice_clone<T: Clone>(&$self, slice: &[T]) -> BumpBox<$lifetime, [T]> {
             BumpAllocatorTypedScope::alloc_slice_clone($access, slice)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_slice_clone`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_slice_clone<T: Clone>(&$self, slice: &[T]) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_slice_clone($access, slice)
         }
@@ -176,12 +203,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_slice_fill`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_slice_fill<T: Clone>(&$self, len: usize, value: T) -> BumpBox<$lifetime, [T]> {
             BumpAllocatorTypedScope::alloc_slice_fill($access, len, value)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_slice_fill`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_slice_fill<T: Clone>(&$self, len: usize, value: T) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_slice_fill($access, len, value)
         }
@@ -189,12 +218,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_slice_fill_with`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_slice_fill_with<T>(&$self, len: usize, f: impl FnMut() -> T) -> BumpBox<$lifetime, [T]> {
             BumpAllocatorTypedScope::alloc_slice_fill_with($access, len, f)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_slice_fill_with`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_slice_fill_with<T>(&$self, len: usize, f: impl FnMut() -> T) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_slice_fill_with($access, len, f)
         }
@@ -202,12 +233,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_str`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_str(&$self, src: &str) -> BumpBox<$lifetime, str> {
             BumpAllocatorTypedScope::alloc_str($access, src)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_str`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_str(&$self, src: &str) -> Result<BumpBox<$lifetime, str>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_str($access, src)
         }
@@ -215,12 +248,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_fmt`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_fmt(&$self, args: fmt::Arguments) -> BumpBox<$lifetime, str> {
             BumpAllocatorTypedScope::alloc_fmt($access, args)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_fmt`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_fmt(&$self, args: fmt::Arguments) -> Result<BumpBox<$lifetime, str>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_fmt($access, args)
         }
@@ -228,12 +263,14 @@ macro_rules! forward_methods {
         /// Forwards to [`MutBumpAllocatorTypedScope::alloc_fmt_mut`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_fmt_mut(&mut $self, args: fmt::Arguments) -> BumpBox<$lifetime, str> {
             MutBumpAllocatorTypedScope::alloc_fmt_mut($access_mut, args)
         }
 
         /// Forwards to [`MutBumpAllocatorTypedScope::try_alloc_fmt_mut`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_fmt_mut(&mut $self, args: fmt::Arguments) -> Result<BumpBox<$lifetime, str>, AllocError> {
             MutBumpAllocatorTypedScope::try_alloc_fmt_mut($access_mut, args)
         }
@@ -241,12 +278,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_cstr`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_cstr(&$self, src: &CStr) -> &$lifetime CStr {
             BumpAllocatorTypedScope::alloc_cstr($access, src)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_cstr`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_cstr(&$self, src: &CStr) -> Result<&$lifetime CStr, AllocError> {
             BumpAllocatorTypedScope::try_alloc_cstr($access, src)
         }
@@ -254,12 +293,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_cstr_from_str`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_cstr_from_str(&$self, src: &str) -> &$lifetime CStr {
             BumpAllocatorTypedScope::alloc_cstr_from_str($access, src)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_cstr_from_str`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_cstr_from_str(&$self, src: &str) -> Result<&$lifetime CStr, AllocError> {
             BumpAllocatorTypedScope::try_alloc_cstr_from_str($access, src)
         }
@@ -267,12 +308,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_cstr_fmt`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_cstr_fmt(&$self, args: fmt::Arguments) -> &$lifetime CStr {
             BumpAllocatorTypedScope::alloc_cstr_fmt($access, args)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_cstr_fmt`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_cstr_fmt(&$self, args: fmt::Arguments) -> Result<&$lifetime CStr, AllocError> {
             BumpAllocatorTypedScope::try_alloc_cstr_fmt($access, args)
         }
@@ -280,12 +323,14 @@ macro_rules! forward_methods {
         /// Forwards to [`MutBumpAllocatorTypedScope::alloc_cstr_fmt_mut`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_cstr_fmt_mut(&mut $self, args: fmt::Arguments) -> &$lifetime CStr {
             MutBumpAllocatorTypedScope::alloc_cstr_fmt_mut($access_mut, args)
         }
 
         /// Forwards to [`MutBumpAllocatorTypedScope::try_alloc_cstr_fmt_mut`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_cstr_fmt_mut(&mut $self, args: fmt::Arguments) -> Result<&$lifetime CStr, AllocError> {
             MutBumpAllocatorTypedScope::try_alloc_cstr_fmt_mut($access_mut, args)
         }
@@ -293,12 +338,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_iter`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_iter<T>(&$self, iter: impl IntoIterator<Item = T>) -> BumpBox<$lifetime, [T]> {
             BumpAllocatorTypedScope::alloc_iter($access, iter)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_iter`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_iter<T>(&$self, iter: impl IntoIterator<Item = T>) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_iter($access, iter)
         }
@@ -306,6 +353,7 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_iter_exact`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_iter_exact<T, I>(&$self, iter: impl IntoIterator<Item = T, IntoIter = I>) -> BumpBox<$lifetime, [T]>
         where
             I: ExactSizeIterator<Item = T>,
@@ -315,6 +363,7 @@ macro_rules! forward_methods {
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_iter_exact`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_iter_exact<T, I>(
             &$self,
             iter: impl IntoIterator<Item = T, IntoIter = I>,
@@ -328,12 +377,14 @@ macro_rules! forward_methods {
         /// Forwards to [`MutBumpAllocatorTypedScope::alloc_iter_mut`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_iter_mut<T>(&mut $self, iter: impl IntoIterator<Item = T>) -> BumpBox<$lifetime, [T]> {
             MutBumpAllocatorTypedScope::alloc_iter_mut($access_mut, iter)
         }
 
         /// Forwards to [`MutBumpAllocatorTypedScope::try_alloc_iter_mut`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_iter_mut<T>(&mut $self, iter: impl IntoIterator<Item = T>) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             MutBumpAllocatorTypedScope::try_alloc_iter_mut($access_mut, iter)
         }
@@ -341,12 +392,14 @@ macro_rules! forward_methods {
         /// Forwards to [`MutBumpAllocatorTypedScope::alloc_iter_mut_rev`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_iter_mut_rev<T>(&mut $self, iter: impl IntoIterator<Item = T>) -> BumpBox<$lifetime, [T]> {
             MutBumpAllocatorTypedScope::alloc_iter_mut_rev($access_mut, iter)
         }
 
         /// Forwards to [`MutBumpAllocatorTypedScope::try_alloc_iter_mut_rev`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_iter_mut_rev<T>(&mut $self, iter: impl IntoIterator<Item = T>) -> Result<BumpBox<$lifetime, [T]>, AllocError> {
             MutBumpAllocatorTypedScope::try_alloc_iter_mut_rev($access_mut, iter)
         }
@@ -354,12 +407,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_uninit`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_uninit<T>(&$self) -> BumpBox<$lifetime, MaybeUninit<T>> {
             BumpAllocatorTypedScope::alloc_uninit($access)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_uninit`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_uninit<T>(&$self) -> Result<BumpBox<$lifetime, MaybeUninit<T>>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_uninit($access)
         }
@@ -367,12 +422,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_uninit_slice`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_uninit_slice<T>(&$self, len: usize) -> BumpBox<$lifetime, [MaybeUninit<T>]> {
             BumpAllocatorTypedScope::alloc_uninit_slice($access, len)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_uninit_slice`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_uninit_slice<T>(&$self, len: usize) -> Result<BumpBox<$lifetime, [MaybeUninit<T>]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_uninit_slice($access, len)
         }
@@ -380,12 +437,14 @@ macro_rules! forward_methods {
         /// Forwards to [`BumpAllocatorTypedScope::alloc_uninit_slice_for`].
         #[inline(always)]
         #[cfg(feature = "panic-on-alloc")]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn alloc_uninit_slice_for<T>(&$self, slice: &[T]) -> BumpBox<$lifetime, [MaybeUninit<T>]> {
             BumpAllocatorTypedScope::alloc_uninit_slice_for($access, slice)
         }
 
         /// Forwards to [`BumpAllocatorTypedScope::try_alloc_uninit_slice_for`].
         #[inline(always)]
+        #[cfg_attr(feature = "track-allocations", track_caller)]
         pub fn try_alloc_uninit_slice_for<T>(&$self, slice: &[T]) -> Result<BumpBox<$lifetime, [MaybeUninit<T>]>, AllocError> {
             BumpAllocatorTypedScope::try_alloc_uninit_slice_for($access, slice)
         }
@@ -1,5 +1,5 @@
 use crate::{
-    BaseAllocator, Bump, BumpScope, WithoutDealloc, WithoutShrink,
+    BaseAllocator, Bump, BumpScope, BumpScopeWithLimit, WithoutDealloc, WithoutShrink,
     settings::BumpAllocatorSettings,
     traits::{BumpAllocatorCore, assert_implements},
 };
@@ -42,3 +42,10 @@ where
     S: BumpAllocatorSettings,
 {
 }
+
+unsafe impl<A, S> MutBumpAllocatorCore for BumpScopeWithLimit<'_, A, S>
+where
+    A: BaseAllocator<S::GuaranteedAllocated>,
+    S: BumpAllocatorSettings,
+{
+}
@@ -34,6 +34,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     /// [`alloc_iter_mut_rev`]: crate::traits::MutBumpAllocatorTypedScope::alloc_iter_mut_rev
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_iter_mut<T>(&mut self, iter: impl IntoIterator<Item = T>) -> BumpBox<'a, [T]> {
         let iter = iter.into_iter();
         let capacity = iter.size_hint().0;
@@ -70,6 +71,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     /// [`try_alloc_iter`]: crate::traits::BumpAllocatorTypedScope::alloc_iter
     /// [`try_alloc_iter_mut_rev`]: crate::traits::MutBumpAllocatorTypedScope::alloc_iter_mut_rev
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_iter_mut<T>(&mut self, iter: impl IntoIterator<Item = T>) -> Result<BumpBox<'a, [T]>, AllocError> {
         let iter = iter.into_iter();
         let capacity = iter.size_hint().0;
@@ -106,6 +108,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     /// [`alloc_iter_mut`]: crate::traits::MutBumpAllocatorTypedScope::alloc_iter_mut
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_iter_mut_rev<T>(&mut self, iter: impl IntoIterator<Item = T>) -> BumpBox<'a, [T]> {
         let iter = iter.into_iter();
         let capacity = iter.size_hint().0;
@@ -142,6 +145,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     ///
     /// [`try_alloc_iter_mut`]: crate::traits::MutBumpAllocatorTypedScope::try_alloc_iter_mut
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_iter_mut_rev<T>(&mut self, iter: impl IntoIterator<Item = T>) -> Result<BumpBox<'a, [T]>, AllocError> {
         let iter = iter.into_iter();
         let capacity = iter.size_hint().0;
@@ -182,6 +186,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     /// [`alloc_fmt`]: crate::traits::BumpAllocatorTypedScope::alloc_fmt
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_fmt_mut(&mut self, args: fmt::Arguments) -> BumpBox<'a, str> {
         if let Some(string) = args.as_str() {
             return self.alloc_str(string);
@@ -219,6 +224,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     ///
     /// [`try_alloc_fmt`]: crate::traits::BumpAllocatorTypedScope::try_alloc_fmt
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_fmt_mut(&mut self, args: fmt::Arguments) -> Result<BumpBox<'a, str>, AllocError> {
         if let Some(string) = args.as_str() {
             return self.try_alloc_str(string);
@@ -260,6 +266,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     /// [`alloc_cstr_fmt`]: crate::traits::BumpAllocatorTypedScope::alloc_cstr_fmt
     #[inline(always)]
     #[cfg(feature = "panic-on-alloc")]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn alloc_cstr_fmt_mut(&mut self, args: fmt::Arguments) -> &'a CStr {
         if let Some(string) = args.as_str() {
             return self.alloc_cstr_from_str(string);
@@ -301,6 +308,7 @@ pub trait MutBumpAllocatorTypedScope<'a>: MutBumpAllocatorCoreScope<'a> + MutBum
     ///
     /// [`try_alloc_cstr_fmt`]: crate::traits::BumpAllocatorTypedScope::try_alloc_cstr_fmt
     #[inline(always)]
+    #[cfg_attr(feature = "track-allocations", track_caller)]
     fn try_alloc_cstr_fmt_mut(&mut self, args: fmt::Arguments) -> Result<&'a CStr, AllocError> {
         if let Some(string) = args.as_str() {
             return self.try_alloc_cstr_from_str(string);
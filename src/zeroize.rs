@@ -0,0 +1,24 @@
+//! Volatile zeroing of freed memory for the `ZEROIZE` setting.
+//!
+//! The hooks for freed memory live in [`poison`](crate::poison), which calls into this module.
+
+use core::{
+    ptr::NonNull,
+    sync::atomic::{Ordering, compiler_fence},
+};
+
+/// Overwrites `len` bytes starting at `ptr` with zeroes.
+///
+/// The writes are volatile and followed by a compiler fence, so they are not
+/// optimized away even though the memory is never read again.
+///
+/// # Safety
+/// `ptr` must be valid for writes of `len` bytes.
+#[inline(never)]
+pub(crate) unsafe fn zero(ptr: NonNull<u8>, len: usize) {
+    for i in 0..len {
+        unsafe { ptr.add(i).write_volatile(0) };
+    }
+
+    compiler_fence(Ordering::SeqCst);
+}
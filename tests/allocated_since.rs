@@ -0,0 +1,164 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{
+    Bump, SyncBump,
+    alloc::Global,
+    settings::BumpSettings,
+    traits::{BumpAllocatorCore, BumpAllocatorTypedScope},
+};
+use common::either_way;
+
+either_way! {
+    same_chunk
+    previous_chunk
+    unallocated
+    ahead
+    foreign
+    pointers
+    pointers_in_previous_chunk
+    scoped
+    sync_bump
+}
+
+type TestBump<const UP: bool, const GUARANTEED_ALLOCATED: bool = true> =
+    Bump<Global, BumpSettings<1, UP, GUARANTEED_ALLOCATED>>;
+
+fn same_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+
+    let checkpoint = bump.checkpoint();
+    assert_eq!(bump.stats().allocated_since(checkpoint), Some(0));
+    assert_eq!(bump.stats().chunks_since(checkpoint), Some(0));
+
+    bump.alloc_str("world!");
+    assert_eq!(bump.stats().allocated_since(checkpoint), Some(6));
+    assert_eq!(bump.stats().chunks_since(checkpoint), Some(0));
+}
+
+fn previous_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+    bump.alloc_str("hello");
+
+    let checkpoint = bump.checkpoint();
+    let capacity = bump.stats().capacity();
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    bump.alloc_uninit_slice::<u8>(2000);
+
+    let stats = bump.stats();
+    assert_eq!(stats.count(), 3);
+    assert_eq!(stats.chunks_since(checkpoint), Some(2));
+
+    // the tails of the previous chunks are included
+    assert_eq!(stats.allocated_since(checkpoint), Some(stats.allocated() - 5));
+    assert!(stats.allocated_since(checkpoint).unwrap() > capacity);
+}
+
+fn unallocated<const UP: bool>() {
+    let bump = TestBump::<UP, false>::unallocated();
+    let checkpoint = bump.checkpoint();
+
+    assert_eq!(bump.stats().allocated_since(checkpoint), Some(0));
+    assert_eq!(bump.stats().chunks_since(checkpoint), Some(0));
+
+    bump.alloc_str("hello");
+    assert_eq!(bump.stats().allocated_since(checkpoint), Some(5));
+    assert_eq!(bump.stats().chunks_since(checkpoint), Some(1));
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    assert_eq!(bump.stats().allocated_since(checkpoint), Some(bump.stats().allocated()));
+    assert_eq!(bump.stats().chunks_since(checkpoint), Some(2));
+}
+
+fn ahead<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let start = bump.checkpoint();
+
+    bump.alloc_str("hello");
+    let same_chunk = bump.checkpoint();
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    let next_chunk = bump.checkpoint();
+
+    bump.reset_to_start();
+
+    assert_eq!(bump.stats().allocated_since(start), Some(0));
+    assert_eq!(bump.stats().allocated_since(same_chunk), None);
+    assert_eq!(bump.stats().allocated_since(next_chunk), None);
+    assert_eq!(bump.stats().chunks_since(next_chunk), None);
+}
+
+fn foreign<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let other = TestBump::<UP>::new();
+    let value = other.alloc(1u8);
+
+    let checkpoint = other.checkpoint();
+    assert_eq!(bump.stats().allocated_since(checkpoint), None);
+    assert_eq!(bump.stats().chunks_since(checkpoint), None);
+    assert!(!bump.stats().is_allocated_since(checkpoint, &*value));
+}
+
+fn pointers<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let before = bump.alloc(1u32);
+
+    let checkpoint = bump.checkpoint();
+    let after = bump.alloc_slice_copy(&[1u8, 2, 3]);
+
+    let stats = bump.stats();
+    assert!(!stats.is_allocated_since(checkpoint, &*before));
+    assert!(stats.is_allocated_since(checkpoint, &*after));
+    assert!(stats.is_allocated_since(checkpoint, &after[2]));
+
+    let elsewhere = 5u8;
+    assert!(!stats.is_allocated_since(checkpoint, &elsewhere));
+}
+
+fn pointers_in_previous_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+    let before = bump.alloc(1u8);
+
+    let checkpoint = bump.checkpoint();
+    let same_chunk = bump.alloc(2u8);
+    let next_chunk = bump.alloc_uninit_slice::<u8>(1000);
+
+    assert_eq!(bump.stats().count(), 2);
+
+    let stats = bump.stats();
+    assert!(!stats.is_allocated_since(checkpoint, &*before));
+    assert!(stats.is_allocated_since(checkpoint, &*same_chunk));
+    assert!(stats.is_allocated_since(checkpoint, next_chunk.as_ptr()));
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+    let checkpoint = bump.checkpoint();
+
+    bump.scoped(|bump| {
+        bump.alloc_slice_fill(10, 0u16);
+
+        // includes one byte of padding
+        assert_eq!(bump.stats().allocated_since(checkpoint), Some(21));
+    });
+
+    assert_eq!(bump.stats().allocated_since(checkpoint), Some(0));
+}
+
+fn sync_bump<const UP: bool>() {
+    let bump = SyncBump::<Global, BumpSettings<1, UP>>::with_size(512);
+    (&bump).alloc_str("hello");
+
+    let checkpoint = bump.checkpoint();
+    let value = (&bump).alloc(1u64);
+    (&bump).alloc_uninit_slice::<u8>(1000);
+
+    let stats = bump.stats();
+    assert_eq!(stats.chunks_since(checkpoint), Some(1));
+    assert_eq!(stats.allocated_since(checkpoint), Some(stats.allocated() - 5));
+    assert!(stats.is_allocated_since(checkpoint, &*value));
+}
@@ -0,0 +1,218 @@
+//! Run with `RUSTFLAGS=-Zsanitizer=address cargo +nightly test --features nightly-asan --target x86_64-unknown-linux-gnu --test asan`.
+#![cfg_attr(feature = "nightly-asan", feature(cfg_sanitize))]
+#![cfg(all(feature = "nightly-asan", feature = "std", feature = "panic-on-alloc"))]
+#![cfg(sanitize = "address")]
+
+mod common;
+
+use std::{alloc::Layout, ptr::NonNull};
+
+use bump_scope::{
+    Bump, BumpVec, MutBumpVec, SyncBump,
+    alloc::{Allocator, Global},
+    settings::{BumpAllocatorSettings, BumpSettings},
+    traits::BumpAllocatorTypedScope,
+};
+use common::either_way;
+
+either_way! {
+    new_chunk
+    alloc
+    reset
+    reset_to
+    scoped
+    previous_chunk
+    deallocate
+    shrink
+    grow
+    mut_bump_vec
+    red_zones
+    sync_bump
+}
+
+type TestBump<const UP: bool> = Bump<Global, BumpSettings<8, UP>>;
+
+unsafe extern "C" {
+    fn __asan_address_is_poisoned(addr: *const u8) -> i32;
+}
+
+fn is_poisoned(ptr: NonNull<u8>, len: usize) -> bool {
+    (0..len).all(|i| unsafe { __asan_address_is_poisoned(ptr.as_ptr().add(i)) } != 0)
+}
+
+fn is_unpoisoned(ptr: NonNull<u8>, len: usize) -> bool {
+    (0..len).all(|i| unsafe { __asan_address_is_poisoned(ptr.as_ptr().add(i)) } == 0)
+}
+
+/// Returns the 8 bytes after an allocation in the direction of bumping.
+fn next<const UP: bool, T: ?Sized>(value: &T) -> NonNull<u8> {
+    let ptr = NonNull::from(value).cast::<u8>();
+
+    if UP {
+        unsafe { ptr.add(size_of_val(value)) }
+    } else {
+        unsafe { ptr.sub(8) }
+    }
+}
+
+fn new_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let value = bump.alloc(1u64);
+
+    assert!(is_unpoisoned(NonNull::from(&*value).cast(), 8));
+    assert!(is_poisoned(next::<UP, _>(&*value), 8));
+}
+
+fn alloc<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let first = NonNull::from(&*bump.alloc(1u64)).cast::<u8>();
+    let second = bump.alloc_slice_copy(&[2u64; 4]);
+
+    assert!(is_unpoisoned(first, 8));
+    assert!(is_unpoisoned(NonNull::from(&*second).cast(), 32));
+    assert!(is_poisoned(next::<UP, _>(&*second), 8));
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let ptr = NonNull::from(&*bump.alloc_slice_copy(&[1u64; 4])).cast::<u8>();
+    assert!(is_unpoisoned(ptr, 32));
+
+    bump.reset();
+    assert!(is_poisoned(ptr, 32));
+
+    let again = NonNull::from(&*bump.alloc_slice_copy(&[2u64; 4])).cast::<u8>();
+    assert_eq!(again, ptr);
+    assert!(is_unpoisoned(ptr, 32));
+}
+
+fn reset_to<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let before = NonNull::from(&*bump.alloc(1u64)).cast::<u8>();
+
+    let checkpoint = bump.checkpoint();
+    let after = NonNull::from(&*bump.alloc(2u64)).cast::<u8>();
+
+    unsafe { bump.reset_to(checkpoint) };
+    assert!(is_unpoisoned(before, 8));
+    assert!(is_poisoned(after, 8));
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let before = NonNull::from(&*bump.alloc(1u64)).cast::<u8>();
+
+    let inside = bump.scoped(|bump| NonNull::from(&*bump.alloc(2u64)).cast::<u8>());
+
+    assert!(is_unpoisoned(before, 8));
+    assert!(is_poisoned(inside, 8));
+}
+
+fn previous_chunk<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let first = NonNull::from(&*bump.alloc_slice_copy(&[1u64; 8])).cast::<u8>();
+    let second = NonNull::from(&*bump.alloc_slice_fill(1000, 2u8)).cast::<u8>();
+    assert_eq!(bump.stats().count(), 2);
+
+    bump.reset();
+    assert!(is_poisoned(first, 64));
+    assert!(is_poisoned(second, 1000));
+}
+
+fn deallocate<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let layout = Layout::new::<[u64; 2]>();
+
+    let first = bump.allocate(layout).unwrap().cast::<u8>();
+    let second = bump.allocate(layout).unwrap().cast::<u8>();
+
+    unsafe { bump.deallocate(first, layout) };
+    assert!(is_poisoned(first, 16));
+    assert!(is_unpoisoned(second, 16));
+
+    unsafe { bump.deallocate(second, layout) };
+    assert!(is_poisoned(second, 16));
+}
+
+fn shrink<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let old_layout = Layout::new::<[u64; 4]>();
+    let new_layout = Layout::new::<[u64; 2]>();
+
+    let ptr = bump.allocate(old_layout).unwrap().cast::<u8>();
+    let new = unsafe { bump.shrink(ptr, old_layout, new_layout) }.unwrap().cast::<u8>();
+    assert!(is_unpoisoned(new, 16));
+
+    if UP {
+        assert!(is_poisoned(unsafe { ptr.add(16) }, 16));
+    } else {
+        assert!(is_poisoned(ptr, 16));
+    }
+}
+
+fn grow<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let old_layout = Layout::new::<[u64; 2]>();
+    let new_layout = Layout::new::<[u64; 4]>();
+
+    let ptr = bump.allocate(old_layout).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 16) };
+
+    let new = unsafe { bump.grow(ptr, old_layout, new_layout) }.unwrap().cast::<u8>();
+    assert!(is_unpoisoned(new, 32));
+    assert_eq!(unsafe { core::slice::from_raw_parts(new.as_ptr(), 16) }, [1; 16]);
+
+    let mut vec = BumpVec::new_in(&bump);
+    vec.extend_from_slice_copy(&[1u64; 2]);
+    bump.alloc(0u64);
+    vec.extend_from_slice_copy(&[2u64; 8]);
+
+    assert!(is_unpoisoned(NonNull::from(vec.as_slice()).cast(), 80));
+}
+
+fn mut_bump_vec<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    let mut vec = MutBumpVec::new_in(&mut bump);
+    vec.extend_from_slice_copy(&[1u64; 3]);
+    let slice = vec.into_slice();
+
+    assert!(is_unpoisoned(NonNull::from(&*slice).cast(), 24));
+    assert!(is_poisoned(next::<UP, _>(&*slice), 8));
+}
+
+fn red_zones<const UP: bool>() {
+    type Settings<const UP: bool> = <BumpSettings<8, UP> as BumpAllocatorSettings>::WithRedZones<true>;
+
+    let mut bump = Bump::<Global, Settings<UP>>::new();
+    let value = NonNull::from(&*bump.alloc(1u64)).cast::<u8>();
+
+    let mut vec = MutBumpVec::new_in(&mut bump);
+    vec.extend_from_slice_copy(&[2u64; 3]);
+    let slice = NonNull::from(vec.into_slice()).cast::<u8>();
+    assert!(is_unpoisoned(slice, 24));
+
+    let layout = Layout::new::<[u64; 4]>();
+    let ptr = bump.allocate(layout).unwrap().cast::<u8>();
+    let new = unsafe { bump.shrink(ptr, layout, Layout::new::<[u64; 1]>()) }
+        .unwrap()
+        .cast::<u8>();
+    assert!(is_unpoisoned(new, 8));
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    bump.reset();
+    assert!(is_poisoned(value, 8));
+    assert!(is_poisoned(slice, 24));
+}
+
+fn sync_bump<const UP: bool>() {
+    let mut bump = SyncBump::<Global, BumpSettings<8, UP>>::new();
+    let value = (&bump).alloc(1u64).into_ref();
+    let ptr = NonNull::from(value).cast::<u8>();
+
+    assert!(is_unpoisoned(ptr, 8));
+    assert!(is_poisoned(next::<UP, _>(value), 8));
+
+    bump.reset();
+    assert!(is_poisoned(ptr, 8));
+}
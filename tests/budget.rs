@@ -0,0 +1,211 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{alloc::Layout, vec::Vec};
+
+use bump_scope::{
+    Bump,
+    alloc::{AllocError, Allocator, AtomicBudget, Budget, CellBudget, Global, Limited},
+    settings::BumpSettings,
+};
+use common::either_way;
+
+either_way! {
+    bump_limit
+    bump_nested
+    bump_release_on_reset
+}
+
+type TestBump<'b, const UP: bool, B> = Bump<Limited<&'b B, Global>, BumpSettings<1, UP>>;
+
+#[test]
+fn cell_budget() {
+    let budget = CellBudget::new(100);
+
+    budget.try_reserve(60).unwrap();
+    assert_eq!(budget.try_reserve(41), Err(AllocError));
+    budget.try_reserve(40).unwrap();
+    assert_eq!(budget.used(), 100);
+    assert_eq!(budget.remaining(), 0);
+
+    budget.release(50);
+    assert_eq!(budget.used(), 50);
+    assert_eq!(budget.remaining(), 50);
+    assert_eq!(budget.try_reserve(usize::MAX), Err(AllocError));
+}
+
+#[test]
+fn atomic_budget() {
+    let budget = AtomicBudget::new(100);
+
+    budget.try_reserve(60).unwrap();
+    assert_eq!(budget.try_reserve(41), Err(AllocError));
+    budget.try_reserve(40).unwrap();
+    assert_eq!(budget.remaining(), 0);
+
+    budget.release(100);
+    assert_eq!(budget.used(), 0);
+    assert_eq!(budget.try_reserve(usize::MAX), Err(AllocError));
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "released more bytes than are used")]
+fn cell_budget_over_release() {
+    let budget = CellBudget::new(100);
+    budget.try_reserve(10).unwrap();
+    budget.release(20);
+    assert_eq!(budget.used(), 0);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic = "released more bytes than are used")]
+fn atomic_budget_over_release() {
+    let budget = AtomicBudget::new(100);
+    budget.try_reserve(10).unwrap();
+    budget.release(20);
+    assert_eq!(budget.used(), 0);
+}
+
+#[test]
+fn parent_rejects() {
+    let tenant = AtomicBudget::new(100);
+    let first = AtomicBudget::with_parent(80, &tenant);
+    let second = CellBudget::with_parent(80, &tenant);
+
+    first.try_reserve(60).unwrap();
+    assert_eq!(second.remaining(), 40);
+
+    // the child has room but the parent doesn't, so nothing is reserved
+    assert_eq!(second.try_reserve(50), Err(AllocError));
+    assert_eq!(second.used(), 0);
+    assert_eq!(tenant.used(), 60);
+
+    second.try_reserve(40).unwrap();
+    assert_eq!(tenant.used(), 100);
+
+    first.release(60);
+    second.release(40);
+    assert_eq!(tenant.used(), 0);
+}
+
+#[test]
+fn nested_three_levels() {
+    let global = CellBudget::new(1000);
+    let tenant = CellBudget::with_parent(500, &global);
+    let request = CellBudget::with_parent(200, &tenant);
+
+    request.try_reserve(150).unwrap();
+    assert_eq!((global.used(), tenant.used(), request.used()), (150, 150, 150));
+
+    global.try_reserve(800).unwrap();
+    assert_eq!(request.remaining(), 50);
+    assert_eq!(request.try_reserve(51), Err(AllocError));
+    assert_eq!((global.used(), tenant.used(), request.used()), (950, 150, 150));
+}
+
+#[test]
+fn threads() {
+    const THREADS: usize = 8;
+    const ITERATIONS: usize = if cfg!(miri) { 10 } else { 1000 };
+
+    let tenant = AtomicBudget::new(THREADS * 10);
+
+    std::thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                let request = AtomicBudget::with_parent(20, &tenant);
+
+                for _ in 0..ITERATIONS {
+                    if request.try_reserve(15).is_ok() {
+                        assert!(tenant.used() <= tenant.limit());
+                        request.release(15);
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(tenant.used(), 0);
+}
+
+#[test]
+fn limited_allocator() {
+    let budget = CellBudget::new(64);
+    let allocator = Limited::new_in(&budget, Global);
+
+    let layout = Layout::new::<[u8; 16]>();
+    let ptr = allocator.allocate(layout).unwrap().cast::<u8>();
+    assert_eq!(budget.used(), 16);
+
+    let big = Layout::new::<[u8; 48]>();
+    let ptr = unsafe { allocator.grow(ptr, layout, big) }.unwrap().cast::<u8>();
+    assert_eq!(budget.used(), 48);
+
+    let too_big = Layout::new::<[u8; 128]>();
+    assert!(unsafe { allocator.grow_zeroed(ptr, big, too_big) }.is_err());
+    assert_eq!(budget.used(), 48);
+    assert!(allocator.allocate_zeroed(Layout::new::<[u8; 17]>()).is_err());
+
+    let ptr = unsafe { allocator.shrink(ptr, big, layout) }.unwrap().cast::<u8>();
+    assert_eq!(budget.used(), 16);
+
+    unsafe { allocator.deallocate(ptr, layout) };
+    assert_eq!(budget.used(), 0);
+}
+
+fn bump_limit<const UP: bool>() {
+    let budget = CellBudget::new(1024);
+    let bump: TestBump<UP, _> = Bump::with_size_in(1024, Limited::new_in(&budget, Global));
+    assert_eq!(budget.used(), bump.stats().current_chunk().unwrap().size());
+
+    bump.alloc_uninit_slice::<u8>(bump.stats().remaining());
+    assert!(bump.try_alloc(0u8).is_err());
+    assert_eq!(bump.stats().count(), 1);
+
+    drop(bump);
+    assert_eq!(budget.used(), 0);
+}
+
+fn bump_nested<const UP: bool>() {
+    let tenant = AtomicBudget::new(8192);
+
+    let requests = (0..2).map(|_| CellBudget::with_parent(4096, &tenant)).collect::<Vec<_>>();
+    let bumps = requests
+        .iter()
+        .map(|request| -> TestBump<UP, _> { Bump::with_size_in(1024, Limited::new_in(request, Global)) })
+        .collect::<Vec<_>>();
+
+    for bump in &bumps {
+        bump.alloc_slice_fill(1500, 0u8);
+    }
+
+    let used = requests.iter().map(CellBudget::used).sum::<usize>();
+    assert_eq!(tenant.used(), used);
+    assert_eq!(
+        used,
+        bumps
+            .iter()
+            .flat_map(|bump| bump.stats().small_to_big())
+            .map(|chunk| chunk.size())
+            .sum::<usize>()
+    );
+
+    // each request is capped by its own budget
+    assert!(bumps[0].try_alloc_slice_fill(4096, 0u8).is_err());
+
+    drop(bumps);
+    assert_eq!(tenant.used(), 0);
+}
+
+fn bump_release_on_reset<const UP: bool>() {
+    let budget = AtomicBudget::new(1 << 16);
+    let mut bump: TestBump<UP, _> = Bump::with_size_in(512, Limited::new_in(&budget, Global));
+
+    bump.alloc_slice_fill(4000, 0u8);
+    let first_chunk = bump.stats().small_to_big().next().unwrap().size();
+    assert!(budget.used() > first_chunk);
+
+    bump.reset();
+    assert_eq!(budget.used(), bump.stats().size());
+}
@@ -0,0 +1,103 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::vec::Vec;
+
+use bump_scope::{
+    Bump,
+    alloc::Global,
+    settings::{BumpAllocatorSettings, BumpSettings, True},
+};
+use common::{MALLOC_OVERHEAD, either_way};
+
+either_way! {
+    default_growth
+    linear_growth
+    maximum_chunk_size
+    maximum_chunk_size_big_allocation
+}
+
+type GrowthBump<
+    const UP: bool,
+    const MINIMUM_CHUNK_SIZE: usize,
+    const MAXIMUM_CHUNK_SIZE: usize,
+    const CHUNK_GROWTH_FACTOR: usize,
+    const CHUNK_GROWTH_STEP: usize,
+> = Bump<
+    Global,
+    BumpSettings<
+        1,
+        UP,
+        true,
+        true,
+        true,
+        true,
+        MINIMUM_CHUNK_SIZE,
+        MAXIMUM_CHUNK_SIZE,
+        CHUNK_GROWTH_FACTOR,
+        CHUNK_GROWTH_STEP,
+    >,
+>;
+
+/// Fills the current chunk and then allocates a single byte so a new chunk needs to be allocated.
+fn grow<S: BumpAllocatorSettings<GuaranteedAllocated = True>>(bump: &Bump<Global, S>) {
+    let remaining = bump.stats().current_chunk().unwrap().remaining();
+    bump.alloc_uninit_slice::<u8>(remaining);
+    bump.alloc_uninit::<u8>();
+}
+
+fn chunk_sizes<S: BumpAllocatorSettings>(bump: &Bump<Global, S>) -> Vec<usize> {
+    bump.stats().small_to_big().map(|chunk| chunk.size()).collect()
+}
+
+fn default_growth<const UP: bool>() {
+    let bump: GrowthBump<UP, 512, { usize::MAX }, 2, 0> = Bump::new();
+
+    for _ in 0..3 {
+        grow(&bump);
+    }
+
+    assert_eq!(chunk_sizes(&bump), [512, 1024, 2048, 4096].map(|size| size - MALLOC_OVERHEAD));
+}
+
+fn linear_growth<const UP: bool>() {
+    let bump: GrowthBump<UP, 4096, { usize::MAX }, 1, 4096> = Bump::new();
+
+    for _ in 0..3 {
+        grow(&bump);
+    }
+
+    assert_eq!(
+        chunk_sizes(&bump),
+        [4096, 8192, 12288, 16384].map(|size| size - MALLOC_OVERHEAD)
+    );
+}
+
+fn maximum_chunk_size<const UP: bool>() {
+    let bump: GrowthBump<UP, 512, 2048, 2, 0> = Bump::new();
+
+    for _ in 0..4 {
+        grow(&bump);
+    }
+
+    assert_eq!(
+        chunk_sizes(&bump),
+        [512, 1024, 2048, 2048, 2048].map(|size| size - MALLOC_OVERHEAD)
+    );
+}
+
+fn maximum_chunk_size_big_allocation<const UP: bool>() {
+    let bump: GrowthBump<UP, 512, 2048, 2, 0> = Bump::new();
+
+    bump.alloc_uninit_slice::<u8>(10_000);
+    assert_eq!(bump.stats().count(), 2);
+
+    let big_chunk = bump.stats().current_chunk().unwrap();
+    assert!(big_chunk.capacity() >= 10_000);
+    assert!(big_chunk.size() < 16 * 1024);
+
+    // the chunk after a big chunk is capped again
+    grow(&bump);
+    assert_eq!(bump.stats().current_chunk().unwrap().size(), 2048 - MALLOC_OVERHEAD);
+}
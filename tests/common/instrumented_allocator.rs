@@ -1,6 +1,6 @@
 use std::{
     alloc::Layout,
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     collections::HashMap,
     panic::{RefUnwindSafe, UnwindSafe},
     ptr::NonNull,
@@ -12,6 +12,7 @@ use bump_scope::alloc::{AllocError, Allocator};
 pub(crate) struct InstrumentedAllocator<A: Allocator> {
     allocator: A,
     allocations: RefCell<HashMap<NonNull<u8>, Layout>>,
+    allocation_count: Cell<usize>,
 }
 
 impl<A: Allocator> UnwindSafe for InstrumentedAllocator<A> where A: UnwindSafe {}
@@ -22,12 +23,18 @@ impl<A: Allocator> InstrumentedAllocator<A> {
         Self {
             allocator,
             allocations: Default::default(),
+            allocation_count: Cell::new(0),
         }
     }
 
     pub(crate) fn leaks(&self) -> Ref<'_, HashMap<NonNull<u8>, Layout>> {
         self.allocations.borrow()
     }
+
+    /// Returns how often `allocate` was called.
+    pub(crate) fn allocation_count(&self) -> usize {
+        self.allocation_count.get()
+    }
 }
 
 impl<A: Allocator> Drop for InstrumentedAllocator<A> {
@@ -40,6 +47,7 @@ impl<A: Allocator> Drop for InstrumentedAllocator<A> {
 
 unsafe impl<A: Allocator> Allocator for InstrumentedAllocator<A> {
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.allocation_count.set(self.allocation_count.get() + 1);
         let ptr = self.allocator.allocate(layout)?.cast();
         self.allocations.borrow_mut().insert(ptr, layout);
         // don't return a bigger slice than requested
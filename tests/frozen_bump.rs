@@ -0,0 +1,119 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::vec::Vec;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use bump_scope::{Bump, BumpBox, FrozenBump, alloc::Global, settings::BumpSettings};
+use common::{InstrumentedAllocator, either_way};
+
+either_way! {
+    threads
+    rayon
+    contains
+    zero_sized
+    thaw
+    deallocates
+}
+
+type TestBump<const UP: bool> = Bump<Global, BumpSettings<1, UP>>;
+
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+fn threads<const UP: bool>() {
+    const THREADS: usize = 4;
+
+    let bump = TestBump::<UP>::with_size(512);
+    let strings = (0..100)
+        .map(|i| BumpBox::into_raw(bump.alloc_fmt(format_args!("string {i}"))))
+        .collect::<Vec<_>>();
+    assert!(bump.stats().count() > 1);
+
+    let frozen = bump.freeze();
+    assert_send_sync(&frozen);
+
+    let strings = strings.into_iter().map(|s| unsafe { frozen.get(s) }).collect::<Vec<&str>>();
+
+    let lengths = std::thread::scope(|s| {
+        let handles = (0..THREADS)
+            .map(|_| {
+                let frozen = &frozen;
+                let strings = &strings;
+                s.spawn(move || {
+                    assert!(strings.iter().all(|&s| frozen.contains(s)));
+                    strings.iter().map(|s| s.len()).sum::<usize>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+    });
+
+    assert_eq!(lengths, [strings.iter().map(|s| s.len()).sum::<usize>(); THREADS]);
+    assert_eq!(strings[42], "string 42");
+}
+
+fn rayon<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let values = BumpBox::into_raw(bump.alloc_iter(0..1000u64));
+
+    let frozen: FrozenBump<Global, _> = bump.freeze();
+    let values = unsafe { frozen.get(values) };
+
+    let sum: u64 = values.par_iter().copied().sum();
+    assert_eq!(sum, (0..1000).sum());
+}
+
+fn contains<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+    let first = BumpBox::into_raw(bump.alloc(1u32));
+    let second = BumpBox::into_raw(bump.alloc_slice_fill(1000, 2u8));
+    let outside = 3u32;
+
+    let frozen = bump.freeze();
+
+    assert!(frozen.contains(first.as_ptr()));
+    assert!(frozen.contains(second.as_ptr()));
+    assert!(!frozen.contains(&outside));
+    assert_eq!(unsafe { *frozen.get(first) }, 1);
+}
+
+fn zero_sized<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let empty = BumpBox::into_raw(bump.alloc_slice_copy::<u32>(&[]));
+
+    let frozen = bump.freeze();
+    assert_eq!(unsafe { frozen.get(empty) }, &[] as &[u32]);
+}
+
+fn thaw<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let before = BumpBox::into_raw(bump.alloc_str("hello"));
+    let allocated = bump.stats().allocated();
+
+    let frozen = bump.freeze();
+    assert_eq!(frozen.stats().allocated(), allocated);
+    assert!(frozen.allocator().is_some());
+
+    let bump = frozen.thaw();
+    bump.alloc_str("world");
+
+    assert_eq!(unsafe { before.as_ref() }, "hello");
+    assert_eq!(bump.stats().allocated(), allocated + 5);
+}
+
+fn deallocates<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = Bump::<_, BumpSettings<1, UP>>::with_size_in(512, &allocator);
+
+    for _ in 0..2 {
+        bump.alloc_uninit_slice::<u8>(bump.stats().remaining() + 1);
+    }
+
+    let frozen = bump.freeze();
+    assert_eq!(allocator.leaks().len(), 3);
+    drop(frozen);
+    assert_eq!(allocator.leaks().len(), 0);
+}
@@ -0,0 +1,183 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{
+    Bump, MutBumpVec, SyncBump,
+    alloc::Global,
+    settings::{BumpAllocatorSettings, BumpSettings},
+    traits::{BumpAllocatorTyped, BumpAllocatorTypedScope},
+};
+use common::{InstrumentedAllocator, either_way};
+
+either_way! {
+    fixed_capacity
+    reuses_reserved_chunks
+    scopes
+    borrow
+    reserve
+    unallocated
+    mut_bump_vec
+    sync_bump
+    reset
+    reset_to_fit
+    reserve_chunks
+}
+
+type Settings<const UP: bool, const GROWS: bool = false> = <BumpSettings<1, UP> as BumpAllocatorSettings>::WithGrows<GROWS>;
+
+fn fixed_capacity<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = Bump::<_, Settings<UP>>::with_size_in(512, &allocator);
+    assert_eq!(allocator.leaks().len(), 1);
+
+    bump.alloc_uninit_slice::<u8>(bump.stats().remaining());
+    assert!(bump.try_alloc(0u8).is_err());
+    assert!(bump.try_alloc_uninit_slice::<u8>(4096).is_err());
+
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(allocator.leaks().len(), 1);
+}
+
+fn reuses_reserved_chunks<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = Bump::<_, Settings<UP, true>>::with_size_in(512, &allocator);
+    bump.reserve(4000);
+
+    let chunks = allocator.leaks().len();
+    assert!(chunks > 1);
+
+    let bump: Bump<_, Settings<UP>> = bump.with_settings();
+
+    for _ in 0..3 {
+        bump.alloc_uninit_slice::<u8>(1000);
+    }
+
+    assert!(bump.try_alloc_uninit_slice::<u8>(1 << 20).is_err());
+    assert_eq!(allocator.leaks().len(), chunks);
+}
+
+fn scopes<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut bump = Bump::<_, Settings<UP>>::with_size_in(1024, &allocator);
+    let remaining = bump.stats().remaining();
+
+    for _ in 0..10 {
+        bump.scoped(|bump| {
+            bump.alloc_uninit_slice::<u8>(remaining);
+            assert!(bump.try_alloc(0u8).is_err());
+        });
+    }
+
+    assert_eq!(allocator.leaks().len(), 1);
+}
+
+fn borrow<const UP: bool>() {
+    let mut bump = Bump::<Global, Settings<UP, true>>::with_size(512);
+
+    {
+        let locked = bump.borrow_mut_with_settings::<Settings<UP>>();
+        locked.alloc_uninit_slice::<u8>(locked.stats().remaining());
+        assert!(locked.try_alloc(0u8).is_err());
+    }
+
+    bump.alloc(0u8);
+    assert_eq!(bump.stats().count(), 2);
+}
+
+fn reserve<const UP: bool>() {
+    let bump = Bump::<Global, Settings<UP>>::with_size(512);
+    let remaining = bump.stats().remaining();
+
+    assert!(bump.try_reserve(remaining).is_ok());
+    assert!(bump.try_reserve(remaining + 1).is_err());
+    assert_eq!(bump.stats().count(), 1);
+}
+
+fn unallocated<const UP: bool>() {
+    type UnallocatedSettings<const UP: bool> = <BumpSettings<1, UP, false> as BumpAllocatorSettings>::WithGrows<false>;
+
+    let bump = Bump::<Global, UnallocatedSettings<UP>>::unallocated();
+
+    assert!(bump.try_alloc(0u8).is_err());
+    assert!(bump.try_reserve(1).is_err());
+    assert_eq!(bump.stats().size(), 0);
+}
+
+fn mut_bump_vec<const UP: bool>() {
+    let mut bump = Bump::<Global, Settings<UP>>::with_size(512);
+    let remaining = bump.stats().remaining();
+
+    let mut vec = MutBumpVec::<u8, _>::new_in(&mut bump);
+    assert!(vec.try_reserve(remaining).is_ok());
+    assert!(vec.try_reserve(remaining + 1).is_err());
+
+    while vec.try_push(1).is_ok() {}
+    assert_eq!(vec.len(), remaining);
+}
+
+fn sync_bump<const UP: bool>() {
+    let bump = SyncBump::<Global, Settings<UP>>::with_size(512);
+    let bump = &bump;
+
+    bump.alloc_uninit_slice::<u8>(bump.stats().remaining());
+    assert!(bump.try_alloc(0u8).is_err());
+    assert!(bump.try_reserve(1).is_err());
+    assert_eq!(bump.stats().count(), 1);
+}
+
+fn reset<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = Bump::<_, Settings<UP, true>>::with_size_in(512, &allocator);
+    bump.reserve_chunks(&[1024, 2048]);
+
+    let mut bump: Bump<_, Settings<UP>> = bump.with_settings();
+    let capacity = bump.stats().capacity();
+
+    bump.alloc_uninit_slice::<u8>(3000);
+    bump.reset();
+
+    // the chunks are kept since they couldn't be allocated again
+    assert_eq!(bump.stats().count(), 3);
+    assert_eq!(bump.stats().allocated(), 0);
+    assert_eq!(bump.stats().capacity(), capacity);
+    assert_eq!(allocator.allocation_count(), 3);
+}
+
+fn reset_to_fit<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = Bump::<_, Settings<UP, true>>::with_size_in(512, &allocator);
+    bump.reserve_chunks(&[1024, 2048]);
+
+    let mut bump: Bump<_, Settings<UP>> = bump.with_settings();
+
+    for _ in 0..3 {
+        // this doesn't fit into the biggest chunk
+        bump.alloc_uninit_slice::<u8>(400);
+        bump.alloc_uninit_slice::<u8>(1000);
+        bump.alloc_uninit_slice::<u8>(2000);
+
+        bump.reset_to_fit();
+        assert_eq!(bump.stats().count(), 3);
+        assert_eq!(bump.stats().allocated(), 0);
+    }
+
+    assert_eq!(allocator.allocation_count(), 3);
+    assert_eq!(allocator.leaks().len(), 3);
+}
+
+fn reserve_chunks<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = Bump::<_, Settings<UP, true>>::with_size_in(512, &allocator);
+    bump.reserve_chunks(&[1024]);
+
+    let bump: Bump<_, Settings<UP>> = bump.with_settings();
+
+    assert!(bump.try_reserve_chunks(&[]).is_ok());
+    assert!(bump.try_reserve_chunks(&[1]).is_err());
+    assert!(bump.try_reserve_chunks(&[4096]).is_err());
+    assert!(bump.try_reserve(1 << 20).is_err());
+
+    assert_eq!(bump.stats().count(), 2);
+    assert_eq!(allocator.allocation_count(), 2);
+}
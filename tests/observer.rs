@@ -0,0 +1,297 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{cell::RefCell, vec::Vec};
+
+use bump_scope::{
+    Bump, BumpPool,
+    alloc::Global,
+    settings::{BumpObserver, BumpSettings},
+    stats::AnyStats,
+    traits::BumpAllocatorTypedScope,
+};
+use common::either_way;
+
+either_way! {
+    allocate_and_drop
+    reset
+    reset_to_start
+    reset_to_fit
+    trim
+    scoped
+    pool_scoped
+    unallocated
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    ChunkAllocated { chunk_size: usize, stats_size: usize },
+    ChunkDeallocated { chunk_size: usize, stats_size: usize },
+    Reset { stats_size: usize },
+    ScopeExit { allocated: usize },
+}
+
+thread_local! {
+    static EVENTS: RefCell<Vec<Event>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_events() -> Vec<Event> {
+    EVENTS.with_borrow_mut(core::mem::take)
+}
+
+fn push(event: Event) {
+    EVENTS.with_borrow_mut(|events| events.push(event));
+}
+
+struct Recorder;
+
+impl BumpObserver for Recorder {
+    fn on_chunk_allocated(chunk_size: usize, stats: AnyStats<'_>) {
+        push(Event::ChunkAllocated {
+            chunk_size,
+            stats_size: stats.size(),
+        });
+    }
+
+    fn on_chunk_deallocated(chunk_size: usize, stats: AnyStats<'_>) {
+        push(Event::ChunkDeallocated {
+            chunk_size,
+            stats_size: stats.size(),
+        });
+    }
+
+    fn on_reset(stats: AnyStats<'_>) {
+        push(Event::Reset {
+            stats_size: stats.size(),
+        });
+    }
+
+    fn on_scope_exit(stats: AnyStats<'_>) {
+        push(Event::ScopeExit {
+            allocated: stats.allocated(),
+        });
+    }
+}
+
+type ObservedSettings<const UP: bool, const GUARANTEED_ALLOCATED: bool = true> = BumpSettings<
+    1,
+    UP,
+    GUARANTEED_ALLOCATED,
+    true,
+    true,
+    true,
+    512,
+    { usize::MAX },
+    2,
+    0,
+    false,
+    false,
+    false,
+    false,
+    false,
+    true,
+    Recorder,
+>;
+
+type ObservedBump<const UP: bool> = Bump<Global, ObservedSettings<UP>>;
+
+fn chunk_sizes<A, const UP: bool>(bump: &Bump<A, ObservedSettings<UP>>) -> Vec<usize>
+where
+    A: bump_scope::alloc::Allocator,
+{
+    bump.stats().small_to_big().map(|chunk| chunk.size()).collect()
+}
+
+fn allocate_and_drop<const UP: bool>() {
+    let bump = ObservedBump::<UP>::new();
+    let first = bump.stats().size();
+
+    assert_eq!(
+        take_events(),
+        [Event::ChunkAllocated {
+            chunk_size: first,
+            stats_size: first
+        }]
+    );
+
+    bump.alloc_uninit_slice::<u8>(first * 2);
+    let sizes = chunk_sizes(&bump);
+    assert_eq!(sizes.len(), 2);
+
+    assert_eq!(
+        take_events(),
+        [Event::ChunkAllocated {
+            chunk_size: sizes[1],
+            stats_size: sizes[0] + sizes[1]
+        }]
+    );
+
+    drop(bump);
+
+    let events = take_events();
+    assert_eq!(events.len(), 2);
+    assert!(
+        events
+            .iter()
+            .all(|event| matches!(event, Event::ChunkDeallocated { stats_size: 0, .. }))
+    );
+
+    let deallocated: usize = events
+        .iter()
+        .map(|event| match event {
+            Event::ChunkDeallocated { chunk_size, .. } => *chunk_size,
+            _ => unreachable!(),
+        })
+        .sum();
+
+    assert_eq!(deallocated, sizes.iter().sum::<usize>());
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = ObservedBump::<UP>::new();
+    bump.alloc_uninit_slice::<u8>(1000);
+    bump.alloc_uninit_slice::<u8>(2000);
+
+    let sizes = chunk_sizes(&bump);
+    assert_eq!(sizes.len(), 3);
+    take_events();
+
+    bump.reset();
+
+    assert_eq!(
+        take_events(),
+        [
+            Event::ChunkDeallocated {
+                chunk_size: sizes[0],
+                stats_size: sizes[2]
+            },
+            Event::ChunkDeallocated {
+                chunk_size: sizes[1],
+                stats_size: sizes[2]
+            },
+            Event::Reset { stats_size: sizes[2] },
+        ]
+    );
+
+    assert_eq!(chunk_sizes(&bump), [sizes[2]]);
+}
+
+fn reset_to_start<const UP: bool>() {
+    let mut bump = ObservedBump::<UP>::new();
+    bump.alloc_uninit_slice::<u8>(1000);
+
+    let size = bump.stats().size();
+    take_events();
+
+    bump.reset_to_start();
+
+    assert_eq!(take_events(), [Event::Reset { stats_size: size }]);
+}
+
+fn reset_to_fit<const UP: bool>() {
+    let mut bump = ObservedBump::<UP>::new();
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    let sizes = chunk_sizes(&bump);
+    assert_eq!(sizes.len(), 2);
+    take_events();
+
+    bump.reset_to_fit();
+
+    let new_size = bump.stats().size();
+    assert_eq!(bump.stats().count(), 1);
+
+    assert_eq!(
+        take_events(),
+        [
+            Event::ChunkAllocated {
+                chunk_size: new_size,
+                stats_size: new_size
+            },
+            Event::ChunkDeallocated {
+                chunk_size: sizes[0],
+                stats_size: new_size
+            },
+            Event::ChunkDeallocated {
+                chunk_size: sizes[1],
+                stats_size: new_size
+            },
+            Event::Reset { stats_size: new_size },
+        ]
+    );
+}
+
+fn trim<const UP: bool>() {
+    let mut bump = ObservedBump::<UP>::new();
+    bump.alloc_uninit_slice::<u8>(1000);
+
+    let sizes = chunk_sizes(&bump);
+    assert_eq!(sizes.len(), 2);
+
+    bump.reset_to_start();
+    take_events();
+
+    assert_eq!(bump.trim(), sizes[1]);
+
+    assert_eq!(
+        take_events(),
+        [Event::ChunkDeallocated {
+            chunk_size: sizes[1],
+            stats_size: sizes[0]
+        }]
+    );
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = ObservedBump::<UP>::new();
+    bump.alloc(1u8);
+    take_events();
+
+    bump.scoped(|bump| {
+        bump.alloc(2u8);
+
+        bump.scoped(|bump| {
+            bump.alloc(3u8);
+        });
+
+        assert_eq!(take_events(), [Event::ScopeExit { allocated: 2 }]);
+    });
+
+    assert_eq!(take_events(), [Event::ScopeExit { allocated: 1 }]);
+
+    let mut guard = bump.scope_guard();
+    guard.scope().alloc(4u8);
+    guard.reset();
+    assert_eq!(take_events(), []);
+
+    drop(guard);
+    assert_eq!(take_events(), [Event::ScopeExit { allocated: 1 }]);
+}
+
+fn pool_scoped<const UP: bool>() {
+    let pool = BumpPool::<Global, ObservedSettings<UP>>::new();
+
+    {
+        let mut guard = pool.get_scoped();
+        guard.scope().alloc(1u32);
+    }
+
+    let events = take_events();
+    assert!(matches!(
+        events[..],
+        [Event::ChunkAllocated { .. }, Event::ScopeExit { allocated: 0 }]
+    ));
+}
+
+fn unallocated<const UP: bool>() {
+    let mut bump = Bump::<Global, ObservedSettings<UP, false>>::unallocated();
+    assert_eq!(take_events(), []);
+
+    bump.reset();
+    assert_eq!(take_events(), [Event::Reset { stats_size: 0 }]);
+
+    drop(bump);
+    assert_eq!(take_events(), []);
+}
@@ -0,0 +1,276 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{
+    Bump, BumpVec, SyncBump,
+    alloc::Global,
+    settings::{BumpAllocatorSettings, BumpSettings},
+    stats::AnyStats,
+    traits::{BumpAllocatorTyped, BumpAllocatorTypedScope},
+};
+use common::either_way;
+
+either_way! {
+    disabled
+    unallocated
+    scoped
+    dealloc
+    dealloc_in_later_chunk
+    shrink
+    reset
+    reset_to_start
+    reset_to_fit
+    trim
+    reset_peak
+    chunk_layout
+    sync_bump
+    sync_bump_threads
+}
+
+type PeakSettings<const UP: bool, const GUARANTEED_ALLOCATED: bool = true> =
+    BumpSettings<1, UP, GUARANTEED_ALLOCATED, true, true, true, 512, { usize::MAX }, 2, 0, true>;
+
+type PeakBump<const UP: bool> = Bump<Global, PeakSettings<UP>>;
+
+fn disabled<const UP: bool>() {
+    let mut bump = Bump::<Global, BumpSettings<1, UP>>::new();
+    bump.alloc_uninit_slice::<u8>(100);
+    bump.reset();
+
+    assert_eq!(bump.stats().peak_allocated(), None);
+    assert_eq!(bump.stats().peak_capacity(), None);
+    assert_eq!(AnyStats::from(bump.stats()).peak_allocated(), None);
+}
+
+fn unallocated<const UP: bool>() {
+    let mut bump = Bump::<Global, PeakSettings<UP, false>>::unallocated();
+    assert_eq!(bump.stats().peak_allocated(), None);
+
+    bump.alloc_uninit_slice::<u8>(100);
+    bump.reset();
+
+    assert_eq!(bump.stats().peak_allocated(), Some(100));
+    assert_eq!(bump.stats().peak_capacity(), Some(bump.stats().capacity()));
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::new();
+    bump.alloc(1u8);
+
+    bump.scoped(|bump| {
+        bump.alloc_uninit_slice::<u8>(100);
+
+        bump.scoped(|bump| {
+            bump.alloc_uninit_slice::<u8>(50);
+        });
+
+        assert_eq!(bump.stats().allocated(), 101);
+        assert_eq!(bump.stats().peak_allocated(), Some(151));
+    });
+
+    assert_eq!(bump.stats().allocated(), 1);
+    assert_eq!(bump.stats().peak_allocated(), Some(151));
+}
+
+fn dealloc<const UP: bool>() {
+    let bump = PeakBump::<UP>::new();
+
+    let boxed = bump.alloc([0u8; 100]);
+    bump.dealloc(boxed);
+
+    assert_eq!(bump.stats().allocated(), 0);
+    assert_eq!(bump.stats().peak_allocated(), Some(100));
+}
+
+fn dealloc_in_later_chunk<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+
+    let boxed = bump.alloc([0u8; 800]);
+    assert_eq!(bump.stats().count(), 2);
+    let allocated = bump.stats().allocated();
+
+    bump.dealloc(boxed);
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+
+    bump.reset();
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+
+    // the chunk that was kept is the first chunk now
+    bump.alloc_uninit_slice::<u8>(bump.stats().remaining());
+    let boxed = bump.alloc([0u8; 5000]);
+    assert_eq!(bump.stats().count(), 2);
+    let allocated = bump.stats().allocated();
+
+    bump.dealloc(boxed);
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+}
+
+fn shrink<const UP: bool>() {
+    let bump = PeakBump::<UP>::new();
+
+    let mut vec = BumpVec::<u8, _>::with_capacity_in(100, &bump);
+    vec.push(1);
+    vec.shrink_to_fit();
+
+    assert_eq!(bump.stats().allocated(), 1);
+    assert_eq!(bump.stats().peak_allocated(), Some(100));
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    assert_eq!(bump.stats().count(), 2);
+    let allocated = bump.stats().allocated();
+    let capacity = bump.stats().capacity();
+
+    bump.reset();
+
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().allocated(), 0);
+    assert!(bump.stats().capacity() < capacity);
+
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+    assert_eq!(bump.stats().peak_capacity(), Some(capacity));
+}
+
+fn reset_to_start<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    let allocated = bump.stats().allocated();
+    bump.reset_to_start();
+
+    // the following allocation goes into the first chunk, leaving the second chunk with its old position
+    bump.alloc_uninit_slice::<u8>(10);
+    bump.reset();
+
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+}
+
+fn reset_to_fit<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    let allocated = bump.stats().allocated();
+    let capacity = bump.stats().capacity();
+
+    bump.reset_to_fit();
+
+    // the new chunk may have a bigger capacity than all the previous chunks combined
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+    assert_eq!(bump.stats().peak_capacity(), Some(capacity.max(bump.stats().capacity())));
+}
+
+fn trim<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    let capacity = bump.stats().capacity();
+
+    bump.reset_to_start();
+    assert_ne!(bump.trim(), 0);
+
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().peak_capacity(), Some(capacity));
+
+    // the peak is still kept up to date in the new last chunk
+    bump.alloc_uninit_slice::<u8>(2000);
+    let allocated = bump.stats().allocated();
+    bump.reset();
+
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+}
+
+fn reset_peak<const UP: bool>() {
+    let mut bump = PeakBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(1000);
+    bump.reset();
+
+    bump.alloc_uninit_slice::<u8>(10);
+    bump.reset_peak();
+
+    assert_eq!(bump.stats().peak_allocated(), Some(10));
+    assert_eq!(bump.stats().peak_capacity(), Some(bump.stats().capacity()));
+
+    bump.scoped(|bump| {
+        bump.alloc_uninit_slice::<u8>(20);
+    });
+
+    assert_eq!(bump.stats().peak_allocated(), Some(30));
+}
+
+fn chunk_layout<const UP: bool>() {
+    let plain = Bump::<Global, BumpSettings<1, UP>>::with_size(512);
+    let bump = PeakBump::<UP>::with_size(512);
+
+    let plain_chunk = plain.stats().current_chunk().unwrap();
+    let chunk = bump.stats().current_chunk().unwrap();
+
+    assert_eq!(chunk.size(), plain_chunk.size());
+    assert_eq!(chunk.capacity(), plain_chunk.capacity() - 32);
+
+    let any_chunk = AnyStats::from(bump.stats()).current_chunk().unwrap();
+    assert_eq!(any_chunk.content_start(), chunk.content_start());
+    assert_eq!(any_chunk.content_end(), chunk.content_end());
+    assert_eq!(any_chunk.capacity(), chunk.capacity());
+
+    // allocations can use the whole capacity without touching the peak record
+    bump.alloc_slice_fill(chunk.capacity(), 0xFFu8);
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().peak_allocated(), Some(chunk.capacity()));
+}
+
+fn sync_bump<const UP: bool>() {
+    let mut bump = SyncBump::<Global, PeakSettings<UP>>::new();
+
+    let boxed = (&bump).alloc([0u8; 100]);
+    bump.dealloc(boxed);
+
+    assert_eq!(bump.stats().allocated(), 0);
+    assert_eq!(bump.stats().peak_allocated(), Some(100));
+
+    (&bump).alloc_uninit_slice::<u8>(1000);
+    let allocated = bump.stats().allocated();
+    bump.reset();
+
+    assert_eq!(bump.stats().peak_allocated(), Some(allocated));
+}
+
+fn sync_bump_threads<const UP: bool>() {
+    let bump = SyncBump::<Global, PeakSettings<UP>>::new();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for i in 0..100 {
+                    let boxed = (&bump).alloc([i as u8; 64]);
+                    assert!(bump.stats().peak_allocated().unwrap() >= 64);
+                    bump.dealloc(boxed);
+                }
+            });
+        }
+    });
+
+    let stats = bump.stats();
+    let peak = stats.peak_allocated().unwrap();
+    assert!(peak >= 64);
+    assert!(peak <= stats.peak_capacity().unwrap());
+}
+
+#[test]
+fn settings() {
+    type Settings = <BumpSettings as BumpAllocatorSettings>::WithTrackPeak<true>;
+    const { assert!(Settings::TRACK_PEAK) };
+
+    let bump: Bump<Global, Settings> = Bump::new();
+    bump.alloc_str("hello");
+    assert_eq!(bump.stats().peak_allocated(), Some(5));
+}
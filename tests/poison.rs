@@ -0,0 +1,241 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{alloc::Layout, ptr::NonNull};
+
+use bump_scope::{
+    Bump, SyncBump,
+    alloc::{Allocator, Global},
+    settings::{BumpAllocatorSettings, BumpSettings, POISON_FREED, POISON_UNINIT},
+    traits::{BumpAllocatorCore, BumpAllocatorTypedScope},
+};
+use common::either_way;
+
+either_way! {
+    alloc
+    reset_to
+    scoped
+    reset
+    reset_to_start
+    previous_chunk
+    deallocate
+    shrink
+    grow
+    grow_moved
+    disabled
+    sync_bump
+    sync_bump_resize
+}
+
+type Settings<const UP: bool, const POISON: bool = true> =
+    <BumpSettings<1, UP> as BumpAllocatorSettings>::WithPoison<POISON>;
+
+type TestBump<const UP: bool, const POISON: bool = true> = Bump<Global, Settings<UP, POISON>>;
+
+fn read(ptr: NonNull<u8>, len: usize) -> Vec<u8> {
+    unsafe { core::slice::from_raw_parts(ptr.as_ptr(), len).to_vec() }
+}
+
+fn alloc<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let ptr = bump.allocate(Layout::new::<[u8; 16]>()).unwrap().cast::<u8>();
+    assert_eq!(read(ptr, 16), [POISON_UNINIT; 16]);
+
+    let uninit = bump.alloc_uninit_slice::<u32>(4);
+    assert_eq!(read(NonNull::from(&*uninit).cast(), 16), [POISON_UNINIT; 16]);
+
+    let value = bump.alloc(0u32);
+    assert_eq!(*value, 0);
+}
+
+fn reset_to<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let before = bump.alloc_slice_copy(&[1u8; 8]);
+    let before = NonNull::from(&*before).cast::<u8>();
+
+    let checkpoint = bump.checkpoint();
+    let after = NonNull::from(&*bump.alloc_slice_copy(&[2u8; 8])).cast::<u8>();
+
+    unsafe { bump.reset_to(checkpoint) };
+
+    assert_eq!(read(before, 8), [1; 8]);
+    assert_eq!(read(after, 8), [POISON_FREED; 8]);
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let outer = NonNull::from(&*bump.alloc_str("hello")).cast::<u8>();
+
+    let inner = bump.scoped(|bump| NonNull::from(&*bump.alloc_str("world")).cast::<u8>());
+
+    assert_eq!(read(outer, 5), *b"hello");
+    assert_eq!(read(inner, 5), [POISON_FREED; 5]);
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let ptr = NonNull::from(&*bump.alloc_str("hello")).cast::<u8>();
+
+    bump.reset();
+
+    assert_eq!(read(ptr, 5), [POISON_FREED; 5]);
+}
+
+fn reset_to_start<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let first = NonNull::from(&*bump.alloc_str("hello")).cast::<u8>();
+    let second = NonNull::from(&*bump.alloc_slice_fill(1000, 1u8)).cast::<u8>();
+    assert_eq!(bump.stats().count(), 2);
+
+    bump.reset_to_start();
+
+    assert_eq!(read(first, 5), [POISON_FREED; 5]);
+    assert_eq!(read(second, 1000), [POISON_FREED; 1000]);
+}
+
+fn previous_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+    let before = NonNull::from(&*bump.alloc_str("hello")).cast::<u8>();
+
+    let checkpoint = bump.checkpoint();
+    let same_chunk = NonNull::from(&*bump.alloc_str("world")).cast::<u8>();
+    let next_chunk = NonNull::from(&*bump.alloc_slice_fill(1000, 1u8)).cast::<u8>();
+    assert_eq!(bump.stats().count(), 2);
+
+    unsafe { bump.reset_to(checkpoint) };
+
+    assert_eq!(read(before, 5), *b"hello");
+    assert_eq!(read(same_chunk, 5), [POISON_FREED; 5]);
+    assert_eq!(read(next_chunk, 1000), [POISON_FREED; 1000]);
+}
+
+fn deallocate<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let layout = Layout::new::<[u8; 8]>();
+
+    let first = bump.allocate(layout).unwrap().cast::<u8>();
+    let second = bump.allocate(layout).unwrap().cast::<u8>();
+
+    // memory that can't be reclaimed is still poisoned
+    unsafe { bump.deallocate(first, layout) };
+    assert_eq!(read(first, 8), [POISON_FREED; 8]);
+
+    unsafe { bump.deallocate(second, layout) };
+    assert_eq!(read(second, 8), [POISON_FREED; 8]);
+}
+
+fn shrink<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let ptr = bump.allocate(Layout::new::<[u8; 8]>()).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 8) };
+
+    let new = unsafe { bump.shrink(ptr, Layout::new::<[u8; 8]>(), Layout::new::<[u8; 4]>()) }.unwrap();
+    let new = new.cast::<u8>();
+    assert_eq!(read(new, 4), [1; 4]);
+
+    if UP {
+        assert_eq!(new, ptr);
+        assert_eq!(read(unsafe { ptr.add(4) }, 4), [POISON_FREED; 4]);
+    } else {
+        assert_eq!(new, unsafe { ptr.add(4) });
+        assert_eq!(read(ptr, 4), [POISON_FREED; 4]);
+    }
+}
+
+fn grow<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let ptr = bump.allocate(Layout::new::<[u8; 4]>()).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 4) };
+
+    let new = unsafe { bump.grow(ptr, Layout::new::<[u8; 4]>(), Layout::new::<[u8; 8]>()) }.unwrap();
+    let new = new.cast::<u8>();
+    assert_eq!(
+        read(new, 8),
+        [1, 1, 1, 1, POISON_UNINIT, POISON_UNINIT, POISON_UNINIT, POISON_UNINIT]
+    );
+
+    if UP {
+        assert_eq!(new, ptr);
+    } else {
+        // the old memory block and the new one overlap
+        assert_eq!(new, unsafe { ptr.sub(4) });
+    }
+}
+
+fn grow_moved<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let ptr = bump.allocate(Layout::new::<[u8; 4]>()).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 4) };
+    bump.alloc(0u8);
+
+    let new = unsafe { bump.grow(ptr, Layout::new::<[u8; 4]>(), Layout::new::<[u8; 8]>()) }.unwrap();
+    let new = new.cast::<u8>();
+    assert_ne!(new, ptr);
+    assert_eq!(
+        read(new, 8),
+        [1, 1, 1, 1, POISON_UNINIT, POISON_UNINIT, POISON_UNINIT, POISON_UNINIT]
+    );
+    assert_eq!(read(ptr, 4), [POISON_FREED; 4]);
+}
+
+fn disabled<const UP: bool>() {
+    let bump = TestBump::<UP, false>::new();
+    let checkpoint = bump.checkpoint();
+
+    let ptr = NonNull::from(&*bump.alloc_slice_copy(&[1u8; 8])).cast::<u8>();
+    unsafe { bump.reset_to(checkpoint) };
+
+    assert_eq!(read(ptr, 8), [1; 8]);
+}
+
+fn sync_bump<const UP: bool>() {
+    let mut bump = SyncBump::<Global, Settings<UP>>::with_size(512);
+    let layout = Layout::new::<[u8; 8]>();
+
+    let ptr = bump.allocate(layout).unwrap().cast::<u8>();
+    assert_eq!(read(ptr, 8), [POISON_UNINIT; 8]);
+
+    unsafe { bump.deallocate(ptr, layout) };
+    assert_eq!(read(ptr, 8), [POISON_FREED; 8]);
+
+    let before = NonNull::from(&*(&bump).alloc_str("hello")).cast::<u8>();
+    let checkpoint = bump.checkpoint();
+    let same_chunk = NonNull::from(&*(&bump).alloc_str("world")).cast::<u8>();
+    let next_chunk = NonNull::from(&*(&bump).alloc_slice_fill(1000, 1u8)).cast::<u8>();
+
+    bump.try_reset_to(checkpoint).unwrap();
+
+    assert_eq!(read(before, 5), *b"hello");
+    assert_eq!(read(same_chunk, 5), [POISON_FREED; 5]);
+    assert_eq!(read(next_chunk, 1000), [POISON_FREED; 1000]);
+
+    bump.reset_to_start();
+    assert_eq!(read(before, 5), [POISON_FREED; 5]);
+}
+
+fn sync_bump_resize<const UP: bool>() {
+    let bump = SyncBump::<Global, Settings<UP>>::new();
+
+    let ptr = bump.allocate(Layout::new::<[u8; 4]>()).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 4) };
+
+    let new = unsafe { bump.grow(ptr, Layout::new::<[u8; 4]>(), Layout::new::<[u8; 8]>()) }.unwrap();
+    let new = new.cast::<u8>();
+    assert_eq!(
+        read(new, 8),
+        [1, 1, 1, 1, POISON_UNINIT, POISON_UNINIT, POISON_UNINIT, POISON_UNINIT]
+    );
+
+    let shrunk = unsafe { bump.shrink(new, Layout::new::<[u8; 8]>(), Layout::new::<[u8; 2]>()) }.unwrap();
+    let shrunk = shrunk.cast::<u8>();
+    assert_eq!(read(shrunk, 2), [1, 1]);
+
+    if UP {
+        assert_eq!(read(unsafe { new.add(2) }, 6), [POISON_FREED; 6]);
+    }
+}
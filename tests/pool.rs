@@ -1,10 +1,13 @@
 #![cfg(all(feature = "std", feature = "panic-on-alloc"))]
 
-use std::vec::Vec;
+mod common;
+
+use std::{sync::Arc, vec::Vec};
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use bump_scope::{BumpPool, alloc::Global};
+use bump_scope::{BumpPool, alloc::Global, settings::BumpSettings, traits::BumpAllocatorTypedScope};
+use common::InstrumentedAllocator;
 
 macro_rules! either_way {
     ($($(#[$attr:meta])* $ident:ident)*) => {
@@ -36,8 +39,40 @@ either_way! {
     rayon
 
     scope
+
+    stats
+
+    stats_threads
+
+    trim
+
+    max_retained_bumps
+
+    max_retained_bump_size
+
+    max_retained_bump_size_on_return
+
+    max_retained_bump_size_reset_to_start
+
+    scoped
+
+    scoped_with_limit
+
+    scoped_with_limit_threads
+
+    scoped_keeps_previous_allocations
+
+    scoped_threads
+
+    owned
+
+    owned_threads
+
+    owned_keeps_pool_alive
 }
 
+type TestPool<A, const UP: bool> = BumpPool<A, BumpSettings<1, UP>>;
+
 fn rayon<const UP: bool>() {
     if cfg!(miri) {
         // rayon violates strict-provenance :(
@@ -81,3 +116,363 @@ fn scope<const UP: bool>() {
         });
     });
 }
+
+fn stats<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+    assert_eq!(pool.stats(), Default::default());
+
+    let a = pool.get_with_size(512);
+    let b = pool.get_with_size(512);
+    let (size, capacity) = (a.stats().size(), a.stats().capacity());
+    a.alloc_str("hello");
+
+    // checked out bumps are accounted for as they were when they were checked out
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 2);
+    assert_eq!(stats.checked_out(), 2);
+    assert_eq!(stats.chunks(), 2);
+    assert_eq!(stats.size(), 2 * size);
+    assert_eq!(stats.allocated(), 0);
+
+    drop(a);
+
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 2);
+    assert_eq!(stats.checked_out(), 1);
+    assert_eq!(stats.allocated(), 5);
+
+    b.alloc_uninit_slice::<u8>(1000);
+    assert_eq!(pool.stats().chunks(), 2);
+
+    let b_stats = b.stats();
+    let (b_chunks, b_size, b_capacity, b_allocated) =
+        (b_stats.count(), b_stats.size(), b_stats.capacity(), b_stats.allocated());
+    drop(b);
+
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 2);
+    assert_eq!(stats.checked_out(), 0);
+    assert_eq!(stats.chunks(), 1 + b_chunks);
+    assert_eq!(stats.size(), size + b_size);
+    assert_eq!(stats.capacity(), capacity + b_capacity);
+    assert_eq!(stats.allocated(), 5 + b_allocated);
+}
+
+fn stats_threads<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let bump = pool.get_with_size(512);
+                bump.alloc_uninit_slice::<u8>(1000);
+            });
+        }
+    });
+
+    let stats = pool.stats();
+    assert!((1..=4).contains(&stats.bumps()));
+    assert_eq!(stats.checked_out(), 0);
+    assert!(stats.allocated() >= 4 * 1000);
+    assert!(stats.capacity() >= stats.allocated());
+    assert!(stats.size() > stats.capacity());
+}
+
+fn trim<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut pool = TestPool::<_, UP>::new_in(&allocator);
+
+    let guards = [pool.get(), pool.get(), pool.get()];
+    let size = guards[0].stats().size();
+    drop(guards);
+
+    assert_eq!(allocator.leaks().len(), 3);
+    assert_eq!(pool.trim(5), 0);
+    assert_eq!(pool.trim(1), 2 * size);
+    assert_eq!(allocator.leaks().len(), 1);
+    assert_eq!(pool.stats().bumps(), 1);
+
+    assert_eq!(pool.trim(0), size);
+    assert_eq!(allocator.leaks().len(), 0);
+    assert_eq!(pool.stats(), Default::default());
+}
+
+fn max_retained_bumps<const UP: bool>() {
+    let mut pool = TestPool::<Global, UP>::new().with_max_retained_bumps(2);
+    assert_eq!(pool.max_retained_bumps(), 2);
+
+    // the limit does not restrict how many bumps can be checked out
+    let guards = [pool.get(), pool.get(), pool.get(), pool.get()];
+    assert_eq!(pool.stats().bumps(), 4);
+
+    // bumps without allocations are deallocated when they are returned
+    drop(guards);
+    assert_eq!(pool.bumps().len(), 2);
+
+    // bumps with allocations are kept until the pool is reset
+    let guards = [pool.get(), pool.get(), pool.get(), pool.get()];
+    for guard in &guards {
+        guard.alloc_str("hello");
+    }
+    drop(guards);
+
+    assert_eq!(pool.bumps().len(), 4);
+    pool.reset();
+    assert_eq!(pool.bumps().len(), 2);
+    pool.reset_to_start();
+    assert_eq!(pool.bumps().len(), 2);
+}
+
+fn max_retained_bump_size<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut pool = TestPool::<_, UP>::new_in(&allocator).with_max_retained_bump_size(2048);
+    assert_eq!(pool.max_retained_bump_size(), 2048);
+
+    let small = pool.get_with_size(512);
+    let big = pool.get_with_size(512);
+    small.alloc_uninit_slice::<u8>(100);
+    big.alloc_uninit_slice::<u8>(4096);
+    drop((small, big));
+
+    assert_eq!(allocator.leaks().len(), 3);
+
+    // `reset` keeps the biggest chunk which exceeds the limit
+    pool.reset();
+    assert_eq!(pool.bumps().len(), 1);
+    assert_eq!(allocator.leaks().len(), 1);
+    assert!(pool.stats().size() <= 2048);
+}
+
+fn max_retained_bump_size_on_return<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let pool = TestPool::<_, UP>::new_in(&allocator).with_max_retained_bump_size(2048);
+
+    // a bump without allocations that is too big is deallocated
+    drop(pool.get_with_size(8192));
+    assert_eq!(pool.stats().bumps(), 0);
+    assert_eq!(allocator.leaks().len(), 0);
+
+    // the spare chunks of a scoped guard are trimmed when it is returned
+    {
+        let mut guard = pool.get_scoped();
+        let scope = guard.scope();
+        scope.alloc_str("hello");
+        scope.alloc_uninit_slice::<u8>(8192);
+    }
+
+    assert_eq!(allocator.leaks().len(), 1);
+    assert!(pool.stats().size() <= 2048);
+
+    // a bump with live allocations is kept even if it is too big
+    let guard = pool.get();
+    guard.alloc_uninit_slice::<u8>(8192);
+    drop(guard);
+
+    assert_eq!(pool.stats().bumps(), 1);
+    assert_eq!(allocator.leaks().len(), 2);
+}
+
+fn max_retained_bump_size_reset_to_start<const UP: bool>() {
+    let mut pool = TestPool::<Global, UP>::new().with_max_retained_bump_size(2048);
+
+    let bump = pool.get_with_size(512);
+    let first_chunk_size = bump.stats().size();
+
+    for _ in 0..4 {
+        bump.alloc_uninit_slice::<u8>(bump.stats().remaining() + 1);
+    }
+
+    let chunk_sizes = bump.stats().small_to_big().map(|chunk| chunk.size()).collect::<Vec<_>>();
+    drop(bump);
+
+    // `reset_to_start` keeps the smallest chunks that fit into the limit
+    pool.reset_to_start();
+    assert_eq!(pool.bumps().len(), 1);
+
+    let mut expected_size = 0;
+    for size in chunk_sizes {
+        if expected_size + size > 2048 {
+            break;
+        }
+        expected_size += size;
+    }
+
+    assert!(expected_size >= first_chunk_size);
+    assert_eq!(pool.stats().size(), expected_size);
+}
+
+fn scoped<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+
+    for _ in 0..3 {
+        let mut guard = pool.get_scoped();
+        let bump = guard.scope();
+        bump.alloc_uninit_slice::<u8>(2000);
+        bump.alloc_str("hello");
+        assert!(bump.stats().allocated() >= 2005);
+
+        guard.reset();
+        assert_eq!(guard.scope().stats().allocated(), 0);
+
+        guard.scope().alloc_str("world");
+    }
+
+    // every iteration reused the same bump
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 1);
+    assert_eq!(stats.allocated(), 0);
+}
+
+fn scoped_with_limit<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+
+    for _ in 0..3 {
+        let mut guard = pool.get_scoped_with_limit(4096);
+
+        {
+            let bump = guard.scope();
+            bump.alloc_uninit_slice::<u8>(3000);
+            assert!(bump.try_alloc_uninit_slice::<u8>(2000).is_err());
+
+            while bump.try_alloc(0u8).is_ok() {}
+            assert_eq!(bump.used(), 4096);
+        }
+
+        // the limit spans all the scopes of the guard
+        assert!(guard.scope().try_alloc(0u8).is_err());
+
+        guard.reset();
+        assert_eq!(guard.scope().remaining(), 4096);
+    }
+
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 1);
+    assert_eq!(stats.allocated(), 0);
+}
+
+fn scoped_with_limit_threads<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let mut guard = pool.get_scoped_with_limit(4096);
+                let bump = guard.scope();
+
+                while bump.try_alloc(0u64).is_ok() {}
+
+                assert_eq!(bump.used(), 4096);
+                assert!(bump.stats().allocated() <= 4096);
+            });
+        }
+    });
+
+    assert_eq!(pool.stats().allocated(), 0);
+}
+
+fn scoped_keeps_previous_allocations<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+
+    let hello = pool.get().alloc_str("hello").into_ref();
+
+    {
+        let mut guard = pool.get_scoped();
+        assert!(core::ptr::eq(guard.pool(), &pool));
+        guard.scope().alloc_uninit_slice::<u8>(4096);
+    }
+
+    assert_eq!(hello, "hello");
+    assert_eq!(pool.stats().bumps(), 1);
+    assert_eq!(pool.stats().allocated(), 5);
+}
+
+fn scoped_threads<const UP: bool>() {
+    let pool = TestPool::<Global, UP>::new();
+
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let pool = &pool;
+
+            s.spawn(move || {
+                for i in 0..10 {
+                    let mut guard = pool.get_scoped();
+                    let bump = guard.scope();
+                    let value = bump.alloc(t * 10 + i);
+                    assert_eq!(*value, t * 10 + i);
+                }
+            });
+        }
+    });
+
+    let stats = pool.stats();
+    assert!((1..=4).contains(&stats.bumps()));
+    assert_eq!(stats.checked_out(), 0);
+    assert_eq!(stats.allocated(), 0);
+}
+
+fn owned<const UP: bool>() {
+    let pool = Arc::new(TestPool::<Global, UP>::new());
+
+    let mut guard = pool.get_owned();
+    assert!(Arc::ptr_eq(guard.pool(), &pool));
+
+    {
+        // allocations borrow the guard
+        let hello = guard.alloc_str("hello");
+        assert_eq!(hello, "hello");
+        assert_eq!(pool.stats().checked_out(), 1);
+    }
+
+    guard.scope().scoped(|bump| {
+        bump.alloc_uninit_slice::<u8>(1000);
+    });
+
+    assert_eq!(guard.stats().allocated(), 5);
+    drop(guard);
+
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 1);
+    assert_eq!(stats.checked_out(), 0);
+    assert_eq!(stats.allocated(), 5);
+}
+
+fn owned_threads<const UP: bool>() {
+    let pool = Arc::new(TestPool::<Global, UP>::new());
+
+    // check out all guards before any thread can return its bump for the next one to reuse
+    let guards = (0..4).map(|_| pool.get_owned()).collect::<Vec<_>>();
+
+    let handles = guards
+        .into_iter()
+        .enumerate()
+        .map(|(t, bump)| {
+            std::thread::spawn(move || {
+                let values = bump.alloc_iter(t * 100..(t + 1) * 100);
+                values.iter().sum::<usize>()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (t, handle) in handles.into_iter().enumerate() {
+        assert_eq!(handle.join().unwrap(), (t * 100..(t + 1) * 100).sum());
+    }
+
+    let stats = pool.stats();
+    assert_eq!(stats.bumps(), 4);
+    assert_eq!(stats.checked_out(), 0);
+    assert!(stats.allocated() >= 4 * 100 * size_of::<usize>());
+}
+
+fn owned_keeps_pool_alive<const UP: bool>() {
+    let pool = Arc::new(TestPool::<Global, UP>::new());
+    let weak = Arc::downgrade(&pool);
+
+    let guard = pool.get_owned();
+    drop(pool);
+
+    assert_eq!(guard.alloc(7u32), 7);
+    assert_eq!(weak.strong_count(), 1);
+
+    drop(guard);
+    assert!(weak.upgrade().is_none());
+}
@@ -0,0 +1,78 @@
+//! Without the `std` feature `BumpPool` protects its `Bump`s with a spin lock.
+//!
+//! Run with `cargo test --no-default-features -F alloc --test pool_spin_lock`.
+#![cfg(all(feature = "alloc", not(feature = "std"), target_has_atomic = "8"))]
+
+use std::{sync::Arc, vec::Vec};
+
+use bump_scope::{BumpPool, alloc::AllocError};
+
+#[test]
+fn round_trip() -> Result<(), AllocError> {
+    let mut pool: BumpPool = BumpPool::new();
+
+    {
+        let guard = pool.try_get()?;
+        assert_eq!(guard.try_alloc_str("hello")?, "hello");
+    }
+
+    assert_eq!(pool.bumps().len(), 1);
+    assert_eq!(pool.stats().allocated(), 5);
+
+    {
+        let mut guard = pool.try_get_scoped()?;
+        guard.scope().try_alloc_str("world")?;
+    }
+
+    assert_eq!(pool.stats().allocated(), 5);
+
+    pool.reset();
+    assert_eq!(pool.stats().allocated(), 0);
+    Ok(())
+}
+
+#[test]
+fn threads() {
+    let pool: BumpPool = BumpPool::new();
+
+    let strings = std::thread::scope(|s| {
+        let handles = (0..8)
+            .map(|i| {
+                let pool = &pool;
+                s.spawn(move || {
+                    (0..100)
+                        .map(|j| {
+                            let guard = pool.try_get().unwrap();
+                            guard.try_alloc_fmt(format_args!("{i}-{j}")).unwrap().into_ref()
+                        })
+                        .collect::<Vec<&str>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert_eq!(strings.len(), 800);
+    assert!(strings.contains(&"7-99"));
+    assert_eq!(pool.stats().checked_out(), 0);
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn owned() -> Result<(), AllocError> {
+    let pool: Arc<BumpPool> = Arc::new(BumpPool::new());
+    let guard = pool.try_get_owned()?;
+
+    let len = std::thread::spawn(move || guard.try_alloc_str("hello").map(|s| s.len()))
+        .join()
+        .unwrap()?;
+
+    assert_eq!(len, 5);
+    assert_eq!(pool.stats().checked_out(), 0);
+    assert_eq!(pool.stats().bumps(), 1);
+    Ok(())
+}
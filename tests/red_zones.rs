@@ -0,0 +1,310 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{
+    alloc::Layout,
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr::NonNull,
+};
+
+use bump_scope::{
+    Bump, BumpVec, FixedBumpVec, MutBumpVec, MutBumpVecRev,
+    alloc::{Allocator, Global},
+    settings::{BumpAllocatorSettings, BumpSettings, POISON_FREED},
+    stats::AnyStats,
+};
+use common::either_way;
+
+either_way! {
+    check_integrity
+    check_integrity_front
+    check_integrity_previous_chunk
+    reset_to
+    scoped
+    reset
+    deallocate
+    deallocate_last
+    shrink
+    grow
+    fixed_vec_set_len
+    mut_bump_vec
+    mut_bump_vec_rev
+    bump_vec
+    alloc_try_with
+    poison
+    any_stats
+}
+
+type Settings<const UP: bool> = <BumpSettings<1, UP> as BumpAllocatorSettings>::WithRedZones<true>;
+
+type TestBump<const UP: bool> = Bump<Global, Settings<UP>>;
+
+fn catch<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|err| match err.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(_) => "panicked".into(),
+    })
+}
+
+fn expect_violation<R>(f: impl FnOnce() -> R) {
+    let msg = catch(f).err().expect("expected a panic");
+    assert!(msg.starts_with("red zone of the allocation at"), "{msg}");
+}
+
+fn check_integrity<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let mut slice = bump.alloc_slice_copy(&[1u8, 2, 3]);
+    bump.alloc(5u32);
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    unsafe { slice.as_mut_ptr().add(3).write(4) };
+
+    let error = bump.check_integrity().unwrap_err();
+    assert_eq!(error.address(), slice.as_ptr().addr());
+    assert_eq!(error.layout(), Layout::new::<[u8; 3]>());
+    assert!(error.to_string().contains("with a size of 3 and an alignment of 1"));
+}
+
+fn check_integrity_front<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let value = bump.alloc(7u64);
+    let ptr = NonNull::from(&*value).cast::<u8>();
+
+    unsafe { ptr.sub(1).write(0) };
+
+    let error = bump.check_integrity().unwrap_err();
+    assert_eq!(error.address(), ptr.addr().get());
+    assert_eq!(error.layout(), Layout::new::<u64>());
+}
+
+fn check_integrity_previous_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+    let mut slice = bump.alloc_slice_copy(&[1u8; 8]);
+    bump.alloc_slice_fill(1000, 2u8);
+    assert_eq!(bump.stats().count(), 2);
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    unsafe { slice.as_mut_ptr().add(8).write(0) };
+
+    assert_eq!(bump.check_integrity().unwrap_err().address(), slice.as_ptr().addr());
+}
+
+fn reset_to<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    bump.alloc_str("before");
+
+    let checkpoint = bump.checkpoint();
+    unsafe { bump.reset_to(checkpoint) };
+
+    let ptr = NonNull::from(&*bump.alloc_slice_copy(&[1u8; 4])).cast::<u8>();
+    unsafe { ptr.add(4).write(0) };
+
+    expect_violation(|| unsafe { bump.reset_to(checkpoint) });
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("before");
+
+    bump.scoped(|bump| {
+        bump.alloc_str("hello");
+    });
+
+    expect_violation(|| {
+        bump.scoped(|bump| {
+            let mut slice = bump.alloc_slice_copy(&[1u8; 4]);
+            unsafe { slice.as_mut_ptr().add(4).write(0) };
+        });
+    });
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let ptr = NonNull::from(&*bump.alloc_slice_copy(&[1u8; 4])).cast::<u8>();
+    unsafe { ptr.add(4).write(0) };
+
+    expect_violation(|| bump.reset());
+}
+
+fn deallocate<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let layout = Layout::new::<[u8; 4]>();
+
+    let ptr = bump.allocate(layout).unwrap().cast::<u8>();
+    unsafe { ptr.add(4).write(0) };
+
+    expect_violation(|| unsafe { bump.deallocate(ptr, layout) });
+}
+
+fn deallocate_last<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let layout = Layout::new::<[u8; 4]>();
+    let before = bump.stats().allocated();
+
+    let first = bump.allocate(layout).unwrap().cast::<u8>();
+    let second = bump.allocate(layout).unwrap().cast::<u8>();
+
+    unsafe {
+        bump.deallocate(second, layout);
+        bump.deallocate(first, layout);
+    }
+
+    assert_eq!(bump.stats().allocated(), before);
+    assert_eq!(bump.check_integrity(), Ok(()));
+}
+
+fn shrink<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let old_layout = Layout::new::<[u8; 8]>();
+    let new_layout = Layout::new::<[u8; 4]>();
+
+    let ptr = bump.allocate(old_layout).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 8) };
+
+    let new = unsafe { bump.shrink(ptr, old_layout, new_layout) }.unwrap().cast::<u8>();
+    assert_eq!(new, ptr);
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    // the memory that was given up is now part of the red zone
+    unsafe { ptr.add(4).write(1) };
+
+    let error = bump.check_integrity().unwrap_err();
+    assert_eq!(error.layout(), new_layout);
+}
+
+fn grow<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let old_layout = Layout::new::<[u8; 4]>();
+    let new_layout = Layout::new::<[u8; 8]>();
+
+    let ptr = bump.allocate(old_layout).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 4) };
+
+    let new = unsafe { bump.grow(ptr, old_layout, new_layout) }.unwrap().cast::<u8>();
+    assert_eq!(unsafe { core::slice::from_raw_parts(new.as_ptr(), 4) }, [1; 4]);
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    unsafe { new.add(8).write(0) };
+    expect_violation(|| unsafe { bump.grow(new, new_layout, Layout::new::<[u8; 16]>()) });
+}
+
+fn fixed_vec_set_len<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let mut vec = FixedBumpVec::<u32>::with_capacity_in(4, &bump);
+    vec.extend_from_slice_copy(&[1, 2, 3, 4]);
+
+    unsafe { vec.set_len(5) };
+    vec[4] = 5;
+
+    let error = bump.check_integrity().unwrap_err();
+    assert_eq!(error.address(), vec.as_ptr().addr());
+    assert_eq!(error.layout(), Layout::new::<[u32; 4]>());
+
+    unsafe { vec.set_len(4) };
+}
+
+fn mut_bump_vec<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+
+    let mut vec = MutBumpVec::new_in(&mut bump);
+
+    for i in 0..1000u32 {
+        vec.push(i);
+    }
+
+    let slice = NonNull::from(vec.into_slice());
+
+    assert_eq!(bump.check_integrity(), Ok(()));
+    assert_eq!(*bump.alloc(5u8), 5);
+    assert_eq!(bump.check_integrity(), Ok(()));
+    assert!(unsafe { slice.as_ref() }.iter().copied().eq(0..1000));
+
+    unsafe { slice.cast::<u32>().add(1000).write(0) };
+    assert_eq!(bump.check_integrity().unwrap_err().address(), slice.addr().get());
+}
+
+fn mut_bump_vec_rev<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+
+    let mut vec = MutBumpVecRev::new_in(&mut bump);
+
+    for i in 0..1000u32 {
+        vec.push(i);
+    }
+
+    let slice = NonNull::from(vec.into_slice());
+
+    assert_eq!(bump.check_integrity(), Ok(()));
+    assert_eq!(*bump.alloc(5u8), 5);
+    assert_eq!(bump.check_integrity(), Ok(()));
+    assert!(unsafe { slice.as_ref() }.iter().copied().eq((0..1000).rev()));
+
+    unsafe { slice.cast::<u32>().sub(1).write(0) };
+    assert_eq!(bump.check_integrity().unwrap_err().address(), slice.addr().get());
+}
+
+fn bump_vec<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let mut vec = BumpVec::new_in(&bump);
+
+    for i in 0..100u32 {
+        vec.push(i);
+    }
+
+    vec.truncate(10);
+    vec.shrink_to_fit();
+    assert!(vec.iter().copied().eq(0..10));
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    drop(vec);
+    assert_eq!(bump.check_integrity(), Ok(()));
+}
+
+fn alloc_try_with<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    assert_eq!(bump.alloc_try_with(|| Err::<u32, u32>(1)).unwrap_err(), 1);
+    assert_eq!(*bump.alloc_try_with(|| Ok::<u32, u32>(2)).unwrap(), 2);
+    assert_eq!(bump.alloc_try_with_mut(|| Err::<u32, u32>(3)).unwrap_err(), 3);
+    assert_eq!(*bump.alloc_try_with_mut(|| Ok::<u32, u32>(4)).unwrap(), 4);
+
+    assert_eq!(bump.check_integrity(), Ok(()));
+}
+
+fn poison<const UP: bool>() {
+    type Both<const UP: bool> = <Settings<UP> as BumpAllocatorSettings>::WithPoison<true>;
+
+    let bump = Bump::<Global, Both<UP>>::new();
+    let checkpoint = bump.checkpoint();
+
+    let ptr = NonNull::from(&*bump.alloc_slice_copy(&[1u8; 8])).cast::<u8>();
+    assert_eq!(bump.check_integrity(), Ok(()));
+
+    unsafe { bump.reset_to(checkpoint) };
+
+    assert_eq!(unsafe { core::slice::from_raw_parts(ptr.as_ptr(), 8) }, [POISON_FREED; 8]);
+    assert_eq!(bump.check_integrity(), Ok(()));
+}
+
+fn any_stats<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+    bump.alloc_str("hello");
+    bump.alloc_uninit_slice::<u8>(1000);
+
+    let stats = bump.stats();
+    let any = AnyStats::from(stats);
+
+    assert_eq!(any.capacity(), stats.capacity());
+    assert_eq!(any.allocated(), stats.allocated());
+    assert_eq!(any.remaining(), stats.remaining());
+
+    for (chunk, any_chunk) in stats.small_to_big().zip(any.small_to_big()) {
+        assert_eq!(any_chunk.content_start(), chunk.content_start());
+        assert_eq!(any_chunk.content_end(), chunk.content_end());
+        assert_eq!(any_chunk.capacity(), chunk.capacity());
+        assert_eq!(any_chunk.allocated(), chunk.allocated());
+    }
+}
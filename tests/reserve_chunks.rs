@@ -0,0 +1,147 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::ptr::NonNull;
+
+use bump_scope::{
+    Bump,
+    alloc::{Allocator, Global},
+    settings::{BumpAllocatorSettings, BumpSettings, POISON_FREED},
+};
+use common::{InstrumentedAllocator, either_way};
+
+either_way! {
+    chain
+    base_allocator_calls
+    unallocated
+    never_smaller
+    reused_after_reset_to_start
+    not_growing
+    prefault
+    prefault_keeps_contents
+}
+
+type TestBump<const UP: bool, A = Global> = Bump<A, BumpSettings<1, UP>>;
+
+fn capacities<const UP: bool, A: Allocator>(bump: &TestBump<UP, A>) -> Vec<usize> {
+    bump.stats().small_to_big().map(|chunk| chunk.capacity()).collect()
+}
+
+fn chain<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = TestBump::<UP, _>::with_size_in(512, &allocator);
+
+    bump.reserve_chunks(&[1024, 4096]);
+    assert_eq!(allocator.leaks().len(), 3);
+
+    let capacities = capacities(&bump);
+    assert!(capacities[1] >= 1024);
+    assert!(capacities[2] >= 4096);
+
+    bump.alloc_uninit_slice::<u8>(1024);
+    bump.alloc_uninit_slice::<u8>(4096);
+    assert_eq!(allocator.leaks().len(), 3);
+}
+
+fn base_allocator_calls<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut bump = TestBump::<UP, _>::with_size_in(512, &allocator);
+    bump.reserve_chunks(&[1000, 2000]);
+    assert_eq!(allocator.allocation_count(), 3);
+
+    // each run of allocations fits into a chunk
+    bump.alloc_uninit_slice::<u8>(bump.stats().current_chunk().unwrap().remaining());
+    bump.alloc_uninit_slice::<u8>(600);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(2000);
+    assert_eq!(allocator.allocation_count(), 3);
+
+    bump.reset_to_start();
+
+    let capacities = capacities(&bump);
+    let (small, big) = (capacities[1], capacities[2]);
+
+    // this skips the smaller spare chunk and leaves memory at the end of the bigger one
+    bump.alloc_uninit_slice::<u8>(bump.stats().current_chunk().unwrap().remaining());
+    bump.alloc_uninit_slice::<u8>(small + 1);
+    assert_eq!(allocator.allocation_count(), 3);
+
+    // so the following allocation doesn't fit, even though the chunks add up to more
+    bump.alloc_uninit_slice::<u8>(big - small);
+    assert_eq!(allocator.allocation_count(), 4);
+}
+
+fn unallocated<const UP: bool>() {
+    let bump = Bump::<Global, BumpSettings<1, UP, false>>::unallocated();
+
+    bump.reserve_chunks(&[]);
+    assert_eq!(bump.stats().count(), 0);
+
+    bump.reserve_chunks(&[1000, 2000]);
+    assert_eq!(bump.stats().count(), 2);
+    assert!(bump.stats().remaining() >= 1000);
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    assert_eq!(bump.stats().current_chunk().unwrap().prev(), None);
+}
+
+fn never_smaller<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(4096);
+    bump.reserve_chunks(&[16]);
+
+    let capacities = capacities(&bump);
+    assert_eq!(capacities.len(), 2);
+    assert_eq!(capacities[0], capacities[1]);
+
+    // the biggest chunk is the last one
+    bump.reset();
+    assert_eq!(bump.stats().capacity(), capacities[1]);
+}
+
+fn reused_after_reset_to_start<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut bump = TestBump::<UP, _>::with_size_in(512, &allocator);
+    bump.reserve_chunks(&[2048, 2048]);
+
+    for _ in 0..3 {
+        bump.alloc_uninit_slice::<u8>(2048);
+        bump.alloc_uninit_slice::<u8>(2048);
+        bump.reset_to_start();
+    }
+
+    assert_eq!(allocator.leaks().len(), 3);
+}
+
+fn not_growing<const UP: bool>() {
+    type Settings<const UP: bool> = <BumpSettings<1, UP> as BumpAllocatorSettings>::WithGrows<false>;
+
+    let bump = Bump::<Global, Settings<UP>>::with_size(512);
+
+    assert!(bump.try_reserve_chunks(&[]).is_ok());
+    assert!(bump.try_reserve_chunks(&[1]).is_err());
+    assert_eq!(bump.stats().count(), 1);
+}
+
+fn prefault<const UP: bool>() {
+    let bump = Bump::<Global, BumpSettings<1, UP, false>>::unallocated();
+    bump.prefault();
+
+    bump.reserve_chunks(&[10_000, 100_000]);
+    bump.alloc_str("hello");
+    bump.prefault();
+
+    assert_eq!(bump.alloc_str("world"), "world");
+}
+
+fn prefault_keeps_contents<const UP: bool>() {
+    type Settings<const UP: bool> = <BumpSettings<1, UP> as BumpAllocatorSettings>::WithPoison<true>;
+
+    let mut bump = Bump::<Global, Settings<UP>>::with_size(10_000);
+
+    let ptr = bump.scoped(|bump| NonNull::from(&*bump.alloc_uninit_slice::<u8>(8192)).cast::<u8>());
+    bump.prefault();
+
+    let freed = unsafe { core::slice::from_raw_parts(ptr.as_ptr(), 8192) };
+    assert!(freed.iter().all(|&byte| byte == POISON_FREED));
+}
@@ -0,0 +1,85 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{Bump, alloc::Global, settings::BumpSettings};
+use common::{InstrumentedAllocator, Limited, either_way};
+
+either_way! {
+    unallocated
+    single_chunk
+    coalesce
+    steady_state
+    allocation_failure
+}
+
+type TestBump<const UP: bool> = Bump<Global, BumpSettings<1, UP, false>>;
+
+fn unallocated<const UP: bool>() {
+    let mut bump = TestBump::<UP>::unallocated();
+    bump.reset_to_fit();
+    assert_eq!(bump.stats().count(), 0);
+}
+
+fn single_chunk<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+
+    let chunk = bump.stats().current_chunk().unwrap().chunk_start();
+    bump.reset_to_fit();
+
+    // the chunk is reused
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().current_chunk().unwrap().chunk_start(), chunk);
+    assert_eq!(bump.stats().allocated(), 0);
+}
+
+fn coalesce<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut bump = Bump::<_, BumpSettings<1, UP>>::with_size_in(512, &allocator);
+
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(600);
+    bump.alloc_uninit_slice::<u8>(1200);
+    assert_eq!(bump.stats().count(), 3);
+
+    let allocated = bump.stats().allocated();
+    bump.reset_to_fit();
+
+    assert_eq!(allocator.leaks().len(), 1);
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().allocated(), 0);
+    assert!(bump.stats().capacity() >= allocated);
+}
+
+fn steady_state<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+
+    for i in 0..4 {
+        for size in [300, 500, 700, 900] {
+            bump.alloc_uninit_slice::<u8>(size);
+        }
+
+        let expected_chunks = if i == 0 { 3 } else { 1 };
+        assert_eq!(bump.stats().count(), expected_chunks);
+
+        bump.reset_to_fit();
+        assert_eq!(bump.stats().count(), 1);
+    }
+}
+
+fn allocation_failure<const UP: bool>() {
+    let allocator = Limited::new_in(2048, Global);
+    let mut bump = Bump::<_, BumpSettings<1, UP>>::with_size_in(512, &allocator);
+
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(600);
+    assert_eq!(bump.stats().count(), 2);
+
+    let count = bump.stats().count();
+    let allocated = bump.stats().allocated();
+
+    assert!(bump.try_reset_to_fit().is_err());
+    assert_eq!(bump.stats().count(), count);
+    assert_eq!(bump.stats().allocated(), allocated);
+}
@@ -0,0 +1,226 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{
+    Bump, BumpVec, MutBumpVec, MutBumpVecRev,
+    alloc::Global,
+    settings::{BumpAllocatorSettings, BumpSettings},
+    traits::BumpAllocatorTypedScope,
+};
+use common::{InstrumentedAllocator, either_way};
+
+either_way! {
+    exact_fit
+    rolls_back
+    reuses_chunks
+    no_chunk_for_huge_request
+    no_chunk_for_rejected_request
+    min_align
+    guard
+    guard_reset
+    mut_bump_vec
+    mut_bump_vec_rev
+    bump_vec
+    bump_vec_moving
+}
+
+type TestBump<const UP: bool, const MIN_ALIGN: usize = 1> = Bump<Global, BumpSettings<MIN_ALIGN, UP>>;
+
+fn exact_fit<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("outside");
+
+    bump.scoped_with_limit(100, |bump| {
+        bump.alloc_uninit_slice::<u8>(100);
+        assert_eq!(bump.used(), 100);
+        assert_eq!(bump.remaining(), 0);
+
+        assert!(bump.try_alloc(0u8).is_err());
+        bump.alloc(());
+    });
+
+    assert_eq!(bump.stats().allocated(), 7);
+}
+
+fn rolls_back<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    bump.scoped_with_limit(10, |bump| {
+        bump.alloc(1u8);
+        assert!(bump.try_alloc(0u64).is_err());
+        assert_eq!(bump.used(), 1);
+        assert_eq!(bump.stats().allocated(), 1);
+    });
+}
+
+fn reuses_chunks<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    bump.scoped(|bump| {
+        bump.alloc_uninit_slice::<u8>(2000);
+    });
+
+    let chunks = bump.stats().count();
+    assert!(chunks > 1);
+
+    bump.scoped_with_limit(3000, |bump| {
+        bump.alloc_uninit_slice::<u8>(2000);
+        assert!(bump.try_alloc_uninit_slice::<u8>(1001).is_err());
+
+        // the first chunk was too small, its free space counts as used as well
+        let first = bump.stats().current_chunk().unwrap().prev().unwrap();
+        assert_eq!(bump.used(), 2000 + first.capacity());
+    });
+
+    assert_eq!(bump.stats().count(), chunks);
+}
+
+fn no_chunk_for_huge_request<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let size = bump.stats().size();
+
+    bump.scoped_with_limit(1024, |bump| {
+        assert!(bump.try_alloc_uninit_slice::<u8>(1 << 30).is_err());
+        assert!(MutBumpVec::<u8, _>::try_with_capacity_in(1 << 30, &mut *bump).is_err());
+    });
+
+    assert_eq!(bump.stats().size(), size);
+}
+
+fn no_chunk_for_rejected_request<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut bump = Bump::<_, BumpSettings<1, UP>>::with_size_in(512, &allocator);
+    let capacity = bump.stats().capacity();
+    let allocations = allocator.allocation_count();
+
+    bump.scoped_with_limit(1024, |bump| {
+        // fits into the limit, but not into the current chunk with its free space counted as used
+        let size = 1024 - capacity / 2;
+        assert!(bump.try_alloc_uninit_slice::<u8>(size).is_err());
+        assert!(MutBumpVec::<u8, _>::try_with_capacity_in(size, &mut *bump).is_err());
+        assert!(MutBumpVecRev::<u8, _>::try_with_capacity_in(size, &mut *bump).is_err());
+
+        let mut vec = BumpVec::<u8, _>::new_in(&*bump);
+        assert!(vec.try_reserve_exact(size).is_err());
+        assert_eq!(bump.used(), 0);
+    });
+
+    assert_eq!(allocator.allocation_count(), allocations);
+    assert_eq!(bump.stats().capacity(), capacity);
+}
+
+fn min_align<const UP: bool>() {
+    let mut bump = TestBump::<UP, 8>::new();
+
+    bump.scoped_with_limit(20, |bump| {
+        // the padding after each byte counts toward the limit
+        bump.alloc(1u8);
+        bump.alloc(2u8);
+        assert_eq!(bump.used(), 16);
+        assert!(bump.try_alloc(3u8).is_err());
+        assert_eq!(bump.used(), 16);
+    });
+}
+
+fn guard<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("outside");
+
+    {
+        let mut guard = bump.scope_guard_with_limit(10);
+        guard.scope().alloc_str("hello");
+
+        // the limit is shared between the scopes of a guard
+        let scope = guard.scope();
+        assert_eq!(scope.used(), 5);
+        assert_eq!(scope.limit(), 10);
+        assert!(scope.try_alloc_str("world!").is_err());
+        scope.alloc_str("world");
+    }
+
+    assert_eq!(bump.stats().allocated(), 7);
+}
+
+fn guard_reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let mut guard = bump.scope_guard_with_limit(10);
+
+    guard.scope().alloc_str("0123456789");
+    assert!(guard.scope().try_alloc(0u8).is_err());
+
+    guard.reset();
+    assert_eq!(guard.scope().used(), 0);
+    guard.scope().alloc_str("0123456789");
+}
+
+fn mut_bump_vec<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    bump.scoped_with_limit(100, |bump| {
+        bump.alloc(0u8);
+
+        let mut vec = MutBumpVec::<u32, _>::new_in(&mut *bump);
+
+        while vec.try_push(1).is_ok() {}
+
+        assert_eq!(vec.len(), 24);
+        assert!(vec.capacity() <= 24);
+
+        let slice = vec.into_slice();
+        assert_eq!(slice.len(), 24);
+        assert!(bump.used() <= 100);
+    });
+}
+
+fn mut_bump_vec_rev<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    bump.scoped_with_limit(100, |bump| {
+        let mut vec = MutBumpVecRev::<u32, _>::new_in(&mut *bump);
+
+        while vec.try_push(1).is_ok() {}
+
+        assert_eq!(vec.len(), 25);
+
+        let slice = vec.into_slice();
+        assert_eq!(slice.len(), 25);
+        assert_eq!(bump.used(), 100);
+    });
+}
+
+fn bump_vec<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    bump.scoped_with_limit(1000, |bump| {
+        let mut vec = BumpVec::<u8, _>::new_in(&*bump);
+
+        while vec.try_push(1).is_ok() {}
+
+        assert!(vec.len() <= 1000);
+        assert!(vec.len() >= 500);
+        assert!(bump.used() <= 1000);
+    });
+}
+
+fn bump_vec_moving<const UP: bool>() {
+    type Settings<const UP: bool> = <BumpSettings<1, UP> as BumpAllocatorSettings>::WithPoison<true>;
+
+    let mut bump = Bump::<Global, Settings<UP>>::with_size(512);
+
+    bump.scoped_with_limit(1200, |bump| {
+        let mut vec = BumpVec::<u32, _>::new_in(&*bump);
+
+        // the allocation after the vector makes it move whenever it grows
+        while vec.try_push(vec.len() as u32).is_ok() {
+            assert!(bump.used() <= 1200);
+
+            if bump.try_alloc(0u8).is_err() {
+                break;
+            }
+        }
+
+        assert!(!vec.is_empty());
+        assert!(vec.iter().enumerate().all(|(i, &x)| x == i as u32));
+        assert!(bump.used() <= 1200);
+    });
+}
@@ -130,3 +130,40 @@ fn de() {
         roundtrip(&src, &mut dst);
     }
 }
+
+#[test]
+fn ser_stats_snapshot() {
+    let bump: Bump = Bump::with_size(512);
+    let before = bump.stats().snapshot();
+    bump.alloc_str("hello");
+
+    let snapshot = bump.stats().snapshot();
+    let chunk = snapshot.chunks()[0];
+
+    let json = serde_json::to_value(&snapshot).unwrap();
+    let expected = serde_json::json!({
+        "count": 1,
+        "size": snapshot.size(),
+        "capacity": snapshot.capacity(),
+        "allocated": 5,
+        "remaining": snapshot.remaining(),
+        "current_chunk": 0,
+        "chunks": [{
+            "size": chunk.size(),
+            "capacity": chunk.capacity(),
+            "allocated": 5,
+            "remaining": chunk.remaining(),
+        }],
+    });
+    assert_eq!(json, expected);
+
+    let json = serde_json::to_value(snapshot.diff(&before)).unwrap();
+    let expected = serde_json::json!({
+        "count": 0,
+        "size": 0,
+        "capacity": 0,
+        "allocated": 5,
+        "remaining": -5,
+    });
+    assert_eq!(json, expected);
+}
@@ -0,0 +1,140 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{Bump, alloc::Global, settings::BumpSettings, stats::AnyStats, traits::BumpAllocatorCore};
+use common::either_way;
+
+either_way! {
+    unallocated
+    chunks
+    following_chunks
+    diff
+    display
+}
+
+type SnapshotBump<const UP: bool, const GUARANTEED_ALLOCATED: bool = true> =
+    Bump<Global, BumpSettings<1, UP, GUARANTEED_ALLOCATED>>;
+
+fn unallocated<const UP: bool>() {
+    let bump = SnapshotBump::<UP, false>::unallocated();
+    let snapshot = bump.stats().snapshot();
+
+    assert_eq!(snapshot.count(), 0);
+    assert_eq!(snapshot.size(), 0);
+    assert_eq!(snapshot.current_chunk_index(), None);
+    assert_eq!(snapshot.current_chunk(), None);
+    assert_eq!(
+        snapshot.to_string(),
+        "chunks: 0, size: 0, capacity: 0, allocated: 0, remaining: 0"
+    );
+}
+
+fn chunks<const UP: bool>() {
+    let bump = SnapshotBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    let stats = bump.stats();
+    let snapshot = stats.snapshot();
+
+    assert_eq!(snapshot.count(), stats.count());
+    assert_eq!(snapshot.size(), stats.size());
+    assert_eq!(snapshot.capacity(), stats.capacity());
+    assert_eq!(snapshot.allocated(), stats.allocated());
+    assert_eq!(snapshot.remaining(), stats.remaining());
+    assert_eq!(snapshot.current_chunk_index(), Some(1));
+
+    for (chunk, chunk_snapshot) in stats.small_to_big().zip(snapshot.chunks()) {
+        assert_eq!(chunk_snapshot.size(), chunk.size());
+        assert_eq!(chunk_snapshot.capacity(), chunk.capacity());
+        assert_eq!(chunk_snapshot.allocated(), chunk.allocated());
+        assert_eq!(chunk_snapshot.remaining(), chunk.remaining());
+    }
+
+    assert_eq!(snapshot.chunks()[0].allocated(), 400);
+    assert_eq!(snapshot.current_chunk().unwrap().allocated(), 800);
+
+    // the type-erased stats produce the same snapshot
+    assert_eq!(bump.any_stats().snapshot(), snapshot);
+    assert_eq!(AnyStats::from(stats).snapshot(), snapshot);
+}
+
+fn following_chunks<const UP: bool>() {
+    let mut bump = SnapshotBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+    bump.reset_to_start();
+    bump.alloc_uninit_slice::<u8>(10);
+
+    let snapshot = bump.stats().snapshot();
+    assert_eq!(snapshot.current_chunk_index(), Some(0));
+
+    // the following chunk still has its old bump position, but it is reported as empty
+    let following = snapshot.chunks()[1];
+    assert_eq!(following.allocated(), 0);
+    assert_eq!(following.remaining(), following.capacity());
+    assert_eq!(snapshot.allocated(), 10);
+}
+
+fn diff<const UP: bool>() {
+    let mut bump = SnapshotBump::<UP>::with_size(512);
+    let empty = bump.stats().snapshot();
+    assert!(empty.diff(&empty).is_empty());
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    let full = bump.stats().snapshot();
+
+    let diff = full.diff(&empty);
+    assert_eq!(diff.count(), 1);
+    assert_eq!(diff.size(), (full.size() - empty.size()) as isize);
+    assert_eq!(diff.capacity(), (full.capacity() - empty.capacity()) as isize);
+    assert_eq!(diff.allocated(), full.allocated() as isize);
+    assert!(!diff.is_empty());
+
+    bump.reset();
+    let reset = bump.stats().snapshot();
+
+    let diff = reset.diff(&full);
+    assert_eq!(diff.count(), -1);
+    assert_eq!(diff.allocated(), -(full.allocated() as isize));
+}
+
+fn display<const UP: bool>() {
+    let bump = SnapshotBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(400);
+    bump.alloc_uninit_slice::<u8>(800);
+
+    let snapshot = bump.stats().snapshot();
+    let [first, second] = snapshot.chunks() else { unreachable!() };
+
+    let expected = format!(
+        "chunks: 2, size: {}, capacity: {}, allocated: {}, remaining: {}\n\
+         chunk 0: size: {}, capacity: {}, allocated: 400, remaining: {}\n\
+         chunk 1: size: {}, capacity: {}, allocated: 800, remaining: {} (current)",
+        snapshot.size(),
+        snapshot.capacity(),
+        snapshot.allocated(),
+        snapshot.remaining(),
+        first.size(),
+        first.capacity(),
+        first.remaining(),
+        second.size(),
+        second.capacity(),
+        second.remaining(),
+    );
+
+    assert_eq!(snapshot.to_string(), expected);
+
+    let diff = snapshot.diff(&Default::default());
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "chunks: +2, size: +{}, capacity: +{}, allocated: +{}, remaining: +{}",
+            snapshot.size(),
+            snapshot.capacity(),
+            snapshot.allocated(),
+            snapshot.remaining()
+        )
+    );
+}
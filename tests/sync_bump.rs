@@ -0,0 +1,256 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::vec::Vec;
+
+use bump_scope::{
+    Bump, BumpVec, SyncBump,
+    alloc::Global,
+    settings::BumpSettings,
+    traits::{BumpAllocatorCore, BumpAllocatorTyped, BumpAllocatorTypedScope},
+};
+use common::{InstrumentedAllocator, either_way};
+
+either_way! {
+    threads
+    threads_bump_vec
+    grow_in_place
+    deallocate_last
+    shrink_last
+    reset_to_start
+    reset
+    checkpoint
+    reserve
+    unallocated
+    into_bump
+    deallocates
+}
+
+type TestSyncBump<const UP: bool> = SyncBump<Global, BumpSettings<1, UP, false>>;
+
+fn assert_sync<T: Sync>(_: &T) {}
+
+fn threads<const UP: bool>() {
+    const THREADS: usize = 8;
+    const ITEMS: usize = if cfg!(miri) { 50 } else { 1000 };
+
+    let bump = TestSyncBump::<UP>::with_size(512);
+    assert_sync(&bump);
+
+    let values = std::thread::scope(|s| {
+        let handles = (0..THREADS)
+            .map(|t| {
+                let bump = &bump;
+                s.spawn(move || (0..ITEMS).map(|i| bump.alloc(t * ITEMS + i).into_ref()).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().flat_map(|h| h.join().unwrap()).collect::<Vec<_>>()
+    });
+
+    assert!(values.iter().copied().copied().eq(0..THREADS * ITEMS));
+    assert!(bump.stats().allocated() >= THREADS * ITEMS * size_of::<usize>());
+    assert!(bump.stats().count() > 1);
+}
+
+fn threads_bump_vec<const UP: bool>() {
+    const THREADS: usize = 4;
+    const ITEMS: usize = if cfg!(miri) { 20 } else { 500 };
+
+    let bump = TestSyncBump::<UP>::with_size(512);
+
+    let slices = std::thread::scope(|s| {
+        let handles = (0..THREADS)
+            .map(|t| {
+                let bump = &bump;
+                s.spawn(move || {
+                    let mut vec = BumpVec::new_in(bump);
+
+                    for i in 0..ITEMS {
+                        vec.push(t * ITEMS + i);
+                    }
+
+                    vec.into_slice()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+    });
+
+    for (t, slice) in slices.iter().enumerate() {
+        assert!(slice.iter().copied().eq(t * ITEMS..(t + 1) * ITEMS));
+    }
+}
+
+fn grow_in_place<const UP: bool>() {
+    let bump = TestSyncBump::<UP>::with_size(512);
+
+    let mut vec = BumpVec::<u8, _>::with_capacity_in(8, &bump);
+    vec.extend_from_slice_copy(&[1; 8]);
+    vec.push(2);
+
+    // the vector was the last allocation, so growing reused its memory
+    assert_eq!(bump.stats().allocated(), vec.capacity());
+    assert_eq!(vec, [1, 1, 1, 1, 1, 1, 1, 1, 2]);
+}
+
+fn deallocate_last<const UP: bool>() {
+    let bump = TestSyncBump::<UP>::with_size(512);
+
+    let a = (&bump).alloc(1u64);
+    let b = (&bump).alloc(2u64);
+    assert_eq!(bump.stats().allocated(), 16);
+
+    // not the last allocation, does nothing
+    bump.dealloc(a);
+    assert_eq!(bump.stats().allocated(), 16);
+
+    bump.dealloc(b);
+    assert_eq!(bump.stats().allocated(), 8);
+}
+
+fn shrink_last<const UP: bool>() {
+    let bump = TestSyncBump::<UP>::with_size(512);
+
+    let mut vec = BumpVec::<u8, _>::with_capacity_in(64, &bump);
+    vec.push(1);
+    vec.shrink_to_fit();
+
+    assert_eq!(vec, [1]);
+
+    if UP {
+        assert_eq!(bump.stats().allocated(), 1);
+    } else {
+        assert_eq!(bump.stats().allocated(), 64);
+    }
+}
+
+fn reset_to_start<const UP: bool>() {
+    let mut bump = TestSyncBump::<UP>::with_size(512);
+
+    for _ in 0..3 {
+        (&bump).alloc_uninit_slice::<u8>(bump.stats().remaining() + 1);
+    }
+
+    let count = bump.stats().count();
+    bump.reset_to_start();
+
+    assert_eq!(bump.stats().count(), count);
+    assert_eq!(bump.stats().allocated(), 0);
+
+    // all chunks are empty, not just the current one
+    assert!(bump.stats().small_to_big().all(|chunk| chunk.allocated() == 0));
+
+    // so we can use all of their capacity again
+    let capacity = bump.stats().capacity();
+    let biggest = bump.stats().small_to_big().last().unwrap().capacity();
+    (&bump).alloc_uninit_slice::<u8>(biggest);
+    assert_eq!(bump.stats().count(), count);
+    assert_eq!(bump.stats().capacity(), capacity);
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = TestSyncBump::<UP>::with_size(512);
+    (&bump).alloc_uninit_slice::<u8>(600);
+    assert_eq!(bump.stats().count(), 2);
+
+    bump.reset();
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().allocated(), 0);
+}
+
+fn checkpoint<const UP: bool>() {
+    let bump = TestSyncBump::<UP>::with_size(512);
+    (&bump).alloc_str("hello");
+
+    let checkpoint = bump.checkpoint();
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    (&bump).alloc_uninit_slice::<u8>(10);
+                }
+            });
+        }
+    });
+
+    assert!(bump.stats().allocated() >= 5 + 4 * 100 * 10);
+    let count = bump.stats().count();
+
+    unsafe { bump.reset_to(checkpoint) };
+    assert_eq!(bump.stats().allocated(), 5);
+    assert_eq!(bump.stats().count(), count);
+    assert!(
+        bump.stats()
+            .current_chunk()
+            .unwrap()
+            .iter_next()
+            .all(|chunk| chunk.allocated() == 0)
+    );
+}
+
+fn reserve<const UP: bool>() {
+    let bump = TestSyncBump::<UP>::with_size(512);
+    bump.reserve(4096);
+    assert!(bump.stats().remaining() >= 4096);
+    assert_eq!(bump.stats().count(), 2);
+
+    // nothing to do
+    bump.reserve(4096);
+    assert_eq!(bump.stats().count(), 2);
+
+    let unallocated = TestSyncBump::<UP>::unallocated();
+    unallocated.reserve(100);
+    assert!(unallocated.stats().remaining() >= 100);
+}
+
+fn unallocated<const UP: bool>() {
+    let bump = TestSyncBump::<UP>::unallocated();
+    assert_eq!(bump.stats().count(), 0);
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| assert_eq!((&bump).alloc(1u32), 1));
+        }
+    });
+
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().allocated(), 16);
+}
+
+fn into_bump<const UP: bool>() {
+    let mut bump = Bump::<Global, BumpSettings<1, UP>>::with_size(512);
+
+    for _ in 0..2 {
+        bump.alloc_uninit_slice::<u8>(bump.stats().remaining() + 1);
+    }
+
+    bump.reset_to_start();
+    bump.alloc_str("hello");
+
+    let sync_bump = SyncBump::from(bump);
+    assert_eq!(sync_bump.stats().allocated(), 5);
+    assert!(sync_bump.stats().small_to_big().skip(1).all(|chunk| chunk.allocated() == 0));
+
+    (&sync_bump).alloc_str("world");
+
+    let bump = sync_bump.into_bump();
+    assert_eq!(bump.stats().allocated(), 10);
+    assert_eq!(bump.stats().count(), 3);
+}
+
+fn deallocates<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let bump = SyncBump::<_, BumpSettings<1, UP>>::with_size_in(512, &allocator);
+
+    for _ in 0..2 {
+        (&bump).alloc_uninit_slice::<u8>(bump.stats().remaining() + 1);
+    }
+
+    assert_eq!(allocator.leaks().len(), 3);
+    drop(bump);
+    assert_eq!(allocator.leaks().len(), 0);
+}
@@ -0,0 +1,177 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{
+    cell::Cell,
+    format,
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::Barrier,
+    vec::Vec,
+};
+
+use bump_scope::{
+    Bump,
+    alloc::Global,
+    settings::{BumpAllocatorSettings, BumpObserver, BumpSettings},
+    stats::AnyStats,
+    thread_local::{self, ThreadLocalBump, ThreadLocalSettings},
+};
+use common::InstrumentedAllocator;
+
+#[test]
+fn with_resets() {
+    thread_local::with(|bump| {
+        bump.alloc_str("hello");
+        assert_eq!(bump.stats().allocated(), 5);
+    });
+
+    thread_local::with(|bump| assert_eq!(bump.stats().allocated(), 0));
+}
+
+#[test]
+fn nested() {
+    fn helper() -> usize {
+        thread_local::with(|bump| {
+            let values = bump.alloc_slice_copy(&[1, 2, 3]);
+            values.iter().sum()
+        })
+    }
+
+    thread_local::with(|bump| {
+        let hello = bump.alloc_str("hello");
+        assert_eq!(helper(), 6);
+
+        // the inner call did not reset the bump allocator
+        assert_eq!(hello, "hello");
+        assert!(bump.stats().allocated() > 5);
+    });
+
+    thread_local::with(|bump| assert_eq!(bump.stats().allocated(), 0));
+}
+
+#[test]
+fn threads() {
+    let barrier = Barrier::new(4);
+
+    let addresses = std::thread::scope(|s| {
+        let handles = (0..4)
+            .map(|_| {
+                s.spawn(|| {
+                    thread_local::with(|bump| {
+                        let address = bump.alloc_str("hello").as_ptr().addr();
+
+                        // keep all threads alive so their memory is not reused
+                        barrier.wait();
+                        address
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+    });
+
+    // every thread has its own bump allocator
+    for (i, a) in addresses.iter().enumerate() {
+        assert!(addresses[i + 1..].iter().all(|b| a != b));
+    }
+}
+
+#[test]
+fn replace() {
+    let previous = thread_local::replace(Bump::with_size(4096));
+    assert_eq!(previous.stats().count(), 0);
+
+    thread_local::with(|bump| {
+        assert_eq!(bump.stats().count(), 1);
+        assert!(bump.stats().capacity() >= 4000);
+    });
+
+    let bump = thread_local::replace(Bump::unallocated());
+    assert!(bump.stats().capacity() >= 4000);
+}
+
+#[test]
+#[should_panic = "the thread-local bump allocator can't be replaced while it is in use"]
+fn replace_while_in_use() {
+    thread_local::with(|_| {
+        thread_local::replace(Bump::unallocated());
+    });
+}
+
+#[test]
+fn reset_on_panic() {
+    let result = catch_unwind(|| {
+        thread_local::with(|bump| {
+            bump.alloc_str("hello");
+            panic!("oh no");
+        })
+    });
+
+    assert!(result.is_err());
+    thread_local::with(|bump| assert_eq!(bump.stats().allocated(), 0));
+}
+
+#[test]
+fn custom() {
+    type Settings = BumpSettings<8, false>;
+
+    let allocator = InstrumentedAllocator::new(Global);
+    let thread_local = ThreadLocalBump::from(Bump::<_, Settings>::with_size_in(512, &allocator));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        thread_local.with(|bump| {
+            bump.alloc_uninit_slice::<u8>(1000);
+            assert_eq!(bump.stats().count(), 2);
+            panic!("oh no");
+        })
+    }));
+
+    assert!(result.is_err());
+
+    // the bump allocator was reset, keeping only the biggest chunk
+    thread_local.with(|bump| {
+        assert_eq!(bump.stats().count(), 1);
+        assert_eq!(bump.stats().allocated(), 0);
+    });
+
+    assert_eq!(allocator.leaks().len(), 1);
+    drop(thread_local.into_inner());
+    assert_eq!(allocator.leaks().len(), 0);
+}
+
+#[test]
+fn observer_reenters() {
+    struct Reenter;
+
+    type Settings = <ThreadLocalSettings as BumpAllocatorSettings>::WithObserver<Reenter>;
+
+    std::thread_local! {
+        static BUMP: ThreadLocalBump<Global, Settings> = const { ThreadLocalBump::new() };
+        static RESETS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    impl BumpObserver for Reenter {
+        fn on_reset(_stats: AnyStats<'_>) {
+            BUMP.with(|bump| {
+                assert_eq!(format!("{bump:?}"), "ThreadLocalBump { .. }");
+                assert!(catch_unwind(AssertUnwindSafe(|| bump.with(|_| ()))).is_err());
+                assert!(catch_unwind(AssertUnwindSafe(|| bump.replace(Bump::unallocated()))).is_err());
+            });
+
+            RESETS.set(RESETS.get() + 1);
+        }
+    }
+
+    BUMP.with(|bump| {
+        bump.with(|bump| {
+            bump.alloc_str("hello");
+        });
+        assert_eq!(RESETS.get(), 1);
+
+        // the bump allocator can be used again after the reset
+        bump.with(|bump| assert_eq!(bump.stats().allocated(), 0));
+        assert_eq!(RESETS.get(), 2);
+    });
+}
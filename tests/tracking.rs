@@ -0,0 +1,174 @@
+#![cfg(all(feature = "track-allocations", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{alloc::Layout, mem, string::String};
+
+use bump_scope::{
+    Bump, BumpBox,
+    alloc::{Allocator, Global},
+    settings::BumpSettings,
+    tracking::{self, AllocationType},
+};
+use common::either_way;
+
+either_way! {
+    sites
+    types
+    multiple_chunks
+    scope_exit
+    reset
+    freed_and_reused
+    allocator_api
+}
+
+type TestBump<const UP: bool> = Bump<Global, BumpSettings<1, UP>>;
+
+fn sites<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let (_, line_a) = (bump.alloc(1u64), line!());
+    let (_, line_b) = (bump.alloc_slice_copy(&[1u32, 2, 3]), line!());
+
+    for _ in 0..3 {
+        bump.alloc(1u8);
+    }
+
+    let line_c = line!() - 3;
+
+    let sites = bump.stats().allocation_sites();
+    assert_eq!(sites.len(), 3);
+
+    assert_eq!(sites[0].location.file(), file!());
+    assert_eq!(sites[0].location.line(), line_b);
+    assert_eq!((sites[0].count, sites[0].bytes), (1, 12));
+
+    assert_eq!(sites[1].location.line(), line_a);
+    assert_eq!((sites[1].count, sites[1].bytes), (1, 8));
+
+    assert_eq!(sites[2].location.line(), line_c);
+    assert_eq!((sites[2].count, sites[2].bytes), (3, 3));
+}
+
+fn types<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    bump.alloc(1u64);
+    bump.alloc(2u64);
+    bump.alloc_str("hello");
+
+    assert_eq!(
+        bump.stats().allocation_types(),
+        [
+            AllocationType {
+                type_name: Some("u64"),
+                count: 2,
+                bytes: 16,
+            },
+            AllocationType {
+                type_name: Some("[u8]"),
+                count: 1,
+                bytes: 5,
+            },
+        ]
+    );
+}
+
+fn multiple_chunks<const UP: bool>() {
+    let bump = TestBump::<UP>::with_size(512);
+
+    for _ in 0..10 {
+        bump.alloc_uninit_slice::<u8>(200);
+    }
+
+    assert!(bump.stats().count() > 1);
+
+    let sites = bump.stats().allocation_sites();
+    assert_eq!(sites.len(), 1);
+    assert_eq!((sites[0].count, sites[0].bytes), (10, 2000));
+
+    let current = bump.stats().current_chunk().unwrap();
+    let in_current: usize = current.allocation_sites().iter().map(|site| site.bytes).sum();
+    assert!(in_current < 2000);
+}
+
+fn scope_exit<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc(1u32);
+
+    bump.scoped(|bump| {
+        bump.alloc(1u64);
+        assert_eq!(bump.stats().allocation_sites().len(), 2);
+    });
+
+    let types = bump.stats().allocation_types();
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].type_name, Some("u32"));
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+
+    for _ in 0..10 {
+        bump.alloc_uninit_slice::<u8>(200);
+    }
+
+    bump.reset();
+    assert_eq!(bump.stats().allocation_sites(), []);
+
+    bump.alloc(1u16);
+    assert_eq!(bump.stats().allocation_types()[0].type_name, Some("u16"));
+}
+
+fn freed_and_reused<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let boxed = bump.alloc(1u64);
+    bump.dealloc(boxed);
+
+    bump.alloc(1u32);
+
+    let types = bump.stats().allocation_types();
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].type_name, Some("u32"));
+}
+
+fn allocator_api<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let (_, line) = (bump.allocate(Layout::new::<[u8; 3]>()).unwrap(), line!());
+
+    let sites = bump.stats().allocation_sites();
+    assert_eq!(sites.len(), 1);
+    assert_eq!(sites[0].location.line(), line);
+
+    let types = bump.stats().allocation_types();
+    assert_eq!(types[0].type_name, None);
+    assert_eq!(types[0].bytes, 3);
+}
+
+#[test]
+fn leaked_boxes() {
+    let mut bump: Bump = Bump::new();
+    let before = tracking::leaked_boxes();
+
+    BumpBox::leak(bump.alloc(String::new()));
+    mem::forget(bump.alloc(String::new()));
+    drop(bump.alloc(String::from("dropped")));
+    _ = bump.alloc(5u32).into_ref();
+    _ = bump.alloc(String::from("inner")).into_inner();
+
+    // counted once their memory is freed
+    assert_eq!(tracking::leaked_boxes() - before, 0);
+    bump.reset();
+    assert_eq!(tracking::leaked_boxes() - before, 2);
+
+    bump.scoped(|bump| {
+        mem::forget(bump.alloc_slice_fill_with(3, String::new));
+    });
+    assert_eq!(tracking::leaked_boxes() - before, 3);
+
+    mem::forget(bump.alloc(String::new()));
+    drop(bump);
+    assert_eq!(tracking::leaked_boxes() - before, 4);
+}
@@ -0,0 +1,152 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::vec::Vec;
+
+use bump_scope::{Bump, alloc::Global, settings::BumpSettings};
+use common::{InstrumentedAllocator, either_way};
+
+either_way! {
+    trim_unallocated
+    trim_nothing
+    trim_after_reset_to_start
+    trim_to
+    trim_in_scope
+    trim_deallocates
+    reset_to_trimmed_chunk
+}
+
+type TestBump<const UP: bool> = Bump<Global, BumpSettings<1, UP, false>>;
+
+/// Fills the current chunk and allocates one more byte so each iteration allocates a new chunk.
+macro_rules! grow {
+    ($bump:expr, $chunks:expr) => {
+        for _ in 0..$chunks {
+            $bump.alloc_uninit_slice::<u8>($bump.stats().remaining() + 1);
+        }
+    };
+}
+
+macro_rules! chunk_sizes {
+    ($bump:expr) => {
+        $bump
+            .stats()
+            .small_to_big()
+            .map(|chunk| chunk.size())
+            .collect::<Vec<_>>()
+    };
+}
+
+fn trim_unallocated<const UP: bool>() {
+    let bump = TestBump::<UP>::unallocated();
+    assert_eq!(bump.trim(), 0);
+    assert_eq!(bump.stats().count(), 0);
+}
+
+fn trim_nothing<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    grow!(bump, 2);
+
+    let sizes = chunk_sizes!(bump);
+    assert_eq!(bump.trim(), 0);
+    assert_eq!(chunk_sizes!(bump), sizes);
+}
+
+fn trim_after_reset_to_start<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    grow!(bump, 3);
+
+    let sizes = chunk_sizes!(bump);
+    bump.reset_to_start();
+
+    let hello = bump.alloc_str("hello");
+
+    assert_eq!(bump.trim(), sizes[1..].iter().sum::<usize>());
+    assert_eq!(chunk_sizes!(bump), &sizes[..1]);
+    assert_eq!(hello, "hello");
+    assert_eq!(bump.stats().allocated(), 5);
+
+    // allocating works as usual after trimming
+    grow!(bump, 1);
+    assert_eq!(bump.stats().count(), 2);
+}
+
+fn trim_to<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    grow!(bump, 4);
+
+    let sizes = chunk_sizes!(bump);
+    bump.reset_to_start();
+
+    // nothing to release
+    assert_eq!(bump.trim_to(usize::MAX), 0);
+    assert_eq!(chunk_sizes!(bump), sizes);
+
+    // just short of keeping two spare chunks
+    assert_eq!(bump.trim_to(sizes[1] + sizes[2] - 1), sizes[2] + sizes[3] + sizes[4]);
+    assert_eq!(chunk_sizes!(bump), &sizes[..2]);
+
+    assert_eq!(bump.trim_to(0), sizes[1]);
+    assert_eq!(chunk_sizes!(bump), &sizes[..1]);
+}
+
+fn trim_in_scope<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    bump.scoped(|bump| {
+        grow!(bump, 1);
+
+        bump.scoped(|bump| {
+            grow!(bump, 2);
+        });
+
+        assert_eq!(bump.stats().count(), 4);
+
+        let sizes = chunk_sizes!(bump);
+        assert_eq!(bump.trim(), sizes[2] + sizes[3]);
+        assert_eq!(bump.stats().count(), 2);
+    });
+
+    // the chunk that was allocated in the outer scope is still there
+    assert_eq!(bump.stats().count(), 2);
+    assert_eq!(bump.stats().allocated(), 0);
+    assert_ne!(bump.trim(), 0);
+    assert_eq!(bump.stats().count(), 1);
+}
+
+fn trim_deallocates<const UP: bool>() {
+    let allocator = InstrumentedAllocator::new(Global);
+    let mut bump = Bump::<_, BumpSettings<1, UP>>::new_in(&allocator);
+    grow!(bump, 2);
+
+    assert_eq!(allocator.leaks().len(), 3);
+
+    bump.reset_to_start();
+    bump.trim();
+    assert_eq!(allocator.leaks().len(), 1);
+
+    drop(bump);
+    assert_eq!(allocator.leaks().len(), 0);
+}
+
+fn reset_to_trimmed_chunk<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let start = bump.checkpoint();
+    grow!(bump, 3);
+
+    let checkpoint = bump.checkpoint();
+    unsafe { bump.reset_to(start) };
+
+    let hello = bump.alloc_str("hello");
+    bump.trim();
+
+    // the checkpoint's chunk is gone, so this does nothing
+    unsafe { bump.reset_to(checkpoint) };
+    assert_eq!(bump.stats().count(), 1);
+    assert_eq!(bump.stats().allocated(), 5);
+    assert_eq!(hello, "hello");
+
+    grow!(bump, 3);
+    assert_eq!(bump.stats().count(), 4);
+}
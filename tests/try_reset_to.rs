@@ -0,0 +1,154 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{
+    Bump, CheckpointError, SyncBump,
+    alloc::Global,
+    settings::BumpSettings,
+    traits::{BumpAllocatorCore, BumpAllocatorTypedScope},
+};
+use common::either_way;
+
+either_way! {
+    same_chunk
+    previous_chunk
+    following_chunk
+    foreign
+    after_reset
+    after_trim
+    unallocated
+    unallocated_checkpoint
+    claimed
+    sync_bump
+}
+
+type TestBump<const UP: bool, const GUARANTEED_ALLOCATED: bool = true> =
+    Bump<Global, BumpSettings<1, UP, GUARANTEED_ALLOCATED>>;
+
+fn same_chunk<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+
+    let checkpoint = bump.checkpoint();
+    bump.alloc_str("world");
+
+    assert_eq!(bump.try_reset_to(checkpoint), Ok(()));
+    assert_eq!(bump.stats().allocated(), 5);
+
+    // resetting to the current position is fine
+    assert_eq!(bump.try_reset_to(checkpoint), Ok(()));
+    assert_eq!(bump.stats().allocated(), 5);
+}
+
+fn previous_chunk<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(10);
+
+    let checkpoint = bump.checkpoint();
+    bump.alloc_uninit_slice::<u8>(1000);
+    assert_eq!(bump.stats().count(), 2);
+
+    assert_eq!(bump.try_reset_to(checkpoint), Ok(()));
+    assert_eq!(bump.stats().allocated(), 10);
+    assert_eq!(bump.stats().count(), 2);
+}
+
+fn following_chunk<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let start = bump.checkpoint();
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    let checkpoint = bump.checkpoint();
+
+    bump.try_reset_to(start).unwrap();
+    assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::AheadOfPosition));
+    assert_eq!(bump.stats().allocated(), 0);
+}
+
+fn foreign<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    let other = TestBump::<UP>::new();
+    other.alloc_str("hello");
+
+    assert_eq!(bump.try_reset_to(other.checkpoint()), Err(CheckpointError::ForeignChunk));
+}
+
+fn after_reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    bump.alloc_uninit_slice::<u8>(1000);
+    bump.alloc_str("hello");
+    let checkpoint = bump.checkpoint();
+
+    bump.reset();
+    bump.alloc_str("hi");
+
+    assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::AheadOfPosition));
+    assert_eq!(bump.stats().allocated(), 2);
+}
+
+fn after_trim<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let start = bump.checkpoint();
+
+    bump.alloc_uninit_slice::<u8>(1000);
+    let checkpoint = bump.checkpoint();
+
+    bump.try_reset_to(start).unwrap();
+    bump.trim();
+
+    assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::ForeignChunk));
+}
+
+fn unallocated<const UP: bool>() {
+    let mut bump = TestBump::<UP, false>::unallocated();
+    let other = TestBump::<UP, false>::new();
+
+    assert_eq!(bump.try_reset_to(other.checkpoint()), Err(CheckpointError::ForeignChunk));
+}
+
+fn unallocated_checkpoint<const UP: bool>() {
+    let mut bump = TestBump::<UP, false>::unallocated();
+    let checkpoint = bump.checkpoint();
+
+    bump.alloc_str("hello");
+    assert_eq!(bump.try_reset_to(checkpoint), Ok(()));
+    assert_eq!(bump.stats().allocated(), 0);
+}
+
+fn claimed<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+    bump.alloc_str("hello");
+
+    let checkpoint = {
+        let scope = bump.as_scope();
+        let _guard = scope.claim();
+        scope.checkpoint()
+    };
+
+    assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::Claimed));
+    assert_eq!(bump.stats().allocated(), 5);
+}
+
+fn sync_bump<const UP: bool>() {
+    let mut bump = SyncBump::<Global, BumpSettings<1, UP>>::with_size(512);
+    (&bump).alloc_str("hello");
+
+    let checkpoint = bump.checkpoint();
+    (&bump).alloc_uninit_slice::<u8>(1000);
+
+    assert_eq!(bump.try_reset_to(checkpoint), Ok(()));
+    assert_eq!(bump.stats().allocated(), 5);
+
+    let other: SyncBump<Global, BumpSettings<1, UP>> = SyncBump::new();
+    assert_eq!(bump.try_reset_to(other.checkpoint()), Err(CheckpointError::ForeignChunk));
+
+    // the reset frees the chunk of the checkpoint
+    bump.reset();
+    assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::ForeignChunk));
+
+    (&bump).alloc_str("hello");
+    let checkpoint = bump.checkpoint();
+    bump.reset();
+    assert_eq!(bump.try_reset_to(checkpoint), Err(CheckpointError::AheadOfPosition));
+}
@@ -0,0 +1,193 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use bump_scope::{
+    Bump, BumpVec, SyncBump,
+    alloc::Global,
+    settings::{BumpAllocatorSettings, BumpSettings},
+    stats::AnyStats,
+    traits::BumpAllocatorTypedScope,
+};
+use common::either_way;
+
+either_way! {
+    disabled
+    padding
+    min_align
+    abandoned
+    abandoned_in_another_chunk
+    wasted_tail
+    reset
+    chunk_layout
+    sync_bump
+}
+
+type WasteSettings<const UP: bool, const MIN_ALIGN: usize = 1> =
+    BumpSettings<MIN_ALIGN, UP, true, true, true, true, 512, { usize::MAX }, 2, 0, false, true>;
+
+type WasteBump<const UP: bool> = Bump<Global, WasteSettings<UP>>;
+
+fn disabled<const UP: bool>() {
+    let bump = Bump::<Global, BumpSettings<1, UP>>::new();
+    bump.alloc(1u8);
+    bump.alloc(2u32);
+
+    assert_eq!(bump.stats().wasted_padding(), None);
+    assert_eq!(bump.stats().wasted_abandoned(), None);
+    assert_eq!(bump.stats().current_chunk().unwrap().wasted_padding(), None);
+    assert_eq!(AnyStats::from(bump.stats()).wasted_padding(), None);
+}
+
+fn padding<const UP: bool>() {
+    let bump = WasteBump::<UP>::new();
+    bump.alloc(1u8);
+    assert_eq!(bump.stats().wasted_padding(), Some(0));
+
+    bump.alloc(2u32);
+    assert_eq!(bump.stats().wasted_padding(), Some(3));
+
+    bump.alloc(3u8);
+    bump.alloc(4u64);
+    assert_eq!(bump.stats().wasted_padding(), Some(10));
+    assert_eq!(AnyStats::from(bump.stats()).wasted_padding(), Some(10));
+}
+
+fn min_align<const UP: bool>() {
+    let bump = Bump::<Global, WasteSettings<UP, 8>>::new();
+    bump.alloc(1u8);
+    bump.alloc(2u64);
+    bump.alloc_str("hello");
+
+    assert_eq!(bump.stats().wasted_padding(), Some(7 + 3));
+}
+
+fn abandoned<const UP: bool>() {
+    let bump = WasteBump::<UP>::new();
+
+    let mut vec = BumpVec::<u8, _>::with_capacity_in(4, &bump);
+    vec.extend_from_slice_copy(&[1, 2, 3, 4]);
+    assert_eq!(bump.stats().wasted_abandoned(), Some(0));
+
+    // growing in place doesn't abandon anything
+    vec.push(5);
+    assert_eq!(bump.stats().wasted_abandoned(), Some(0));
+    assert_eq!(vec.capacity(), 8);
+
+    bump.alloc(6u8);
+    vec.extend_from_slice_copy(&[6, 7, 8, 9]);
+
+    assert_eq!(bump.stats().wasted_abandoned(), Some(8));
+    assert_eq!(AnyStats::from(bump.stats()).wasted_abandoned(), Some(8));
+}
+
+fn abandoned_in_another_chunk<const UP: bool>() {
+    let bump = WasteBump::<UP>::with_size(512);
+    let capacity = bump.stats().capacity();
+
+    let mut vec = BumpVec::<u8, _>::with_capacity_in(capacity, &bump);
+    vec.resize(capacity, 0);
+    vec.push(1);
+
+    let stats = bump.stats();
+    assert_eq!(stats.count(), 2);
+    assert_eq!(stats.wasted_abandoned(), Some(capacity));
+
+    let first = stats.current_chunk().unwrap().prev().unwrap();
+    assert_eq!(first.wasted_abandoned(), Some(capacity));
+    assert_eq!(first.wasted_tail(), 0);
+    assert_eq!(stats.current_chunk().unwrap().wasted_abandoned(), Some(0));
+}
+
+fn wasted_tail<const UP: bool>() {
+    let bump = WasteBump::<UP>::with_size(512);
+    let capacity = bump.stats().capacity();
+
+    bump.alloc_uninit_slice::<u8>(capacity - 10);
+    assert_eq!(bump.stats().wasted_tail(), 0);
+
+    bump.alloc_uninit_slice::<u8>(20);
+    assert_eq!(bump.stats().count(), 2);
+    assert_eq!(bump.stats().wasted_tail(), 10);
+    assert_eq!(AnyStats::from(bump.stats()).wasted_tail(), 10);
+
+    let first = bump.stats().current_chunk().unwrap().prev().unwrap();
+    assert_eq!(first.wasted_tail(), 10);
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = WasteBump::<UP>::with_size(512);
+    bump.alloc(1u8);
+    bump.alloc(2u32);
+    bump.alloc_uninit_slice::<u8>(1000);
+    bump.alloc(3u8);
+    bump.alloc(4u32);
+
+    assert_eq!(bump.stats().wasted_padding(), Some(6));
+
+    bump.reset();
+    assert_eq!(bump.stats().wasted_padding(), Some(0));
+    assert_eq!(bump.stats().wasted_tail(), 0);
+
+    bump.alloc(1u8);
+    bump.alloc(2u32);
+    bump.reset_to_start();
+    assert_eq!(bump.stats().wasted_padding(), Some(0));
+}
+
+fn chunk_layout<const UP: bool>() {
+    type BothSettings<const UP: bool> = BumpSettings<1, UP, true, true, true, true, 512, { usize::MAX }, 2, 0, true, true>;
+
+    let plain = Bump::<Global, BumpSettings<1, UP>>::with_size(512);
+    let bump = WasteBump::<UP>::with_size(512);
+    let both = Bump::<Global, BothSettings<UP>>::with_size(512);
+
+    let plain_chunk = plain.stats().current_chunk().unwrap();
+    let chunk = bump.stats().current_chunk().unwrap();
+    let both_chunk = both.stats().current_chunk().unwrap();
+
+    assert_eq!(chunk.size(), plain_chunk.size());
+    assert_eq!(chunk.capacity(), plain_chunk.capacity() - 16);
+    assert_eq!(both_chunk.capacity(), plain_chunk.capacity() - 48);
+
+    let any_chunk = AnyStats::from(both.stats()).current_chunk().unwrap();
+    assert_eq!(any_chunk.content_start(), both_chunk.content_start());
+    assert_eq!(any_chunk.content_end(), both_chunk.content_end());
+
+    // allocations can use the whole capacity without touching the records
+    both.alloc_slice_fill(both_chunk.capacity(), 0xFFu8);
+    assert_eq!(both.stats().count(), 1);
+    assert_eq!(both.stats().wasted_padding(), Some(0));
+    assert_eq!(any_chunk.wasted_abandoned(), Some(0));
+    assert_eq!(both.stats().peak_allocated(), Some(both_chunk.capacity()));
+}
+
+fn sync_bump<const UP: bool>() {
+    let mut bump = SyncBump::<Global, WasteSettings<UP>>::new();
+
+    (&bump).alloc(1u8);
+    (&bump).alloc(2u32);
+    assert_eq!(bump.stats().wasted_padding(), Some(3));
+
+    let mut vec = BumpVec::<u8, _>::with_capacity_in(4, &bump);
+    vec.extend_from_slice_copy(&[1, 2, 3, 4]);
+    (&bump).alloc(5u8);
+    vec.push(5);
+    assert_eq!(bump.stats().wasted_abandoned(), Some(4));
+
+    drop(vec);
+    bump.reset();
+    assert_eq!(bump.stats().wasted_padding(), Some(0));
+    assert_eq!(bump.stats().wasted_abandoned(), Some(0));
+}
+
+#[test]
+fn settings() {
+    type Settings = <BumpSettings as BumpAllocatorSettings>::WithTrackWaste<true>;
+    const { assert!(Settings::TRACK_WASTE) };
+
+    let bump: Bump<Global, Settings> = Bump::new();
+    bump.alloc(1u8);
+    bump.alloc(2u16);
+    assert_eq!(bump.stats().wasted_padding(), Some(1));
+}
@@ -0,0 +1,226 @@
+#![cfg(all(feature = "std", feature = "panic-on-alloc"))]
+
+mod common;
+
+use std::{alloc::Layout, cell::Cell, ptr::NonNull, rc::Rc};
+
+use bump_scope::{
+    Bump, BumpString, MutBumpVec, SyncBump,
+    alloc::{AllocError, Allocator, Global},
+    settings::{BumpAllocatorSettings, BumpSettings, POISON_FREED},
+    traits::{BumpAllocatorCore, BumpAllocatorTypedScope},
+};
+use common::either_way;
+
+either_way! {
+    reset_to
+    scoped
+    reset
+    deallocate
+    shrink
+    shrink_slice
+    grow_moved
+    drop_chunks
+    mut_bump_vec
+    with_poison
+    disabled
+    sync_bump
+}
+
+const SECRET: &str = "correct horse battery staple";
+
+type Settings<const UP: bool, const ZEROIZE: bool = true> =
+    <BumpSettings<1, UP> as BumpAllocatorSettings>::WithZeroize<ZEROIZE>;
+
+type TestBump<const UP: bool, const ZEROIZE: bool = true, A = Global> = Bump<A, Settings<UP, ZEROIZE>>;
+
+fn read(ptr: NonNull<u8>, len: usize) -> Vec<u8> {
+    unsafe { core::slice::from_raw_parts(ptr.as_ptr(), len).to_vec() }
+}
+
+fn contains_secret(bytes: &[u8]) -> bool {
+    bytes.windows(SECRET.len()).any(|window| window == SECRET.as_bytes())
+}
+
+/// A base allocator that checks the chunks it gets back for the secret.
+#[derive(Clone, Default)]
+struct Inspector {
+    deallocated: Rc<Cell<usize>>,
+    leaked: Rc<Cell<usize>>,
+}
+
+unsafe impl Allocator for Inspector {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.deallocated.set(self.deallocated.get() + 1);
+
+        if contains_secret(&read(ptr, layout.size())) {
+            self.leaked.set(self.leaked.get() + 1);
+        }
+
+        unsafe { Global.deallocate(ptr, layout) };
+    }
+}
+
+fn reset_to<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let before = NonNull::from(&*bump.alloc_str("hello")).cast::<u8>();
+
+    let checkpoint = bump.checkpoint();
+    let secret = NonNull::from(BumpString::from_str_in(SECRET, &bump).into_str()).cast::<u8>();
+
+    unsafe { bump.reset_to(checkpoint) };
+
+    assert_eq!(read(before, 5), *b"hello");
+    assert_eq!(read(secret, SECRET.len()), vec![0; SECRET.len()]);
+}
+
+fn scoped<const UP: bool>() {
+    let mut bump = TestBump::<UP>::new();
+
+    let secret = bump.scoped(|bump| NonNull::from(&*bump.alloc_str(SECRET)).cast::<u8>());
+
+    assert_eq!(read(secret, SECRET.len()), vec![0; SECRET.len()]);
+}
+
+fn reset<const UP: bool>() {
+    let mut bump = TestBump::<UP>::with_size(512);
+    let first = NonNull::from(&*bump.alloc_str(SECRET)).cast::<u8>();
+    bump.alloc_slice_fill(1000, 1u8);
+    assert_eq!(bump.stats().count(), 2);
+
+    bump.reset();
+
+    assert_eq!(read(first, SECRET.len()), vec![0; SECRET.len()]);
+}
+
+fn deallocate<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let secret = bump.alloc_str(SECRET).into_boxed_bytes();
+    let ptr = NonNull::from(&*secret).cast::<u8>();
+    bump.alloc(0u8);
+
+    // memory that can't be reclaimed is still wiped
+    bump.dealloc(secret);
+    assert_eq!(read(ptr, SECRET.len()), vec![0; SECRET.len()]);
+}
+
+fn shrink<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+    let old_layout = Layout::new::<[u8; 8]>();
+
+    let ptr = bump.allocate(old_layout).unwrap().cast::<u8>();
+    unsafe { ptr.write_bytes(1, 8) };
+
+    let new = unsafe { bump.shrink(ptr, old_layout, Layout::new::<[u8; 4]>()) }.unwrap();
+    assert_eq!(read(new.cast(), 4), [1; 4]);
+
+    if UP {
+        assert_eq!(read(unsafe { ptr.add(4) }, 4), [0; 4]);
+    } else {
+        assert_eq!(read(ptr, 4), [0; 4]);
+    }
+}
+
+fn shrink_slice<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let mut string = BumpString::from_str_in(SECRET, &bump);
+    let ptr = NonNull::from(string.as_str()).cast::<u8>();
+    string.truncate(7);
+    string.shrink_to_fit();
+
+    assert_eq!(string, "correct");
+
+    if UP {
+        assert_eq!(read(unsafe { ptr.add(7) }, SECRET.len() - 7), vec![0; SECRET.len() - 7]);
+    } else {
+        assert_eq!(read(ptr, SECRET.len() - 7), vec![0; SECRET.len() - 7]);
+    }
+}
+
+fn grow_moved<const UP: bool>() {
+    let bump = TestBump::<UP>::new();
+
+    let mut string = BumpString::from_str_in(SECRET, &bump);
+    let ptr = NonNull::from(string.as_str()).cast::<u8>();
+    bump.alloc(0u8);
+    string.push_str("!!!");
+
+    assert_ne!(NonNull::from(string.as_str()).cast::<u8>(), ptr);
+    assert_eq!(read(ptr, SECRET.len()), vec![0; SECRET.len()]);
+}
+
+fn drop_chunks<const UP: bool>() {
+    let inspector = Inspector::default();
+
+    let bump = TestBump::<UP, true, Inspector>::with_size_in(512, inspector.clone());
+    bump.alloc_str(SECRET);
+    bump.alloc_slice_fill(1000, 1u8);
+    bump.alloc_str(SECRET);
+    drop(bump);
+
+    assert_eq!(inspector.deallocated.get(), 2);
+    assert_eq!(inspector.leaked.get(), 0);
+}
+
+fn mut_bump_vec<const UP: bool>() {
+    let inspector = Inspector::default();
+    let mut bump = TestBump::<UP, true, Inspector>::new_in(inspector.clone());
+
+    // the buffer is never allocated, so only the chunk deallocation wipes it
+    let mut vec = MutBumpVec::new_in(&mut bump);
+    vec.extend_from_slice_copy(SECRET.as_bytes());
+    drop(vec);
+
+    drop(bump);
+
+    assert_eq!(inspector.deallocated.get(), 1);
+    assert_eq!(inspector.leaked.get(), 0);
+}
+
+fn with_poison<const UP: bool>() {
+    let bump = Bump::<Global, <Settings<UP> as BumpAllocatorSettings>::WithPoison<true>>::new();
+    let checkpoint = bump.checkpoint();
+    let secret = NonNull::from(&*bump.alloc_str(SECRET)).cast::<u8>();
+
+    unsafe { bump.reset_to(checkpoint) };
+
+    assert_eq!(read(secret, SECRET.len()), vec![POISON_FREED; SECRET.len()]);
+}
+
+fn disabled<const UP: bool>() {
+    let inspector = Inspector::default();
+
+    let bump = TestBump::<UP, false, Inspector>::new_in(inspector.clone());
+    let checkpoint = bump.checkpoint();
+    let secret = NonNull::from(&*bump.alloc_str(SECRET)).cast::<u8>();
+
+    unsafe { bump.reset_to(checkpoint) };
+    assert_eq!(read(secret, SECRET.len()), SECRET.as_bytes());
+
+    drop(bump);
+    assert_eq!(inspector.leaked.get(), 1);
+}
+
+fn sync_bump<const UP: bool>() {
+    let inspector = Inspector::default();
+    let mut bump = SyncBump::<Inspector, Settings<UP>>::with_size_in(512, inspector.clone());
+
+    let checkpoint = bump.checkpoint();
+    let secret = NonNull::from((&bump).alloc_str(SECRET).into_ref()).cast::<u8>();
+
+    bump.try_reset_to(checkpoint).unwrap();
+    assert_eq!(read(secret, SECRET.len()), vec![0; SECRET.len()]);
+
+    (&bump).alloc_str(SECRET);
+    (&bump).alloc_slice_fill(1000, 1u8);
+    drop(bump);
+
+    assert_eq!(inspector.deallocated.get(), 2);
+    assert_eq!(inspector.leaked.get(), 0);
+}